@@ -0,0 +1,12 @@
+CREATE TABLE IF NOT EXISTS media
+(
+    id                  SERIAL PRIMARY KEY,
+    path                TEXT NOT NULL UNIQUE,
+    size                BIGINT NOT NULL,
+    mime_type           VARCHAR(255) NOT NULL,
+    checksum            VARCHAR(64) NOT NULL,
+    width               INT NULL,
+    height              INT NULL,
+    created             TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated             TIMESTAMP NULL
+);
@@ -0,0 +1,7 @@
+-- Postgres cannot drop enum values, the type is recreated without them (affected code blocks fall back to bash).
+UPDATE contents SET language = 'bash'
+WHERE language::text IN ('c', 'cpp', 'haskell', 'go', 'toml', 'json', 'dockerfile', 'markdown', 'diff', 'plain_text');
+ALTER TYPE language RENAME TO language_old;
+CREATE TYPE language AS ENUM ('rust', 'bash', 'python', 'sql', 'html', 'css', 'javascript', 'typescript', 'yaml');
+ALTER TABLE contents ALTER COLUMN language TYPE language USING language::text::language;
+DROP TYPE language_old;
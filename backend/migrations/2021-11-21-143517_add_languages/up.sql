@@ -0,0 +1,11 @@
+-- ALTER TYPE ... ADD VALUE runs inside the migration transaction, which requires PostgreSQL 12 or later.
+ALTER TYPE language ADD VALUE IF NOT EXISTS 'c';
+ALTER TYPE language ADD VALUE IF NOT EXISTS 'cpp';
+ALTER TYPE language ADD VALUE IF NOT EXISTS 'haskell';
+ALTER TYPE language ADD VALUE IF NOT EXISTS 'go';
+ALTER TYPE language ADD VALUE IF NOT EXISTS 'toml';
+ALTER TYPE language ADD VALUE IF NOT EXISTS 'json';
+ALTER TYPE language ADD VALUE IF NOT EXISTS 'dockerfile';
+ALTER TYPE language ADD VALUE IF NOT EXISTS 'markdown';
+ALTER TYPE language ADD VALUE IF NOT EXISTS 'diff';
+ALTER TYPE language ADD VALUE IF NOT EXISTS 'plain_text';
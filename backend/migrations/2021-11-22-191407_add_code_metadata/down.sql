@@ -0,0 +1,6 @@
+ALTER TABLE contents
+    DROP COLUMN caption,
+    DROP COLUMN start_line,
+    DROP COLUMN emphasized_lines,
+    DROP COLUMN added_lines,
+    DROP COLUMN removed_lines;
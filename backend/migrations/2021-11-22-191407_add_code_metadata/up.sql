@@ -0,0 +1,7 @@
+-- Optional metadata of Code contents, line ranges are written as "3, 5-8".
+ALTER TABLE contents
+    ADD COLUMN caption TEXT,
+    ADD COLUMN start_line INTEGER,
+    ADD COLUMN emphasized_lines TEXT,
+    ADD COLUMN added_lines TEXT,
+    ADD COLUMN removed_lines TEXT;
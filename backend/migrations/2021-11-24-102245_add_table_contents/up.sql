@@ -0,0 +1,3 @@
+ALTER TYPE content_type ADD VALUE IF NOT EXISTS 'table';
+-- Structured data of the content types that need more than a text (rows and columns of a Table).
+ALTER TABLE contents ADD COLUMN data JSONB;
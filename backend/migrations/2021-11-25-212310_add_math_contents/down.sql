@@ -0,0 +1,7 @@
+ALTER TABLE contents DROP COLUMN rendered_html;
+-- Postgres cannot drop enum values, the type is recreated without it.
+DELETE FROM contents WHERE content_type::text = 'math';
+ALTER TYPE content_type RENAME TO content_type_old;
+CREATE TYPE content_type AS ENUM ('text', 'comment', 'link', 'code', 'image', 'table');
+ALTER TABLE contents ALTER COLUMN content_type TYPE content_type USING content_type::text::content_type;
+DROP TYPE content_type_old;
@@ -0,0 +1,3 @@
+ALTER TYPE content_type ADD VALUE IF NOT EXISTS 'math';
+-- HTML rendered at save time (MathML of Math contents and of the inline math of Text contents).
+ALTER TABLE contents ADD COLUMN rendered_html TEXT;
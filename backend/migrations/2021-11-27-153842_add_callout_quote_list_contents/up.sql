@@ -0,0 +1,3 @@
+ALTER TYPE content_type ADD VALUE IF NOT EXISTS 'callout';
+ALTER TYPE content_type ADD VALUE IF NOT EXISTS 'quote';
+ALTER TYPE content_type ADD VALUE IF NOT EXISTS 'list';
@@ -0,0 +1,7 @@
+ALTER TABLE contents ADD COLUMN plain_text TEXT NOT NULL DEFAULT '';
+UPDATE
+   contents
+SET
+   plain_text = content
+WHERE
+   content_type IN ('text', 'comment', 'callout', 'quote');
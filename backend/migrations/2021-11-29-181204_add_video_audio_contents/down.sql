@@ -0,0 +1,6 @@
+-- Postgres cannot drop enum values, the type is recreated without them.
+DELETE FROM contents WHERE content_type::text IN ('video', 'audio');
+ALTER TYPE content_type RENAME TO content_type_old;
+CREATE TYPE content_type AS ENUM ('text', 'comment', 'link', 'code', 'image', 'table', 'math', 'callout', 'quote', 'list');
+ALTER TABLE contents ALTER COLUMN content_type TYPE content_type USING content_type::text::content_type;
+DROP TYPE content_type_old;
@@ -0,0 +1,2 @@
+ALTER TYPE content_type ADD VALUE IF NOT EXISTS 'video';
+ALTER TYPE content_type ADD VALUE IF NOT EXISTS 'audio';
@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS article_references
+  (
+     id           SERIAL PRIMARY KEY,
+     article_id   INT NOT NULL REFERENCES articles(id) ON DELETE CASCADE,
+     index        INT NOT NULL DEFAULT (0),
+     key          TEXT NOT NULL,
+     title        TEXT NOT NULL,
+     url          TEXT,
+     UNIQUE (article_id, key)
+  );
@@ -0,0 +1,8 @@
+UPDATE contents SET data = NULL WHERE content_type = 'image';
+
+ALTER TABLE project_images
+  DROP COLUMN IF EXISTS alt,
+  DROP COLUMN IF EXISTS caption,
+  DROP COLUMN IF EXISTS credits;
+
+ALTER TABLE articles DROP COLUMN IF EXISTS image_alt;
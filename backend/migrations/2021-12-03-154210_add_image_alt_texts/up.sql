@@ -0,0 +1,14 @@
+-- Alt text of the article header image, the title being the best guess for existing articles.
+ALTER TABLE articles ADD COLUMN IF NOT EXISTS image_alt TEXT NOT NULL DEFAULT '';
+UPDATE articles SET image_alt = title;
+
+-- Alt text, caption and credits of project gallery images.
+ALTER TABLE project_images
+  ADD COLUMN IF NOT EXISTS alt TEXT NOT NULL DEFAULT '',
+  ADD COLUMN IF NOT EXISTS caption TEXT,
+  ADD COLUMN IF NOT EXISTS credits TEXT;
+UPDATE project_images SET alt = projects.title FROM projects WHERE projects.id = project_images.project_id;
+
+-- Image contents keep their alt text, credits and dimensions in contents.data, the caption being contents.caption.
+UPDATE contents SET data = jsonb_build_object('alt', COALESCE(caption, ''))
+  WHERE content_type = 'image' AND data IS NULL;
@@ -0,0 +1,2 @@
+ALTER TABLE contents DROP CONSTRAINT IF EXISTS contents_chapter_id_index_key;
+ALTER TABLE chapters DROP CONSTRAINT IF EXISTS chapters_article_id_index_key;
@@ -0,0 +1,16 @@
+-- Renumber chapters and contents from 0 first, side effects of past shifts could have left gaps or duplicates.
+WITH ordered AS (
+  SELECT id, ROW_NUMBER() OVER (PARTITION BY article_id ORDER BY index, id) - 1 AS position FROM chapters
+)
+UPDATE chapters SET index = ordered.position FROM ordered WHERE chapters.id = ordered.id;
+
+WITH ordered AS (
+  SELECT id, ROW_NUMBER() OVER (PARTITION BY chapter_id ORDER BY index, id) - 1 AS position FROM contents
+)
+UPDATE contents SET index = ordered.position FROM ordered WHERE contents.id = ordered.id;
+
+-- Checked at commit time, shifting the siblings of a moved row temporarily duplicates an index.
+ALTER TABLE chapters
+  ADD CONSTRAINT chapters_article_id_index_key UNIQUE (article_id, index) DEFERRABLE INITIALLY DEFERRED;
+ALTER TABLE contents
+  ADD CONSTRAINT contents_chapter_id_index_key UNIQUE (chapter_id, index) DEFERRABLE INITIALLY DEFERRED;
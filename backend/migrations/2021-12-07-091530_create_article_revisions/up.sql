@@ -0,0 +1,10 @@
+-- Full snapshot of an article (header, tags, chapters, contents and references) recorded after every save.
+CREATE TABLE article_revisions (
+    id SERIAL PRIMARY KEY,
+    article_id INTEGER NOT NULL REFERENCES articles(id) ON DELETE CASCADE,
+    author TEXT NOT NULL,
+    created TIMESTAMP NOT NULL DEFAULT NOW(),
+    snapshot JSONB NOT NULL
+);
+
+CREATE INDEX article_revisions_article_id_index ON article_revisions (article_id, created);
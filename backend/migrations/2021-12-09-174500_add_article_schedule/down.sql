@@ -0,0 +1,4 @@
+DROP INDEX IF EXISTS articles_unpublish_at_index;
+DROP INDEX IF EXISTS articles_publish_at_index;
+ALTER TABLE articles DROP COLUMN unpublish_at;
+ALTER TABLE articles DROP COLUMN publish_at;
@@ -0,0 +1,6 @@
+-- Scheduled transitions of an article, applied (then cleared) by the scheduler of the API.
+ALTER TABLE articles ADD COLUMN publish_at TIMESTAMP;
+ALTER TABLE articles ADD COLUMN unpublish_at TIMESTAMP;
+
+CREATE INDEX articles_publish_at_index ON articles (publish_at) WHERE publish_at IS NOT NULL;
+CREATE INDEX articles_unpublish_at_index ON articles (unpublish_at) WHERE unpublish_at IS NOT NULL;
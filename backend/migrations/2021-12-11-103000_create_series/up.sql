@@ -0,0 +1,17 @@
+CREATE TABLE IF NOT EXISTS series
+  (
+     id           SERIAL PRIMARY KEY,
+     title        TEXT NOT NULL,
+     description  TEXT NOT NULL DEFAULT ''
+  );
+
+-- Ordered parts of a series, an article belongs to one series at most.
+CREATE TABLE IF NOT EXISTS series_articles
+  (
+     id           SERIAL PRIMARY KEY,
+     series_id    INT NOT NULL REFERENCES series(id) ON DELETE CASCADE,
+     article_id   INT NOT NULL UNIQUE REFERENCES articles(id) ON DELETE CASCADE,
+     index        INT NOT NULL DEFAULT (0)
+  );
+
+CREATE INDEX IF NOT EXISTS series_articles_series_id_index ON series_articles (series_id, index);
@@ -0,0 +1,15 @@
+DO $$
+DECLARE
+    cached TEXT;
+BEGIN
+    FOREACH cached IN ARRAY ARRAY[
+        'articles', 'article_tags', 'article_references', 'chapters', 'contents', 'tags',
+        'projects', 'project_tags', 'project_images', 'series', 'series_articles'
+    ] LOOP
+        EXECUTE format('DROP TRIGGER IF EXISTS %I ON %I', cached || '_cache_generation', cached);
+    END LOOP;
+END;
+$$;
+
+DROP FUNCTION IF EXISTS bump_cache_generation();
+DROP TABLE IF EXISTS cache_generation;
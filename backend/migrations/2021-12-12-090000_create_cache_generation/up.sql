@@ -0,0 +1,33 @@
+-- Bumped by any row written to the content served from the response caches, each API process polls it to drop its own
+-- cache. Row triggers do not fire for statements matching no row, like the scheduler's when nothing is due.
+CREATE TABLE IF NOT EXISTS cache_generation
+  (
+     id           INT PRIMARY KEY DEFAULT (1) CHECK (id = 1),
+     generation   BIGINT NOT NULL DEFAULT (0)
+  );
+
+INSERT INTO cache_generation (id, generation) VALUES (1, 0) ON CONFLICT DO NOTHING;
+
+CREATE OR REPLACE FUNCTION bump_cache_generation() RETURNS TRIGGER AS $$
+BEGIN
+    UPDATE cache_generation SET generation = generation + 1 WHERE id = 1;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+DO $$
+DECLARE
+    cached TEXT;
+BEGIN
+    FOREACH cached IN ARRAY ARRAY[
+        'articles', 'article_tags', 'article_references', 'chapters', 'contents', 'tags',
+        'projects', 'project_tags', 'project_images', 'series', 'series_articles'
+    ] LOOP
+        EXECUTE format(
+            'CREATE TRIGGER %I AFTER INSERT OR UPDATE OR DELETE ON %I
+                FOR EACH ROW EXECUTE PROCEDURE bump_cache_generation()',
+            cached || '_cache_generation', cached
+        );
+    END LOOP;
+END;
+$$;
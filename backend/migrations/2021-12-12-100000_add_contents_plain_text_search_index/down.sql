@@ -0,0 +1 @@
+DROP INDEX IF EXISTS contents_plain_text_search_index;
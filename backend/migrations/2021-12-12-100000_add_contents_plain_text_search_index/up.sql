@@ -0,0 +1,2 @@
+-- Search of the plain text of the contents, with the configuration of the articles' search vectors.
+CREATE INDEX IF NOT EXISTS contents_plain_text_search_index ON contents USING GIN (to_tsvector('english', plain_text));
@@ -0,0 +1,315 @@
+/*
+ * In-process cache of serialized API responses, keyed by route and query string.
+ * Content only changes a few times a month, so the public handlers serve their JSON from here instead of re-running their queries.
+ * Each entry records what it was built from (its dependencies), which lets the editable handlers invalidate exactly the
+ * entries affected by a mutation. The public and the editable servers are distinct processes sharing the same database:
+ * every write to the cached tables bumps cache_generation (see its migration), which each process polls to drop its
+ * whole cache when another one changed the content. Entries also expire after a TTL.
+ */
+use {
+    crate::{
+        conditional::{etag, respond_with_body},
+        errors::api_error::ApiError,
+        schema::cache_generation,
+        Pool,
+    },
+    actix_web::{
+        rt,
+        web::{self, Bytes},
+        HttpRequest, HttpResponse,
+    },
+    chrono::NaiveDateTime,
+    diesel::{QueryDsl, RunQueryDsl},
+    log::error,
+    serde::Serialize,
+    std::{
+        collections::{HashMap, HashSet},
+        sync::{
+            atomic::{AtomicU64, Ordering},
+            Mutex,
+        },
+        time::{Duration, Instant},
+    },
+};
+
+const DEFAULT_TTL_SECONDS: u64 = 300;
+const DEFAULT_MAX_ENTRIES: usize = 512;
+const DEFAULT_MAX_BYTES: usize = 32 * 1024 * 1024;
+const DEFAULT_POLL_INTERVAL_SECONDS: u64 = 5;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Dependency {
+    ArticleList,
+    Article(i32),
+    TagList,
+    TagResults,
+    ProjectList,
+    Project(i32),
+    Search,
+    SeriesList,
+    Series(i32),
+}
+
+struct Entry {
+    body: Bytes,
+    etag: String,
+    last_modified: Option<NaiveDateTime>,
+    dependencies: HashSet<Dependency>,
+    expires: Instant,
+    last_used: Instant,
+}
+
+#[cfg(feature = "editable")]
+#[derive(Debug, Serialize)]
+pub struct CacheStats {
+    pub entries: usize,
+    pub bytes: usize,
+    pub max_entries: usize,
+    pub max_bytes: usize,
+    pub ttl_seconds: u64,
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+    pub invalidations: u64,
+    pub hit_ratio: f64,
+}
+
+// The key of a response, and the epoch of the cache when the response started to be computed.
+pub struct CacheKey {
+    key: String,
+    epoch: u64,
+}
+
+pub struct ResponseCache {
+    entries: Mutex<HashMap<String, Entry>>,
+    // Bumped by every invalidation, under the lock of the entries.
+    epoch: AtomicU64,
+    ttl: Duration,
+    max_entries: usize,
+    max_bytes: usize,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    evictions: AtomicU64,
+    invalidations: AtomicU64,
+}
+
+pub fn env_or<T: std::str::FromStr>(name: &str, default: T) -> T {
+    std::env::var(name)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
+
+impl ResponseCache {
+    pub fn new(ttl: Duration, max_entries: usize, max_bytes: usize) -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+            epoch: AtomicU64::new(0),
+            ttl,
+            max_entries,
+            max_bytes,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            evictions: AtomicU64::new(0),
+            invalidations: AtomicU64::new(0),
+        }
+    }
+
+    // RESPONSE_CACHE_TTL (seconds), RESPONSE_CACHE_MAX_ENTRIES and RESPONSE_CACHE_MAX_BYTES can be set in the environment.
+    pub fn from_env() -> Self {
+        Self::new(
+            Duration::from_secs(env_or("RESPONSE_CACHE_TTL", DEFAULT_TTL_SECONDS)),
+            env_or("RESPONSE_CACHE_MAX_ENTRIES", DEFAULT_MAX_ENTRIES),
+            env_or("RESPONSE_CACHE_MAX_BYTES", DEFAULT_MAX_BYTES),
+        )
+    }
+
+    pub fn key(&self, req: &HttpRequest) -> CacheKey {
+        let query = req.query_string();
+        CacheKey {
+            key: if query.is_empty() {
+                req.path().to_owned()
+            } else {
+                [req.path(), "?", query].concat()
+            },
+            epoch: self.epoch.load(Ordering::SeqCst),
+        }
+    }
+
+    // Answer from the cache (200 or 304), or None on a miss.
+    pub fn respond(&self, req: &HttpRequest, key: &CacheKey) -> Option<HttpResponse> {
+        let mut entries = self.entries.lock().unwrap();
+        let now = Instant::now();
+        let key = key.key.as_str();
+
+        let response = match entries.get_mut(key) {
+            Some(entry) if entry.expires > now => {
+                entry.last_used = now;
+                Some(respond_with_body(
+                    req,
+                    entry.body.clone(),
+                    &entry.etag,
+                    entry.last_modified.as_ref(),
+                ))
+            }
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        };
+
+        match response {
+            Some(_) => self.hits.fetch_add(1, Ordering::Relaxed),
+            None => self.misses.fetch_add(1, Ordering::Relaxed),
+        };
+        response
+    }
+
+    // Serialize a freshly computed value, store it and answer with it. A value computed while an invalidation happened
+    // may predate it, it is answered but not stored.
+    pub fn store<T: Serialize>(
+        &self,
+        req: &HttpRequest,
+        key: CacheKey,
+        value: &T,
+        last_modified: Option<&NaiveDateTime>,
+        dependencies: HashSet<Dependency>,
+    ) -> HttpResponse {
+        let body = match serde_json::to_vec(value) {
+            Ok(body) => Bytes::from(body),
+            Err(_) => return HttpResponse::InternalServerError().finish(),
+        };
+        let etag = etag(&body);
+        let response = respond_with_body(req, body.clone(), &etag, last_modified);
+
+        if body.len() <= self.max_bytes {
+            let now = Instant::now();
+            let mut entries = self.entries.lock().unwrap();
+            if self.epoch.load(Ordering::SeqCst) == key.epoch {
+                entries.insert(
+                    key.key,
+                    Entry {
+                        body,
+                        etag,
+                        last_modified: last_modified.cloned(),
+                        dependencies,
+                        expires: now + self.ttl,
+                        last_used: now,
+                    },
+                );
+                self.evict(&mut entries);
+            }
+        }
+
+        response
+    }
+
+    // Drop expired entries first, then the least recently used ones until the cache fits in its bounds.
+    fn evict(&self, entries: &mut HashMap<String, Entry>) {
+        let now = Instant::now();
+        let before = entries.len();
+        entries.retain(|_, entry| entry.expires > now);
+        let mut evicted = before - entries.len();
+
+        let mut bytes: usize = entries.values().map(|entry| entry.body.len()).sum();
+        while entries.len() > self.max_entries || bytes > self.max_bytes {
+            let least_recently_used = entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone());
+            match least_recently_used.and_then(|key| entries.remove(&key)) {
+                Some(entry) => {
+                    bytes -= entry.body.len();
+                    evicted += 1;
+                }
+                None => break,
+            }
+        }
+
+        self.evictions.fetch_add(evicted as u64, Ordering::Relaxed);
+    }
+
+    // Drop every entry built from at least one of the given dependencies.
+    pub fn invalidate(&self, dependencies: &[Dependency]) {
+        let mut entries = self.entries.lock().unwrap();
+        self.epoch.fetch_add(1, Ordering::SeqCst);
+        let before = entries.len();
+        entries.retain(|_, entry| {
+            !dependencies
+                .iter()
+                .any(|dependency| entry.dependencies.contains(dependency))
+        });
+
+        self.invalidations
+            .fetch_add((before - entries.len()) as u64, Ordering::Relaxed);
+    }
+
+    // Drop every entry, when the content was changed by another process.
+    pub fn clear(&self) {
+        let mut entries = self.entries.lock().unwrap();
+        self.epoch.fetch_add(1, Ordering::SeqCst);
+        self.invalidations
+            .fetch_add(entries.len() as u64, Ordering::Relaxed);
+        entries.clear();
+    }
+
+    #[cfg(feature = "editable")]
+    pub fn stats(&self) -> CacheStats {
+        let entries = self.entries.lock().unwrap();
+        let (hits, misses) = (
+            self.hits.load(Ordering::Relaxed),
+            self.misses.load(Ordering::Relaxed),
+        );
+
+        CacheStats {
+            entries: entries.len(),
+            bytes: entries.values().map(|entry| entry.body.len()).sum(),
+            max_entries: self.max_entries,
+            max_bytes: self.max_bytes,
+            ttl_seconds: self.ttl.as_secs(),
+            hits,
+            misses,
+            evictions: self.evictions.load(Ordering::Relaxed),
+            invalidations: self.invalidations.load(Ordering::Relaxed),
+            hit_ratio: if hits + misses == 0 {
+                0.0
+            } else {
+                hits as f64 / (hits + misses) as f64
+            },
+        }
+    }
+}
+
+fn generation(pool: &Pool) -> Result<i64, ApiError> {
+    let connection = pool.get()?;
+    Ok(cache_generation::table
+        .select(cache_generation::generation)
+        .first(&connection)?)
+}
+
+// Spawned once on the system arbiter: every RESPONSE_CACHE_POLL_INTERVAL seconds (5 by default), the cache is cleared if
+// the generation moved since the previous poll.
+pub fn watch(pool: Pool, cache: web::Data<ResponseCache>) {
+    let period = Duration::from_secs(env_or(
+        "RESPONSE_CACHE_POLL_INTERVAL",
+        DEFAULT_POLL_INTERVAL_SECONDS,
+    ));
+    rt::spawn(async move {
+        let mut interval = rt::time::interval(period);
+        let mut current = None;
+        loop {
+            interval.tick().await;
+            let pool = pool.clone();
+            match web::block(move || generation(&pool)).await {
+                Ok(generation) => {
+                    if current.map_or(false, |current| current != generation) {
+                        cache.clear();
+                    }
+                    current = Some(generation);
+                }
+                Err(err) => error!("Cache: could not poll the generation ({}).", err),
+            }
+        }
+    });
+}
@@ -1,14 +1,312 @@
+/*
+ * Syntax highlighting of the Code contents.
+ * The syntax and theme sets are loaded once and shared by every worker. Custom .sublime-syntax and .tmTheme files can be added
+ * to the syntect defaults by dropping them in the syntaxes/ and themes/ folders of HIGHLIGHTING_DIR (./highlighting by default).
+ * Highlighted code only carries CSS classes ("syn-"-prefixed spans), the colours come from a stylesheet generated for each theme,
+ * so that code blocks can follow the site theme. HIGHLIGHTING_LIGHT_THEME and HIGHLIGHTING_DARK_THEME select the themes served
+ * as "light" and "dark". After changing any of these, reload() picks them up without restarting the server.
+ * syntect's defaults have no TOML, TypeScript or Dockerfile syntax: TOML and Dockerfile are shipped in highlighting/syntaxes and
+ * TypeScript is highlighted as JavaScript.
+ * Code is highlighted line by line, every line being a standalone <span class="syn-line"> so that it can be emphasized or marked
+ * as added/removed (see CodeLines) and lined up with the line-number gutter rendered by the frontend.
+ */
+use {
+    log::warn,
+    once_cell::sync::Lazy,
+    std::{path::Path, sync::RwLock},
+    syntect::{
+        highlighting::ThemeSet,
+        html::{css_for_theme_with_class_style, ClassStyle},
+        parsing::{SyntaxSet, SyntaxSetBuilder},
+    },
+};
+
+#[cfg(any(test, feature = "editable"))]
+use {crate::types::language::Language, syntect::parsing::SyntaxReference};
+
+#[cfg(feature = "editable")]
+use {
+    std::{ops::RangeInclusive, str::FromStr},
+    syntect::{
+        html::line_tokens_to_classed_spans,
+        parsing::{ParseState, ScopeStack, ScopeStackOp},
+        util::LinesWithEndings,
+    },
+};
+
+const DEFAULT_HIGHLIGHTING_DIR: &str = "./highlighting";
+const DEFAULT_LIGHT_THEME: &str = "InspiredGitHub";
+const DEFAULT_DARK_THEME: &str = "base16-ocean.dark";
+const CLASS_STYLE: ClassStyle = ClassStyle::SpacedPrefixed { prefix: "syn-" };
+
+static HIGHLIGHTER: Lazy<RwLock<Highlighter>> = Lazy::new(|| RwLock::new(Highlighter::load()));
+
+struct Highlighter {
+    // Only used to highlight code, which the public API never does.
+    #[cfg_attr(not(any(test, feature = "editable")), allow(dead_code))]
+    syntax_set: SyntaxSet,
+    theme_set: ThemeSet,
+    light_theme: String,
+    dark_theme: String,
+}
+
+fn theme_from_env(theme_set: &ThemeSet, name: &str, default: &str) -> String {
+    let theme = std::env::var(name).unwrap_or_else(|_| default.to_owned());
+    if theme_set.themes.contains_key(&theme) {
+        theme
+    } else {
+        warn!("Unknown highlighting theme {}, using {}.", theme, default);
+        default.to_owned()
+    }
+}
+
+impl Highlighter {
+    fn load() -> Self {
+        let directory = std::env::var("HIGHLIGHTING_DIR")
+            .unwrap_or_else(|_| DEFAULT_HIGHLIGHTING_DIR.to_owned());
+        let directory = Path::new(&directory);
+
+        let mut syntax_set_builder: SyntaxSetBuilder =
+            SyntaxSet::load_defaults_newlines().into_builder();
+        let syntaxes = directory.join("syntaxes");
+        if syntaxes.is_dir() {
+            if let Err(error) = syntax_set_builder.add_from_folder(&syntaxes, true) {
+                warn!("Could not load the syntaxes from {:?}: {}", syntaxes, error);
+            }
+        }
+
+        let mut theme_set = ThemeSet::load_defaults();
+        let themes = directory.join("themes");
+        if themes.is_dir() {
+            if let Err(error) = theme_set.add_from_folder(&themes) {
+                warn!("Could not load the themes from {:?}: {}", themes, error);
+            }
+        }
+
+        Self {
+            syntax_set: syntax_set_builder.build(),
+            light_theme: theme_from_env(
+                &theme_set,
+                "HIGHLIGHTING_LIGHT_THEME",
+                DEFAULT_LIGHT_THEME,
+            ),
+            dark_theme: theme_from_env(&theme_set, "HIGHLIGHTING_DARK_THEME", DEFAULT_DARK_THEME),
+            theme_set,
+        }
+    }
+
+    #[cfg(any(test, feature = "editable"))]
+    fn syntax_for(&self, language: &Language) -> Option<&SyntaxReference> {
+        syntax_tokens(language)
+            .iter()
+            .find_map(|token| self.syntax_set.find_syntax_by_token(token))
+    }
+
+    #[cfg(feature = "editable")]
+    fn highlight(&self, code: &str, language: &Language, lines: &CodeLines) -> String {
+        // Languages without a loaded syntax are rendered as plain text instead of failing.
+        let syntax = self
+            .syntax_for(language)
+            .unwrap_or_else(|| self.syntax_set.find_syntax_plain_text());
+
+        let mut parse_state = ParseState::new(syntax);
+        let mut scope_stack = ScopeStack::new();
+        let mut html = String::from("<pre class=\"syn-code\">");
+        for (line, number) in LinesWithEndings::from(code).zip(lines.start_line..) {
+            let ops = parse_state.parse_line(line, &self.syntax_set);
+            let text = line.trim_end_matches(|c| c == '\n' || c == '\r');
+            // Reopen the scopes left open by the previous lines, and close everything at the end of the line.
+            let line_ops: Vec<(usize, ScopeStackOp)> = scope_stack
+                .as_slice()
+                .iter()
+                .map(|scope| (0, ScopeStackOp::Push(*scope)))
+                .chain(
+                    ops.iter()
+                        .map(|(index, op)| ((*index).min(text.len()), op.clone())),
+                )
+                .collect();
+            let (spans, open_spans) =
+                line_tokens_to_classed_spans(text, &line_ops, CLASS_STYLE, &mut ScopeStack::new());
+            for (_, op) in &ops {
+                scope_stack.apply(op);
+            }
+
+            html.push_str("<span class=\"");
+            html.push_str(&lines.classes(number));
+            html.push_str("\">");
+            html.push_str(&spans);
+            for _ in 0..open_spans.max(0) {
+                html.push_str("</span>");
+            }
+            html.push_str("\n</span>");
+        }
+        html.push_str("</pre>");
+
+        html
+    }
+
+    fn stylesheet(&self, theme: &str) -> Option<String> {
+        let theme = match theme {
+            "light" => self.light_theme.as_str(),
+            "dark" => self.dark_theme.as_str(),
+            theme => theme,
+        };
+
+        self.theme_set
+            .themes
+            .get(theme)
+            .map(|theme| css_for_theme_with_class_style(theme, CLASS_STYLE))
+    }
+}
+
+// syntect tokens (file extensions or syntax names) for a language, tried in order.
+#[cfg(any(test, feature = "editable"))]
+fn syntax_tokens(language: &Language) -> &'static [&'static str] {
+    match language {
+        Language::Rust => &["rs", "rust"],
+        Language::Bash => &["bash", "sh"],
+        Language::Python => &["py", "python"],
+        Language::Sql => &["sql"],
+        Language::Html => &["html"],
+        Language::Css => &["css"],
+        Language::Javascript => &["js", "javascript"],
+        Language::Typescript => &["ts", "typescript", "js"],
+        Language::Yaml => &["yaml", "yml"],
+        Language::C => &["c"],
+        Language::Cpp => &["cpp", "c++"],
+        Language::Haskell => &["hs", "haskell"],
+        Language::Go => &["go"],
+        Language::Toml => &["toml"],
+        Language::Json => &["json"],
+        Language::Dockerfile => &["dockerfile"],
+        Language::Markdown => &["md", "markdown"],
+        Language::Diff => &["diff", "patch"],
+        Language::PlainText => &["txt", "plain text"],
+    }
+}
+
+// Line ranges written as "3, 5-8", lines being numbered from 1.
 #[cfg(feature = "editable")]
-use syntect::{highlighting::ThemeSet, html::highlighted_html_for_string, parsing::SyntaxSet};
+#[derive(Debug, Default)]
+pub struct LineRanges(Vec<RangeInclusive<i32>>);
 
 #[cfg(feature = "editable")]
-pub fn highlight_code(code: &str, language: &str) -> String {
-    let syntax_set = SyntaxSet::load_defaults_newlines();
-    let theme_set = ThemeSet::load_defaults();
-    let theme = &theme_set.themes["base16-ocean.dark"];
-    let syntax = syntax_set
-        .find_syntax_by_token(&language.to_lowercase())
-        .unwrap();
+impl LineRanges {
+    fn contains(&self, line: i32) -> bool {
+        self.0.iter().any(|range| range.contains(&line))
+    }
+}
+
+#[cfg(feature = "editable")]
+impl FromStr for LineRanges {
+    type Err = String;
+
+    fn from_str(ranges: &str) -> Result<Self, Self::Err> {
+        ranges
+            .split(',')
+            .map(str::trim)
+            .filter(|range| !range.is_empty())
+            .map(|range| {
+                let (start, end) = range.split_once('-').unwrap_or((range, range));
+                match (start.trim().parse::<i32>(), end.trim().parse::<i32>()) {
+                    (Ok(start), Ok(end)) if 0 < start && start <= end => Ok(start..=end),
+                    _ => Err(format!(
+                        "Invalid line range \"{}\", expected a line (\"3\") or a range (\"5-8\").",
+                        range
+                    )),
+                }
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+// Numbering and annotations of the lines of a Code content. Line ranges refer to the displayed line numbers.
+#[cfg(feature = "editable")]
+pub struct CodeLines {
+    start_line: i32,
+    emphasized: LineRanges,
+    added: LineRanges,
+    removed: LineRanges,
+}
+
+#[cfg(feature = "editable")]
+impl CodeLines {
+    // Invalid ranges are ignored, they are rejected by the validation before reaching this point.
+    pub fn new(
+        start_line: Option<i32>,
+        emphasized: Option<&str>,
+        added: Option<&str>,
+        removed: Option<&str>,
+    ) -> Self {
+        let ranges = |ranges: Option<&str>| {
+            ranges
+                .and_then(|ranges| ranges.parse().ok())
+                .unwrap_or_default()
+        };
+        Self {
+            start_line: start_line.unwrap_or(1),
+            emphasized: ranges(emphasized),
+            added: ranges(added),
+            removed: ranges(removed),
+        }
+    }
+
+    fn classes(&self, line: i32) -> String {
+        let mut classes = String::from("syn-line");
+        if self.emphasized.contains(line) {
+            classes.push_str(" syn-line-emphasized");
+        }
+        if self.added.contains(line) {
+            classes.push_str(" syn-line-added");
+        } else if self.removed.contains(line) {
+            classes.push_str(" syn-line-removed");
+        }
+        classes
+    }
+}
+
+#[cfg(feature = "editable")]
+pub fn highlight_code(code: &str, language: &Language, lines: &CodeLines) -> String {
+    HIGHLIGHTER.read().unwrap().highlight(code, language, lines)
+}
+
+// CSS for a theme, by name or through the "light" and "dark" aliases.
+pub fn stylesheet(theme: &str) -> Option<String> {
+    HIGHLIGHTER.read().unwrap().stylesheet(theme)
+}
+
+pub fn themes() -> Vec<String> {
+    HIGHLIGHTER
+        .read()
+        .unwrap()
+        .theme_set
+        .themes
+        .keys()
+        .cloned()
+        .collect()
+}
+
+// Reload the syntaxes and themes (defaults and HIGHLIGHTING_DIR) and the selected themes.
+#[cfg(feature = "editable")]
+pub fn reload() {
+    let highlighter = Highlighter::load();
+    *HIGHLIGHTER.write().unwrap() = highlighter;
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::HIGHLIGHTER, crate::types::language::Language};
 
-    highlighted_html_for_string(code, &syntax_set, syntax, theme)
+    #[test]
+    fn every_language_resolves_to_a_syntax() {
+        let highlighter = HIGHLIGHTER.read().unwrap();
+        for language in Language::ALL.iter() {
+            assert!(
+                highlighter.syntax_for(language).is_some(),
+                "No syntax found for {}",
+                language
+            );
+        }
+    }
 }
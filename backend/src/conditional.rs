@@ -0,0 +1,88 @@
+/*
+ * Conditional GET support for the JSON API.
+ * Every response carries a strong ETag (SHA-256 of the serialized body) and, when the resource knows when it was last changed,
+ * a Last-Modified header. Requests whose If-None-Match (or, when absent, If-Modified-Since) validators still match are answered
+ * with an empty 304 Not Modified.
+ * Lists only get an ETag: an item leaving them (deleted or unpublished) would not move their latest update.
+ */
+use {
+    actix_web::{
+        http::header::{self, HeaderValue, HttpDate},
+        web::Bytes,
+        HttpRequest, HttpResponse,
+    },
+    chrono::NaiveDateTime,
+    sha2::{Digest, Sha256},
+    std::{
+        str::FromStr,
+        time::{Duration, SystemTime, UNIX_EPOCH},
+    },
+};
+
+pub fn etag(body: &[u8]) -> String {
+    format!("\"{:x}\"", Sha256::digest(body))
+}
+
+// Timestamps are stored without a time zone, HTTP dates only have a one second precision.
+fn to_http_date(datetime: &NaiveDateTime) -> HttpDate {
+    let seconds = datetime.and_utc().timestamp().max(0) as u64;
+    HttpDate::from(UNIX_EPOCH + Duration::from_secs(seconds))
+}
+
+fn etag_matches(req: &HttpRequest, etag: &str) -> Option<bool> {
+    let if_none_match = req.headers().get(header::IF_NONE_MATCH)?.to_str().ok()?;
+
+    Some(if_none_match.split(',').any(|candidate| {
+        let candidate = candidate.trim();
+        candidate == "*" || candidate.trim_start_matches("W/") == etag
+    }))
+}
+
+fn not_modified_since(req: &HttpRequest, last_modified: &HttpDate) -> bool {
+    req.headers()
+        .get(header::IF_MODIFIED_SINCE)
+        .and_then(|since| since.to_str().ok())
+        .and_then(|since| HttpDate::from_str(since).ok())
+        .map_or(false, |since| {
+            SystemTime::from(last_modified.clone()) <= SystemTime::from(since)
+        })
+}
+
+pub fn is_fresh(req: &HttpRequest, etag: &str, last_modified: Option<&HttpDate>) -> bool {
+    // If-Modified-Since is ignored when If-None-Match is present (RFC 7232, section 6).
+    match etag_matches(req, etag) {
+        Some(matches) => matches,
+        None => last_modified.map_or(false, |last_modified| {
+            not_modified_since(req, last_modified)
+        }),
+    }
+}
+
+// Build a response from an already serialized JSON body and its validators.
+pub fn respond_with_body(
+    req: &HttpRequest,
+    body: Bytes,
+    etag: &str,
+    last_modified: Option<&NaiveDateTime>,
+) -> HttpResponse {
+    let last_modified = last_modified.map(to_http_date);
+    let fresh = is_fresh(req, etag, last_modified.as_ref());
+    let mut response = if fresh {
+        HttpResponse::NotModified()
+    } else {
+        HttpResponse::Ok()
+    };
+
+    response
+        .header(header::CACHE_CONTROL, HeaderValue::from_static("no-cache"))
+        .header(header::ETAG, etag);
+    if let Some(last_modified) = last_modified {
+        response.header(header::LAST_MODIFIED, last_modified.to_string());
+    }
+
+    if fresh {
+        response.finish()
+    } else {
+        response.content_type("application/json").body(body)
+    }
+}
@@ -0,0 +1,423 @@
+/*
+ * Structural diff between two revisions of an article: the fields of the header, then the chapters in the order of
+ * the newer revision, each one with its contents. Chapters, contents and references are matched by id, so that a
+ * content moved to another chapter is listed as modified (its chapter_id changed) under its new chapter.
+ * Unchanged chapters without changed contents are left out.
+ */
+use {
+    crate::{
+        models::{
+            chapters::Chapter, contents::Content, references::Reference, revisions::ArticleSnapshot,
+        },
+        types::content_type::ContentType,
+    },
+    serde::Serialize,
+    serde_json::{Map, Value},
+};
+
+// Fields computed from the others or by the database, which would only repeat their changes.
+const IGNORED_FIELDS: [&str; 6] = [
+    "id",
+    "article_id",
+    "updated",
+    "highlighted_code",
+    "rendered_html",
+    "plain_text",
+];
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Change {
+    Added,
+    Removed,
+    Modified,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+pub struct FieldChange {
+    pub field: String,
+    pub before: Value,
+    pub after: Value,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ContentDiff {
+    pub id: i32,
+    pub content_type: ContentType,
+    pub change: Change,
+    pub fields: Vec<FieldChange>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ChapterDiff {
+    pub id: i32,
+    pub title: String,
+    // None when only the contents of the chapter changed.
+    pub change: Option<Change>,
+    pub fields: Vec<FieldChange>,
+    pub contents: Vec<ContentDiff>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReferenceDiff {
+    pub id: i32,
+    pub key: String,
+    pub change: Change,
+    pub fields: Vec<FieldChange>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RevisionDiff {
+    pub from: i32,
+    pub to: i32,
+    pub fields: Vec<FieldChange>,
+    pub chapters: Vec<ChapterDiff>,
+    pub references: Vec<ReferenceDiff>,
+}
+
+fn to_object<T: Serialize>(value: Option<&T>) -> Map<String, Value> {
+    match value.map(serde_json::to_value) {
+        Some(Ok(Value::Object(object))) => object,
+        _ => Map::new(),
+    }
+}
+
+// Every field of an added (or removed) row is listed with a null before (or after) value.
+fn field_changes<T: Serialize>(before: Option<&T>, after: Option<&T>) -> Vec<FieldChange> {
+    let (before, mut after) = (to_object(before), to_object(after));
+    let mut changes: Vec<FieldChange> = before
+        .into_iter()
+        .filter_map(|(field, before)| {
+            let after = after.remove(&field).unwrap_or(Value::Null);
+            (before != after).then(|| FieldChange {
+                field,
+                before,
+                after,
+            })
+        })
+        .collect();
+    changes.extend(after.into_iter().map(|(field, after)| FieldChange {
+        field,
+        before: Value::Null,
+        after,
+    }));
+    changes.retain(|change| !IGNORED_FIELDS.contains(&change.field.as_str()));
+    changes
+}
+
+fn change_of<T>(before: Option<&T>, after: Option<&T>, fields: &[FieldChange]) -> Option<Change> {
+    match (before, after) {
+        (None, Some(_)) => Some(Change::Added),
+        (Some(_), None) => Some(Change::Removed),
+        _ if !fields.is_empty() => Some(Change::Modified),
+        _ => None,
+    }
+}
+
+fn content_diffs(
+    from: &ArticleSnapshot,
+    to: &ArticleSnapshot,
+    chapter_id: i32,
+) -> Vec<ContentDiff> {
+    let find = |snapshot: &ArticleSnapshot, id: i32| -> Option<Content> {
+        snapshot
+            .contents
+            .iter()
+            .find(|content| content.id == id)
+            .cloned()
+    };
+    // Contents of the chapter in the newer revision, then the ones removed from it.
+    let ids = to
+        .contents
+        .iter()
+        .filter(|content| content.chapter_id == chapter_id)
+        .map(|content| content.id)
+        .chain(
+            from.contents
+                .iter()
+                .filter(|content| {
+                    content.chapter_id == chapter_id && find(to, content.id).is_none()
+                })
+                .map(|content| content.id),
+        );
+
+    ids.filter_map(|id| {
+        let (before, after) = (find(from, id), find(to, id));
+        let fields = field_changes(before.as_ref(), after.as_ref());
+        let change = change_of(before.as_ref(), after.as_ref(), &fields)?;
+        Some(ContentDiff {
+            id,
+            content_type: after.or(before)?.content_type,
+            change,
+            fields,
+        })
+    })
+    .collect()
+}
+
+fn chapter_diffs(from: &ArticleSnapshot, to: &ArticleSnapshot) -> Vec<ChapterDiff> {
+    let find = |snapshot: &ArticleSnapshot, id: i32| -> Option<Chapter> {
+        snapshot
+            .chapters
+            .iter()
+            .find(|chapter| chapter.id == id)
+            .cloned()
+    };
+    let ids = to.chapters.iter().map(|chapter| chapter.id).chain(
+        from.chapters
+            .iter()
+            .filter(|chapter| find(to, chapter.id).is_none())
+            .map(|chapter| chapter.id),
+    );
+
+    ids.filter_map(|id| {
+        let (before, after) = (find(from, id), find(to, id));
+        let fields = field_changes(before.as_ref(), after.as_ref());
+        let change = change_of(before.as_ref(), after.as_ref(), &fields);
+        let contents = content_diffs(from, to, id);
+        if change.is_none() && contents.is_empty() {
+            return None;
+        }
+        Some(ChapterDiff {
+            id,
+            title: after.or(before)?.title,
+            change,
+            fields,
+            contents,
+        })
+    })
+    .collect()
+}
+
+fn reference_diffs(from: &ArticleSnapshot, to: &ArticleSnapshot) -> Vec<ReferenceDiff> {
+    let find = |snapshot: &ArticleSnapshot, id: i32| -> Option<Reference> {
+        snapshot
+            .references
+            .iter()
+            .find(|reference| reference.id == id)
+            .cloned()
+    };
+    let ids = to.references.iter().map(|reference| reference.id).chain(
+        from.references
+            .iter()
+            .filter(|reference| find(to, reference.id).is_none())
+            .map(|reference| reference.id),
+    );
+
+    ids.filter_map(|id| {
+        let (before, after) = (find(from, id), find(to, id));
+        let fields = field_changes(before.as_ref(), after.as_ref());
+        let change = change_of(before.as_ref(), after.as_ref(), &fields)?;
+        Some(ReferenceDiff {
+            id,
+            key: after.or(before)?.key,
+            change,
+            fields,
+        })
+    })
+    .collect()
+}
+
+pub fn diff_revisions(
+    (from_id, from): (i32, &ArticleSnapshot),
+    (to_id, to): (i32, &ArticleSnapshot),
+) -> RevisionDiff {
+    let mut fields = field_changes(Some(&from.article), Some(&to.article));
+    let labels = |snapshot: &ArticleSnapshot| -> Vec<String> {
+        snapshot.tags.iter().map(|tag| tag.label.clone()).collect()
+    };
+    let (tags_before, tags_after) = (labels(from), labels(to));
+    if tags_before != tags_after {
+        fields.push(FieldChange {
+            field: "tags".to_owned(),
+            before: Value::from(tags_before),
+            after: Value::from(tags_after),
+        });
+    }
+
+    RevisionDiff {
+        from: from_id,
+        to: to_id,
+        fields,
+        chapters: chapter_diffs(from, to),
+        references: reference_diffs(from, to),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{diff_revisions, Change, RevisionDiff},
+        crate::{
+            models::{
+                articles::Article, chapters::Chapter, contents::Content, references::Reference,
+                revisions::ArticleSnapshot, tags::Tag,
+            },
+            types::content_type::ContentType,
+        },
+        serde_json::Value,
+    };
+
+    fn snapshot() -> ArticleSnapshot {
+        ArticleSnapshot {
+            article: Article {
+                id: 1,
+                title: "Title".to_owned(),
+                pub_date: "2021-12-01T00:00:00".parse().unwrap(),
+                published: false,
+                headline: "Headline".to_owned(),
+                image: String::new(),
+                image_alt: String::new(),
+                image_credits: None,
+                updated: None,
+                publish_at: None,
+                unpublish_at: None,
+            },
+            tags: vec![Tag {
+                id: 1,
+                label: "rust".to_owned(),
+            }],
+            chapters: vec![chapter(1, 0, "First"), chapter(2, 1, "Second")],
+            contents: vec![content(1, 1, 0, "One"), content(2, 2, 0, "Two")],
+            references: vec![reference(1, 0, "knuth")],
+        }
+    }
+
+    fn chapter(id: i32, index: i32, title: &str) -> Chapter {
+        Chapter {
+            id,
+            article_id: 1,
+            index,
+            title: title.to_owned(),
+        }
+    }
+
+    fn content(id: i32, chapter_id: i32, index: i32, text: &str) -> Content {
+        Content {
+            id,
+            article_id: 1,
+            chapter_id,
+            index,
+            content_type: ContentType::Text,
+            content: text.to_owned(),
+            language: None,
+            highlighted_code: None,
+            url: None,
+            caption: None,
+            start_line: None,
+            emphasized_lines: None,
+            added_lines: None,
+            removed_lines: None,
+            data: None,
+            rendered_html: Some(text.to_owned()),
+            plain_text: text.to_owned(),
+        }
+    }
+
+    fn reference(id: i32, index: i32, key: &str) -> Reference {
+        Reference {
+            id,
+            article_id: 1,
+            index,
+            key: key.to_owned(),
+            title: key.to_owned(),
+            url: None,
+        }
+    }
+
+    fn diff(from: &ArticleSnapshot, to: &ArticleSnapshot) -> RevisionDiff {
+        diff_revisions((1, from), (2, to))
+    }
+
+    fn field_names(fields: &[super::FieldChange]) -> Vec<&str> {
+        fields.iter().map(|field| field.field.as_str()).collect()
+    }
+
+    #[test]
+    fn identical_revisions_have_no_changes() {
+        let diff = diff(&snapshot(), &snapshot());
+        assert!(diff.fields.is_empty());
+        assert!(diff.chapters.is_empty());
+        assert!(diff.references.is_empty());
+    }
+
+    #[test]
+    fn header_and_tags_changes_leave_out_computed_fields() {
+        let mut to = snapshot();
+        to.article.title = "New title".to_owned();
+        to.article.updated = Some(to.article.pub_date);
+        to.tags.push(Tag {
+            id: 2,
+            label: "web".to_owned(),
+        });
+
+        let diff = diff(&snapshot(), &to);
+        assert_eq!(field_names(&diff.fields), ["title", "tags"]);
+        assert_eq!(diff.fields[0].before, Value::from("Title"));
+        assert_eq!(diff.fields[0].after, Value::from("New title"));
+        assert_eq!(diff.fields[1].after, Value::from(vec!["rust", "web"]));
+    }
+
+    #[test]
+    fn modified_content_is_listed_under_its_chapter() {
+        let mut to = snapshot();
+        to.contents[1] = content(2, 2, 0, "Two, edited");
+
+        let diff = diff(&snapshot(), &to);
+        assert_eq!(diff.chapters.len(), 1);
+        let chapter = &diff.chapters[0];
+        assert_eq!((chapter.id, chapter.change), (2, None));
+        assert_eq!(chapter.contents.len(), 1);
+        assert_eq!(chapter.contents[0].change, Change::Modified);
+        // The rendered HTML and the plain text follow the content.
+        assert_eq!(field_names(&chapter.contents[0].fields), ["content"]);
+    }
+
+    #[test]
+    fn moved_content_is_modified_under_its_new_chapter() {
+        let mut to = snapshot();
+        to.contents[0] = content(1, 2, 1, "One");
+
+        let diff = diff(&snapshot(), &to);
+        assert_eq!(diff.chapters.len(), 1);
+        assert_eq!(diff.chapters[0].id, 2);
+        let moved = &diff.chapters[0].contents[0];
+        assert_eq!((moved.id, moved.change), (1, Change::Modified));
+        assert_eq!(field_names(&moved.fields), ["chapter_id", "index"]);
+    }
+
+    #[test]
+    fn added_and_removed_rows_are_listed_after_the_others() {
+        let mut to = snapshot();
+        to.chapters.remove(0);
+        to.contents.remove(0);
+        to.chapters.push(chapter(3, 1, "Third"));
+        to.references = vec![reference(2, 0, "dijkstra")];
+
+        let diff = diff(&snapshot(), &to);
+        let chapters: Vec<(i32, Option<Change>)> = diff
+            .chapters
+            .iter()
+            .map(|chapter| (chapter.id, chapter.change))
+            .collect();
+        assert_eq!(
+            chapters,
+            [(3, Some(Change::Added)), (1, Some(Change::Removed))]
+        );
+        assert_eq!(diff.chapters[1].contents[0].change, Change::Removed);
+        assert!(diff.chapters[0]
+            .fields
+            .iter()
+            .all(|field| field.before.is_null()));
+
+        let references: Vec<(&str, Change)> = diff
+            .references
+            .iter()
+            .map(|reference| (reference.key.as_str(), reference.change))
+            .collect();
+        assert_eq!(
+            references,
+            [("dijkstra", Change::Added), ("knuth", Change::Removed)]
+        );
+    }
+}
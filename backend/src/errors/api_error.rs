@@ -0,0 +1,143 @@
+/*
+ * Every handler error ends up as an ApiError, answered with a JSON body:
+ *     { "code": "validation", "message": "Invalid input.", "fields": [{ "field": "title", "message": "..." }] }
+ * Diesel errors are mapped from their kind: NotFound is a 404, unique and foreign key violations are a 409 and anything else is a 500.
+ * When the pool cannot hand out a connection (or the blocking thread pool is gone), a 503 is returned instead of panicking.
+ */
+use {
+    actix_web::{error::BlockingError, http::StatusCode, HttpResponse, ResponseError},
+    derive_more::Display,
+    diesel::{
+        r2d2::PoolError,
+        result::{DatabaseErrorKind, Error as DieselError},
+    },
+    log::error,
+    serde::Serialize,
+    std::fmt::Debug,
+};
+
+#[cfg(feature = "editable")]
+use actix_web::{error::JsonPayloadError, HttpRequest};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+#[cfg(feature = "editable")]
+impl FieldError {
+    pub fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
+// Validation errors are only raised by the editable API.
+#[cfg_attr(not(feature = "editable"), allow(dead_code))]
+#[derive(Debug, Display)]
+pub enum ApiError {
+    #[display(fmt = "Invalid input.")]
+    Validation(Vec<FieldError>),
+    #[display(fmt = "Resource not found.")]
+    NotFound,
+    #[display(fmt = "{}", _0)]
+    Conflict(String),
+    #[display(fmt = "Service temporarily unavailable.")]
+    Unavailable,
+    #[display(fmt = "Internal server error.")]
+    Internal,
+}
+
+#[derive(Serialize)]
+struct ErrorBody<'a> {
+    code: &'a str,
+    message: String,
+    fields: &'a [FieldError],
+}
+
+impl ApiError {
+    #[cfg(feature = "editable")]
+    pub fn field(field: &str, message: &str) -> Self {
+        ApiError::Validation(vec![FieldError::new(field, message)])
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            ApiError::Validation(_) => "validation",
+            ApiError::NotFound => "not_found",
+            ApiError::Conflict(_) => "conflict",
+            ApiError::Unavailable => "unavailable",
+            ApiError::Internal => "internal",
+        }
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+impl ResponseError for ApiError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            ApiError::Validation(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            ApiError::NotFound => StatusCode::NOT_FOUND,
+            ApiError::Conflict(_) => StatusCode::CONFLICT,
+            ApiError::Unavailable => StatusCode::SERVICE_UNAVAILABLE,
+            ApiError::Internal => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        let fields = match self {
+            ApiError::Validation(fields) => fields.as_slice(),
+            _ => &[],
+        };
+        HttpResponse::build(self.status_code()).json(ErrorBody {
+            code: self.code(),
+            message: self.to_string(),
+            fields,
+        })
+    }
+}
+
+impl From<DieselError> for ApiError {
+    fn from(error: DieselError) -> Self {
+        match error {
+            DieselError::NotFound => ApiError::NotFound,
+            DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, _) => {
+                ApiError::Conflict("A resource with the same value already exists.".to_owned())
+            }
+            DieselError::DatabaseError(DatabaseErrorKind::ForeignKeyViolation, _) => {
+                ApiError::Conflict("The resource is linked to another resource.".to_owned())
+            }
+            error => {
+                error!("Database error: {}", error);
+                ApiError::Internal
+            }
+        }
+    }
+}
+
+impl From<PoolError> for ApiError {
+    fn from(error: PoolError) -> Self {
+        error!("Could not get a database connection: {}", error);
+        ApiError::Unavailable
+    }
+}
+
+// Malformed JSON bodies are reported like any other validation error.
+#[cfg(feature = "editable")]
+pub fn json_error(error: JsonPayloadError, _: &HttpRequest) -> actix_web::Error {
+    ApiError::field("body", &error.to_string()).into()
+}
+
+// Queries run in actix_web::web::block, which wraps their error in a BlockingError<_>.
+impl<E: Into<ApiError> + Debug> From<BlockingError<E>> for ApiError {
+    fn from(error: BlockingError<E>) -> Self {
+        match error {
+            BlockingError::Error(error) => error.into(),
+            BlockingError::Canceled => ApiError::Unavailable,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+/*
+ * Errors raised while indexing or deleting media.
+ * An asset that is still referenced by an article, a content or a project cannot be deleted (409 Conflict).
+ */
+use {
+    super::api_error::ApiError,
+    derive_more::{Display, From},
+    diesel::result::Error as DieselError,
+    std::io,
+};
+
+#[derive(Display, From, Debug)]
+pub enum MediaError {
+    Database(DieselError),
+    Io(io::Error),
+    #[display(fmt = "Media is still referenced.")]
+    InUse,
+}
+
+impl From<MediaError> for ApiError {
+    fn from(error: MediaError) -> Self {
+        match error {
+            MediaError::Database(error) => error.into(),
+            MediaError::Io(error) if error.kind() == io::ErrorKind::NotFound => ApiError::NotFound,
+            MediaError::Io(error) => {
+                log::error!("Media error: {}", error);
+                ApiError::Internal
+            }
+            MediaError::InUse => ApiError::Conflict(MediaError::InUse.to_string()),
+        }
+    }
+}
@@ -1 +1,3 @@
-pub mod database_error;
+pub mod api_error;
+#[cfg(feature = "editable")]
+pub mod media_error;
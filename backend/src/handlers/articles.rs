@@ -1,58 +1,91 @@
 use {
-    crate::{errors::database_error::DatabaseError, models::articles::Article, Pool},
-    actix_web::{web, Error, HttpResponse},
+    crate::{
+        cache::{Dependency, ResponseCache},
+        errors::api_error::ApiError,
+        html::export_article as export_article_html,
+        interfaces::RelatedQuery,
+        markdown::export_article,
+        models::{articles::Article, visibility::Visibility},
+        service::related::{related as related_service, RelatedItem},
+        Pool,
+    },
+    actix_web::{web, HttpRequest, HttpResponse},
+    std::{collections::HashSet, iter::once},
 };
 
 #[cfg(feature = "editable")]
 use crate::{
     code::highlight_code,
-    interfaces::{InputArticle, InputPublishArticle},
+    diesel::{connection::Connection, PgConnection},
+    handlers::revisions::author,
+    interfaces::{InputArticle, InputPublishArticle, InputScheduleArticle},
     models::{
-        articles::{NewArticle, NewArticleHeader, NewArticleTag},
+        articles::{ArticleRepresentation, NewArticle, NewArticleHeader, NewArticleTag},
         chapters::{NewChapter, NewChapterForm},
         contents::NewContent,
+        references::NewReference,
+        revisions::Revision,
     },
+    rich_text::render_content,
+    service::media::measure_image,
     types::{content_type::ContentType, language::Language},
+    validation::validate,
 };
 
+// Insert a validated InputArticle with its chapters and contents, recording its first revision.
 #[cfg(feature = "editable")]
-pub async fn add(
-    pool: web::Data<Pool>,
-    json_article: web::Json<InputArticle>,
-) -> Result<HttpResponse, Error> {
-    let connection = pool.get().unwrap();
-    Ok(web::block(move || {
-        // TODO - InputArticle.
-        Article::add(
-            &NewArticle {
-                article_header: NewArticleHeader {
-                    title: &json_article.title,
-                    headline: &json_article.headline,
-                    published: json_article.published,
-                    image: &json_article.image,
-                    image_credits: Some(json_article.image_credits.as_deref().unwrap_or("")),
-                },
-                tags: json_article
-                    .tags
-                    .iter()
-                    .map(|t| NewArticleTag {
-                        article_id: 0,
-                        tag_id: t.id,
-                    })
-                    .collect(),
-                chapters: json_article
-                    .chapters
-                    .iter()
-                    .map(|chap| NewChapterForm {
-                        chapter: NewChapter {
-                            article_id: chap.article_id,
-                            index: chap.index,
-                            title: &chap.title,
-                        },
-                        contents: chap
-                            .contents
-                            .iter()
-                            .map(|cont| NewContent {
+fn insert(
+    input: &InputArticle,
+    author: &str,
+    connection: &PgConnection,
+) -> Result<ArticleRepresentation, diesel::result::Error> {
+    connection.transaction(|| {
+        let article = add_article(input, connection)?;
+        Revision::add(article.id, author, connection)?;
+        Ok(article)
+    })
+}
+
+#[cfg(feature = "editable")]
+fn add_article(
+    input: &InputArticle,
+    connection: &PgConnection,
+) -> Result<ArticleRepresentation, diesel::result::Error> {
+    Article::add(
+        &NewArticle {
+            article_header: NewArticleHeader {
+                title: &input.title,
+                headline: &input.headline,
+                published: input.published,
+                image: &input.image,
+                image_alt: &input.image_alt,
+                image_credits: Some(input.image_credits.as_deref().unwrap_or("")),
+                publish_at: input.publish_at,
+                unpublish_at: input.unpublish_at,
+            },
+            tags: input
+                .tags
+                .iter()
+                .map(|t| NewArticleTag {
+                    article_id: 0,
+                    tag_id: t.id,
+                })
+                .collect(),
+            chapters: input
+                .chapters
+                .iter()
+                .map(|chap| NewChapterForm {
+                    chapter: NewChapter {
+                        article_id: chap.article_id,
+                        index: chap.index,
+                        title: &chap.title,
+                    },
+                    contents: chap
+                        .contents
+                        .iter()
+                        .map(|cont| {
+                            let rendering = render_content(&cont.content_type, &cont.content);
+                            NewContent {
                                 article_id: cont.article_id,
                                 chapter_id: cont.chapter_id,
                                 index: cont.index,
@@ -64,77 +97,306 @@ pub async fn add(
                                             Some(language) => language,
                                             None => &Language::Bash,
                                         };
-                                        Some(highlight_code(&cont.content, &language.to_string()))
+                                        Some(highlight_code(
+                                            &cont.content,
+                                            language,
+                                            &cont.code_lines(),
+                                        ))
                                     }
                                     _ => None,
                                 },
                                 url: Some(cont.url.as_deref().unwrap_or("")),
                                 language: cont.language.clone(),
-                            })
-                            .collect(),
-                    })
-                    .collect(),
-            },
-            &connection,
-        )
-    })
-    .await
-    .map(|article| HttpResponse::Ok().json(article))
-    .map_err(DatabaseError)?)
+                                caption: cont.caption.as_deref(),
+                                start_line: cont.start_line,
+                                emphasized_lines: cont.emphasized_lines.as_deref(),
+                                added_lines: cont.added_lines.as_deref(),
+                                removed_lines: cont.removed_lines.as_deref(),
+                                data: measure_image(&cont.content_type, &cont.content, &cont.data),
+                                rendered_html: rendering.html,
+                                plain_text: rendering.plain_text,
+                            }
+                        })
+                        .collect(),
+                })
+                .collect(),
+            references: input
+                .references
+                .iter()
+                .map(|reference| NewReference {
+                    article_id: reference.article_id,
+                    index: reference.index,
+                    key: &reference.key,
+                    title: &reference.title,
+                    url: reference
+                        .url
+                        .as_deref()
+                        .filter(|url| !url.trim().is_empty()),
+                })
+                .collect(),
+        },
+        connection,
+    )
 }
 
 #[cfg(feature = "editable")]
-pub async fn update(
+pub async fn add(
+    req: HttpRequest,
     pool: web::Data<Pool>,
-    id: web::Path<i32>,
-    article: web::Json<Article>,
-) -> Result<HttpResponse, Error> {
-    let connection = pool.get().unwrap();
+    cache: web::Data<ResponseCache>,
+    json_article: web::Json<InputArticle>,
+) -> Result<HttpResponse, ApiError> {
+    validate(&*json_article)?;
+    let connection = pool.get()?;
+    let author = author(&req);
     Ok(
-        web::block(move || Article::update(*id, &article, &connection))
+        web::block(move || insert(&json_article, &author, &connection))
             .await
-            .map(|article| HttpResponse::Ok().json(article))
-            .map_err(DatabaseError)?,
+            .map(|article| {
+                cache.invalidate(&[
+                    Dependency::ArticleList,
+                    Dependency::TagResults,
+                    Dependency::Search,
+                ]);
+                HttpResponse::Ok().json(article)
+            })?,
     )
 }
 
+#[cfg(feature = "editable")]
+pub async fn update(
+    req: HttpRequest,
+    pool: web::Data<Pool>,
+    cache: web::Data<ResponseCache>,
+    id: web::Path<i32>,
+    article: web::Json<Article>,
+) -> Result<HttpResponse, ApiError> {
+    validate(&*article)?;
+    let connection = pool.get()?;
+    let (id, author) = (*id, author(&req));
+    Ok(web::block(move || {
+        Revision::record(id, &author, &connection, || {
+            Article::update(id, &article, &connection)
+        })
+    })
+    .await
+    .map(|article| {
+        cache.invalidate(&[Dependency::Article(id), Dependency::Search]);
+        HttpResponse::Ok().json(article)
+    })?)
+}
+
 #[cfg(feature = "editable")]
 pub async fn publish(
+    req: HttpRequest,
     pool: web::Data<Pool>,
+    cache: web::Data<ResponseCache>,
     id: web::Path<i32>,
     payload: web::Json<InputPublishArticle>,
-) -> Result<HttpResponse, Error> {
-    let connection = pool.get().unwrap();
-    let published = payload.published;
-    Ok(
-        web::block(move || Article::publish(*id, published, &connection))
-            .await
-            .map(|article| HttpResponse::Ok().json(article))
-            .map_err(DatabaseError)?,
-    )
+) -> Result<HttpResponse, ApiError> {
+    let connection = pool.get()?;
+    let (id, published, author) = (*id, payload.published, author(&req));
+    Ok(web::block(move || {
+        Revision::record(id, &author, &connection, || {
+            Article::publish(id, published, &connection)
+        })
+    })
+    .await
+    .map(|article| {
+        cache.invalidate(&[
+            Dependency::Article(id),
+            Dependency::ArticleList,
+            Dependency::TagResults,
+            Dependency::Search,
+        ]);
+        HttpResponse::Ok().json(article)
+    })?)
+}
+
+// Set (or cancel, with null times) the scheduled publication and unpublication of an article.
+#[cfg(feature = "editable")]
+pub async fn schedule(
+    req: HttpRequest,
+    pool: web::Data<Pool>,
+    cache: web::Data<ResponseCache>,
+    id: web::Path<i32>,
+    payload: web::Json<InputScheduleArticle>,
+) -> Result<HttpResponse, ApiError> {
+    validate(&*payload)?;
+    let connection = pool.get()?;
+    let (id, author) = (*id, author(&req));
+    Ok(web::block(move || {
+        Revision::record(id, &author, &connection, || {
+            Article::schedule(id, payload.publish_at, payload.unpublish_at, &connection)
+        })
+    })
+    .await
+    .map(|article| {
+        cache.invalidate(&[Dependency::Article(id)]);
+        HttpResponse::Ok().json(article)
+    })?)
 }
 
 #[cfg(feature = "editable")]
-pub async fn delete(pool: web::Data<Pool>, id: web::Path<i32>) -> Result<HttpResponse, Error> {
-    let connection = pool.get().unwrap();
-    Ok(web::block(move || Article::delete(*id, &connection))
+pub async fn scheduled(pool: web::Data<Pool>) -> Result<HttpResponse, ApiError> {
+    let connection = pool.get()?;
+    Ok(web::block(move || Article::scheduled(&connection))
         .await
-        .map(|response| HttpResponse::Ok().json(response))
-        .map_err(DatabaseError)?)
+        .map(|articles| HttpResponse::Ok().json(articles))?)
 }
 
-pub async fn list(pool: web::Data<Pool>) -> Result<HttpResponse, Error> {
-    let connection = pool.get().unwrap();
-    Ok(web::block(move || Article::list(&connection))
+#[cfg(feature = "editable")]
+pub async fn delete(
+    pool: web::Data<Pool>,
+    cache: web::Data<ResponseCache>,
+    id: web::Path<i32>,
+) -> Result<HttpResponse, ApiError> {
+    let connection = pool.get()?;
+    let id = *id;
+    Ok(web::block(move || Article::delete(id, &connection))
         .await
-        .map(|articles| HttpResponse::Ok().json(articles))
-        .map_err(DatabaseError)?)
+        .map(|response| {
+            cache.invalidate(&[Dependency::Article(id)]);
+            HttpResponse::Ok().json(response)
+        })?)
 }
 
-pub async fn get(pool: web::Data<Pool>, id: web::Path<i32>) -> Result<HttpResponse, Error> {
-    let connection = pool.get().unwrap();
-    Ok(web::block(move || Article::get(*id, &connection))
+pub async fn list(
+    req: HttpRequest,
+    pool: web::Data<Pool>,
+    cache: web::Data<ResponseCache>,
+    visibility: web::Data<Visibility>,
+) -> Result<HttpResponse, ApiError> {
+    let key = cache.key(&req);
+    if let Some(response) = cache.respond(&req, &key) {
+        return Ok(response);
+    }
+
+    let connection = pool.get()?;
+    let visibility = **visibility;
+    Ok(web::block(move || Article::list(visibility, &connection))
         .await
-        .map(|article| HttpResponse::Ok().json(article))
-        .map_err(DatabaseError)?)
+        .map(|articles| {
+            // No Last-Modified: unpublishing or deleting an article changes the list, not its latest update.
+            let dependencies: HashSet<Dependency> = articles
+                .keys()
+                .map(|id| Dependency::Article(*id))
+                .chain(once(Dependency::ArticleList))
+                .collect();
+            cache.store(&req, key, &articles, None, dependencies)
+        })?)
+}
+
+pub async fn get(
+    req: HttpRequest,
+    pool: web::Data<Pool>,
+    cache: web::Data<ResponseCache>,
+    visibility: web::Data<Visibility>,
+    id: web::Path<i32>,
+) -> Result<HttpResponse, ApiError> {
+    let key = cache.key(&req);
+    if let Some(response) = cache.respond(&req, &key) {
+        return Ok(response);
+    }
+
+    let connection = pool.get()?;
+    let (id, visibility) = (*id, **visibility);
+    Ok(
+        web::block(move || Article::get(id, visibility, &connection))
+            .await
+            .map(|article| {
+                let last_modified = *article.last_modified();
+                // The series box links to the neighbouring parts, whose titles and visibility may change.
+                let dependencies: HashSet<Dependency> = match &article.series {
+                    Some(series) => vec![
+                        Dependency::Article(id),
+                        Dependency::Series(series.id),
+                        Dependency::ArticleList,
+                    ]
+                    .into_iter()
+                    .collect(),
+                    None => once(Dependency::Article(id)).collect(),
+                };
+                cache.store(&req, key, &article, Some(&last_modified), dependencies)
+            })?,
+    )
+}
+
+const DEFAULT_RELATED: i64 = 5;
+const MAX_RELATED: i64 = 20;
+
+pub async fn related(
+    req: HttpRequest,
+    pool: web::Data<Pool>,
+    cache: web::Data<ResponseCache>,
+    visibility: web::Data<Visibility>,
+    id: web::Path<i32>,
+    query: web::Query<RelatedQuery>,
+) -> Result<HttpResponse, ApiError> {
+    let key = cache.key(&req);
+    if let Some(response) = cache.respond(&req, &key) {
+        return Ok(response);
+    }
+
+    let connection = pool.get()?;
+    let (id, visibility) = (*id, **visibility);
+    let limit = query.limit.unwrap_or(DEFAULT_RELATED).clamp(1, MAX_RELATED);
+    Ok(
+        web::block(move || related_service(&connection, id, limit, visibility))
+            .await
+            .map(|items| {
+                // The ranking changes with any edited, tagged or published article or project.
+                let dependencies: HashSet<Dependency> = items
+                    .iter()
+                    .map(|item| match item {
+                        RelatedItem::Article(article) => Dependency::Article(article.id),
+                        RelatedItem::Project(project) => Dependency::Project(project.id),
+                    })
+                    .chain(vec![
+                        Dependency::Article(id),
+                        Dependency::ArticleList,
+                        Dependency::ProjectList,
+                        Dependency::TagResults,
+                        Dependency::Search,
+                    ])
+                    .collect();
+                cache.store(&req, key, &items, None, dependencies)
+            })?,
+    )
+}
+
+pub async fn export_markdown(
+    pool: web::Data<Pool>,
+    visibility: web::Data<Visibility>,
+    id: web::Path<i32>,
+) -> Result<HttpResponse, ApiError> {
+    let connection = pool.get()?;
+    let (id, visibility) = (*id, **visibility);
+    Ok(
+        web::block(move || Article::get(id, visibility, &connection))
+            .await
+            .map(|article| {
+                HttpResponse::Ok()
+                    .content_type("text/markdown; charset=utf-8")
+                    .body(export_article(&article))
+            })?,
+    )
+}
+
+pub async fn export_html(
+    pool: web::Data<Pool>,
+    visibility: web::Data<Visibility>,
+    id: web::Path<i32>,
+) -> Result<HttpResponse, ApiError> {
+    let connection = pool.get()?;
+    let (id, visibility) = (*id, **visibility);
+    Ok(
+        web::block(move || Article::get(id, visibility, &connection))
+            .await
+            .map(|article| {
+                HttpResponse::Ok()
+                    .content_type("text/html; charset=utf-8")
+                    .body(export_article_html(&article))
+            })?,
+    )
 }
@@ -0,0 +1,8 @@
+use {
+    crate::cache::ResponseCache,
+    actix_web::{web, HttpResponse},
+};
+
+pub async fn stats(cache: web::Data<ResponseCache>) -> HttpResponse {
+    HttpResponse::Ok().json(cache.stats())
+}
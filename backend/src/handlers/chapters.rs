@@ -1,58 +1,150 @@
 #[cfg(feature = "editable")]
 use {
     crate::{
-        errors::database_error::DatabaseError,
-        interfaces::InputChapter,
+        cache::{Dependency, ResponseCache},
+        errors::api_error::ApiError,
+        handlers::revisions::author,
+        interfaces::{InputChapter, InputChapterMove},
         models::{
             articles::Article,
             chapters::{Chapter, NewChapter},
+            revisions::Revision,
+            visibility::Visibility,
         },
+        validation::{validate, Validate, Violations},
         Pool,
     },
-    actix_web::{web, Error, HttpResponse},
+    actix_web::{web, HttpRequest, HttpResponse},
 };
 
 #[cfg(feature = "editable")]
 pub async fn update(
+    req: HttpRequest,
     pool: web::Data<Pool>,
+    cache: web::Data<ResponseCache>,
     id: web::Path<i32>,
     body: web::Json<Chapter>,
-) -> Result<HttpResponse, Error> {
-    let connection = pool.get().unwrap();
-    Ok(web::block(move || Chapter::update(*id, &body, &connection))
-        .await
-        .map(|article| HttpResponse::Ok().json(article))
-        .map_err(DatabaseError)?)
+) -> Result<HttpResponse, ApiError> {
+    validate(&*body)?;
+    let connection = pool.get()?;
+    let author = author(&req);
+    Ok(web::block(move || {
+        // The chapter stays in its article and at its index, whatever the client sent.
+        let chapter = Chapter::find(*id, &connection)?;
+        let article = Revision::record(chapter.article_id, &author, &connection, || {
+            Chapter::update(&chapter, &body, &connection)
+        })?;
+        Ok::<_, diesel::result::Error>((chapter.article_id, article))
+    })
+    .await
+    .map(|(article_id, article)| {
+        cache.invalidate(&[Dependency::Article(article_id)]);
+        HttpResponse::Ok().json(article)
+    })?)
 }
 
 #[cfg(feature = "editable")]
 pub async fn add(
+    req: HttpRequest,
     pool: web::Data<Pool>,
+    cache: web::Data<ResponseCache>,
     json_chapter: web::Json<InputChapter>,
-) -> Result<HttpResponse, Error> {
-    let connection = pool.get().unwrap();
-    let article_id = json_chapter.article_id;
+) -> Result<HttpResponse, ApiError> {
+    let connection = pool.get()?;
+    let (article_id, author) = (json_chapter.article_id, author(&req));
+    Ok(web::block(move || {
+        let siblings = Chapter::count(article_id, &connection)?;
+        let mut violations = Violations::default();
+        json_chapter.validate(&mut violations);
+        violations.check(
+            i64::from(json_chapter.index) <= siblings,
+            "index",
+            "Index should follow the existing chapters of the article.",
+        );
+        violations.into_result()?;
+
+        Revision::record(article_id, &author, &connection, || {
+            Chapter::add(
+                &NewChapter {
+                    article_id,
+                    index: json_chapter.index,
+                    title: &json_chapter.title,
+                },
+                &connection,
+            )
+        })?;
+        Ok::<_, ApiError>(Article::get(article_id, Visibility::Admin, &connection)?)
+    })
+    .await
+    .map(|article| {
+        cache.invalidate(&[Dependency::Article(article_id)]);
+        HttpResponse::Ok().json(article)
+    })?)
+}
+
+#[cfg(feature = "editable")]
+pub async fn delete(
+    req: HttpRequest,
+    pool: web::Data<Pool>,
+    cache: web::Data<ResponseCache>,
+    id: web::Path<i32>,
+) -> Result<HttpResponse, ApiError> {
+    let connection = pool.get()?;
+    let author = author(&req);
     Ok(web::block(move || {
-        Chapter::add(
-            &NewChapter {
-                article_id,
-                index: json_chapter.index,
-                title: &json_chapter.title,
-            },
-            &connection,
-        )?;
-        Article::get(article_id, &connection)
+        let article_id = Chapter::find(*id, &connection)?.article_id;
+        let response = Revision::record(article_id, &author, &connection, || {
+            Chapter::delete(*id, &connection)
+        })?;
+        Ok::<_, diesel::result::Error>((article_id, response))
     })
     .await
-    .map(|article| HttpResponse::Ok().json(article))
-    .map_err(DatabaseError)?)
+    .map(|(article_id, response)| {
+        cache.invalidate(&[Dependency::Article(article_id)]);
+        HttpResponse::Ok().json(response)
+    })?)
 }
 
 #[cfg(feature = "editable")]
-pub async fn delete(pool: web::Data<Pool>, id: web::Path<i32>) -> Result<HttpResponse, Error> {
-    let connection = pool.get().unwrap();
-    Ok(web::block(move || Chapter::delete(*id, &connection))
-        .await
-        .map(|response| HttpResponse::Ok().json(response))
-        .map_err(DatabaseError)?)
+pub async fn move_to(
+    req: HttpRequest,
+    pool: web::Data<Pool>,
+    cache: web::Data<ResponseCache>,
+    id: web::Path<i32>,
+    body: web::Json<InputChapterMove>,
+) -> Result<HttpResponse, ApiError> {
+    let connection = pool.get()?;
+    let (article_id, author) = (body.article_id, author(&req));
+    Ok(web::block(move || {
+        let chapter = Chapter::find(*id, &connection)?;
+        // A chapter moved inside its article is not one more sibling.
+        let siblings =
+            Chapter::count(article_id, &connection)? - i64::from(chapter.article_id == article_id);
+        let mut violations = Violations::default();
+        violations.check(
+            body.index >= 0 && i64::from(body.index) <= siblings,
+            "index",
+            "Index should follow the existing chapters of the article.",
+        );
+        violations.into_result()?;
+
+        // Both articles get a revision when the chapter changes of article.
+        let move_chapter = || Chapter::move_to(*id, article_id, body.index, &connection);
+        let article = if chapter.article_id == article_id {
+            Revision::record(article_id, &author, &connection, move_chapter)?
+        } else {
+            Revision::record(chapter.article_id, &author, &connection, || {
+                Revision::record(article_id, &author, &connection, move_chapter)
+            })?
+        };
+        Ok::<_, ApiError>((chapter.article_id, article))
+    })
+    .await
+    .map(|(previous_article_id, article)| {
+        cache.invalidate(&[
+            Dependency::Article(previous_article_id),
+            Dependency::Article(article_id),
+        ]);
+        HttpResponse::Ok().json(article)
+    })?)
 }
@@ -0,0 +1,61 @@
+use {
+    crate::{
+        code::{stylesheet as theme_stylesheet, themes as list_themes},
+        errors::api_error::ApiError,
+    },
+    actix_web::{
+        http::header::{self, HeaderValue},
+        web, HttpResponse,
+    },
+};
+
+#[cfg(feature = "editable")]
+use crate::{
+    cache::{Dependency, ResponseCache},
+    code::reload,
+    interfaces::{Status, TAPIResponse},
+    models::contents::Content,
+    Pool,
+};
+
+pub async fn themes() -> HttpResponse {
+    HttpResponse::Ok().json(list_themes())
+}
+
+// Stylesheet colouring the highlighted code for a theme ("light", "dark" or any theme name).
+pub async fn stylesheet(theme: web::Path<String>) -> Result<HttpResponse, ApiError> {
+    let css = theme_stylesheet(&theme).ok_or(ApiError::NotFound)?;
+
+    Ok(HttpResponse::Ok()
+        .content_type("text/css; charset=utf-8")
+        .header(
+            header::CACHE_CONTROL,
+            HeaderValue::from_static("public, max-age=3600"),
+        )
+        .body(css))
+}
+
+// Reload the syntaxes and themes, then highlight every stored Code content again.
+#[cfg(feature = "editable")]
+pub async fn rehighlight(
+    pool: web::Data<Pool>,
+    cache: web::Data<ResponseCache>,
+) -> Result<HttpResponse, ApiError> {
+    let connection = pool.get()?;
+    let article_ids = web::block(move || {
+        reload();
+        Content::rehighlight_all(&connection)
+    })
+    .await?;
+
+    let dependencies: Vec<Dependency> = article_ids
+        .iter()
+        .map(|id| Dependency::Article(*id))
+        .collect();
+    cache.invalidate(&dependencies);
+
+    Ok(HttpResponse::Ok().json(TAPIResponse {
+        status: Status::Success,
+        content: Some(article_ids),
+    }))
+}
@@ -1,78 +1,186 @@
 #[cfg(feature = "editable")]
 use {
     crate::{
+        cache::{Dependency, ResponseCache},
         code::highlight_code,
-        errors::database_error::DatabaseError,
-        interfaces::InputContent,
+        errors::api_error::ApiError,
+        handlers::revisions::author,
+        interfaces::{InputContent, InputContentMove},
         models::{
             articles::Article,
+            chapters::Chapter,
             contents::{Content, NewContent},
+            revisions::Revision,
+            visibility::Visibility,
         },
+        rich_text::render_content,
+        service::media::measure_image,
         types::content_type::ContentType,
+        validation::{validate, Validate, Violations},
         Pool,
     },
-    actix_web::{web, Error, HttpResponse},
+    actix_web::{web, HttpRequest, HttpResponse},
 };
 
 #[cfg(feature = "editable")]
-pub async fn delete(pool: web::Data<Pool>, id: web::Path<i32>) -> Result<HttpResponse, Error> {
-    let connection = pool.get().unwrap();
-    Ok(web::block(move || Content::delete(*id, &connection))
-        .await
-        .map(|response| HttpResponse::Ok().json(response))
-        .map_err(DatabaseError)?)
+pub async fn delete(
+    req: HttpRequest,
+    pool: web::Data<Pool>,
+    cache: web::Data<ResponseCache>,
+    id: web::Path<i32>,
+) -> Result<HttpResponse, ApiError> {
+    let connection = pool.get()?;
+    let author = author(&req);
+    Ok(web::block(move || {
+        let article_id = Content::find(*id, &connection)?.article_id;
+        let response = Revision::record(article_id, &author, &connection, || {
+            Content::delete(*id, &connection)
+        })?;
+        Ok::<_, diesel::result::Error>((article_id, response))
+    })
+    .await
+    .map(|(article_id, response)| {
+        cache.invalidate(&[Dependency::Article(article_id)]);
+        HttpResponse::Ok().json(response)
+    })?)
 }
 
 #[cfg(feature = "editable")]
 pub async fn update(
+    req: HttpRequest,
     pool: web::Data<Pool>,
+    cache: web::Data<ResponseCache>,
     id: web::Path<i32>,
     body: web::Json<Content>,
-) -> Result<HttpResponse, Error> {
-    let connection = pool.get().unwrap();
-    Ok(
-        web::block(move || Content::update(*id, body.into_inner(), &connection))
-            .await
-            .map(|article| HttpResponse::Ok().json(article))
-            .map_err(DatabaseError)?,
-    )
+) -> Result<HttpResponse, ApiError> {
+    validate(&*body)?;
+    let connection = pool.get()?;
+    let author = author(&req);
+    Ok(web::block(move || {
+        // The content stays in its chapter and at its index, whatever the client sent.
+        let content = Content::find(*id, &connection)?;
+        let article = Revision::record(content.article_id, &author, &connection, || {
+            Content::update(&content, body.into_inner(), &connection)
+        })?;
+        Ok::<_, diesel::result::Error>((content.article_id, article))
+    })
+    .await
+    .map(|(article_id, article)| {
+        cache.invalidate(&[Dependency::Article(article_id)]);
+        HttpResponse::Ok().json(article)
+    })?)
 }
 
 #[cfg(feature = "editable")]
 pub async fn add(
+    req: HttpRequest,
     pool: web::Data<Pool>,
+    cache: web::Data<ResponseCache>,
     json_content: web::Json<InputContent>,
-) -> Result<HttpResponse, Error> {
+) -> Result<HttpResponse, ApiError> {
+    let connection = pool.get()?;
+    let (article_id, author) = (json_content.article_id, author(&req));
     Ok(web::block(move || {
-        let connection = pool.get().unwrap();
-        let article_id = json_content.article_id;
+        let chapter = Chapter::find(json_content.chapter_id, &connection)?;
+        let siblings = Content::count(chapter.id, &connection)?;
+        let mut violations = Violations::default();
+        json_content.validate(&mut violations);
+        violations.check(
+            chapter.article_id == article_id,
+            "chapter_id",
+            "Chapter does not belong to this article.",
+        );
+        violations.check(
+            i64::from(json_content.index) <= siblings,
+            "index",
+            "Index should follow the existing contents of the chapter.",
+        );
+        violations.into_result()?;
+
+        let rendering = render_content(&json_content.content_type, &json_content.content);
         // TODO - InputContent.into_inner(NewContent)?
-        Content::add(
-            &NewContent {
-                article_id,
-                chapter_id: json_content.chapter_id,
-                content_type: json_content.content_type.clone(),
-                content: &json_content.content,
-                language: json_content.language.clone(),
-                highlighted_code: match &json_content.content_type {
-                    ContentType::Code => {
-                        let language = json_content
-                            .language
-                            .as_ref()
-                            .expect("Code content should specify a language!")
-                            .to_string();
-                        Some(highlight_code(&json_content.content, &language))
-                    }
-                    _ => None,
-                },
-                url: Some(json_content.url.as_deref().unwrap_or("")),
-                index: json_content.index,
+        let new_content = NewContent {
+            article_id,
+            chapter_id: json_content.chapter_id,
+            content_type: json_content.content_type.clone(),
+            content: &json_content.content,
+            language: json_content.language.clone(),
+            highlighted_code: match (&json_content.content_type, &json_content.language) {
+                (ContentType::Code, Some(language)) => Some(highlight_code(
+                    &json_content.content,
+                    language,
+                    &json_content.code_lines(),
+                )),
+                _ => None,
             },
-            &connection,
-        )?;
-        Article::get(article_id, &connection)
+            url: Some(json_content.url.as_deref().unwrap_or("")),
+            caption: json_content.caption.as_deref(),
+            start_line: json_content.start_line,
+            emphasized_lines: json_content.emphasized_lines.as_deref(),
+            added_lines: json_content.added_lines.as_deref(),
+            removed_lines: json_content.removed_lines.as_deref(),
+            data: measure_image(
+                &json_content.content_type,
+                &json_content.content,
+                &json_content.data,
+            ),
+            rendered_html: rendering.html,
+            plain_text: rendering.plain_text,
+            index: json_content.index,
+        };
+        Revision::record(article_id, &author, &connection, || {
+            Content::add(&new_content, &connection)
+        })?;
+        Ok::<_, ApiError>(Article::get(article_id, Visibility::Admin, &connection)?)
+    })
+    .await
+    .map(|article| {
+        cache.invalidate(&[Dependency::Article(article_id)]);
+        HttpResponse::Ok().json(article)
+    })?)
+}
+
+#[cfg(feature = "editable")]
+pub async fn move_to(
+    req: HttpRequest,
+    pool: web::Data<Pool>,
+    cache: web::Data<ResponseCache>,
+    id: web::Path<i32>,
+    body: web::Json<InputContentMove>,
+) -> Result<HttpResponse, ApiError> {
+    let connection = pool.get()?;
+    let (chapter_id, author) = (body.chapter_id, author(&req));
+    Ok(web::block(move || {
+        let content = Content::find(*id, &connection)?;
+        // A content moved inside its chapter is not one more sibling.
+        let siblings =
+            Content::count(chapter_id, &connection)? - i64::from(content.chapter_id == chapter_id);
+        let mut violations = Violations::default();
+        violations.check(
+            body.index >= 0 && i64::from(body.index) <= siblings,
+            "index",
+            "Index should follow the existing contents of the chapter.",
+        );
+        violations.into_result()?;
+
+        // Both articles get a revision when the content changes of article.
+        let article_id = Chapter::find(chapter_id, &connection)?.article_id;
+        let move_content = || Content::move_to(*id, chapter_id, body.index, &connection);
+        let article = if content.article_id == article_id {
+            Revision::record(article_id, &author, &connection, move_content)?
+        } else {
+            Revision::record(content.article_id, &author, &connection, || {
+                Revision::record(article_id, &author, &connection, move_content)
+            })?
+        };
+        Ok::<_, ApiError>((content.article_id, article))
     })
     .await
-    .map(|article| HttpResponse::Ok().json(article))
-    .map_err(DatabaseError)?)
+    .map(|(previous_article_id, article)| {
+        cache.invalidate(&[
+            Dependency::Article(previous_article_id),
+            Dependency::Article(article.id),
+        ]);
+        HttpResponse::Ok().json(article)
+    })?)
 }
@@ -0,0 +1,50 @@
+#[cfg(feature = "editable")]
+use {
+    crate::{
+        errors::{api_error::ApiError, media_error::MediaError},
+        interfaces::MediaQuery,
+        models::media::Media,
+        service::media::scan as scan_media,
+        Pool,
+    },
+    actix_web::{web, HttpResponse},
+};
+
+#[cfg(feature = "editable")]
+pub async fn list(
+    pool: web::Data<Pool>,
+    query: web::Query<MediaQuery>,
+) -> Result<HttpResponse, ApiError> {
+    let connection = pool.get()?;
+    let orphans_only = query.orphans.unwrap_or(false);
+    Ok(web::block(move || Media::list(orphans_only, &connection))
+        .await
+        .map(|media| HttpResponse::Ok().json(media))?)
+}
+
+#[cfg(feature = "editable")]
+pub async fn get(pool: web::Data<Pool>, id: web::Path<i32>) -> Result<HttpResponse, ApiError> {
+    let connection = pool.get()?;
+    Ok(web::block(move || Media::get(*id, &connection))
+        .await
+        .map(|media| HttpResponse::Ok().json(media))?)
+}
+
+#[cfg(feature = "editable")]
+pub async fn scan(pool: web::Data<Pool>) -> Result<HttpResponse, ApiError> {
+    let connection = pool.get()?;
+    Ok(web::block(move || {
+        let scanned_files = scan_media()?;
+        Ok::<_, MediaError>(Media::register(&scanned_files, &connection)?)
+    })
+    .await
+    .map(|report| HttpResponse::Ok().json(report))?)
+}
+
+#[cfg(feature = "editable")]
+pub async fn delete(pool: web::Data<Pool>, id: web::Path<i32>) -> Result<HttpResponse, ApiError> {
+    let connection = pool.get()?;
+    Ok(web::block(move || Media::delete(*id, &connection))
+        .await
+        .map(|response| HttpResponse::Ok().json(response))?)
+}
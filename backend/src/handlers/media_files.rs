@@ -0,0 +1,198 @@
+/*
+ * Serve the files living under the media root.
+ * - Directory listing is disabled unless MEDIA_FILES_LISTING=true is set in the environment.
+ * - Path traversal ("..", hidden files) and symlinks resolving outside of the media root are rejected with a 404.
+ * - Content-addressed assets (named after a UUID) never change and are cached for a year, every other file is revalidated with ETag/Last-Modified.
+ * - Text assets are served from their precompressed .br/.gz sidecar when the client accepts it.
+ */
+use {
+    crate::{html::escape_html, MEDIA_ROOT},
+    actix_files::{file_extension_to_mime, NamedFile},
+    actix_web::{
+        http::{
+            header::{self, HeaderValue},
+            ContentEncoding,
+        },
+        HttpRequest, HttpResponse, Result,
+    },
+    std::{
+        fs,
+        path::{Path, PathBuf},
+    },
+};
+
+const IMMUTABLE_CACHE_CONTROL: &str = "public, max-age=31536000, immutable";
+const REVALIDATE_CACHE_CONTROL: &str = "public, no-cache";
+
+fn is_listing_enabled() -> bool {
+    std::env::var("MEDIA_FILES_LISTING").map_or(false, |listing| listing == "true")
+}
+
+// Resolve a request path to a canonical path inside the media root.
+fn resolve(path: &str) -> Option<PathBuf> {
+    let mut resolved = PathBuf::from(MEDIA_ROOT);
+    for segment in path.split('/') {
+        match segment {
+            "" | "." => continue,
+            ".." => return None,
+            _ if segment.starts_with('.') || segment.contains('\\') => return None,
+            _ => resolved.push(segment),
+        }
+    }
+
+    inside_root(&resolved)
+}
+
+// Canonicalize a path, rejecting it if it resolves (e.g. through a symlink) outside of the media root.
+fn inside_root(path: &Path) -> Option<PathBuf> {
+    let root = Path::new(MEDIA_ROOT).canonicalize().ok()?;
+    let canonical = path.canonicalize().ok()?;
+    if canonical.starts_with(&root) {
+        Some(canonical)
+    } else {
+        None
+    }
+}
+
+// Uploaded assets are named after a v4 UUID ("da6aa3a5-19b5-4565-8625-e81c128d8ce8.webp").
+fn is_content_addressed(file: &Path) -> bool {
+    let stem = match file.file_stem().and_then(|stem| stem.to_str()) {
+        Some(stem) => stem,
+        None => return false,
+    };
+    let groups: Vec<&str> = stem.split('-').collect();
+
+    groups.len() == 5
+        && groups.iter().zip([8, 4, 4, 4, 12]).all(|(group, length)| {
+            group.len() == length && group.chars().all(|c| c.is_ascii_hexdigit())
+        })
+}
+
+fn is_text(file: &Path) -> bool {
+    let extension = file
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or("");
+    let mime = file_extension_to_mime(extension);
+
+    mime.type_().as_str() == "text"
+        || matches!(
+            mime.subtype().as_str(),
+            "javascript" | "json" | "xml" | "svg"
+        )
+}
+
+// Pick the best precompressed sidecar (".br" then ".gz") accepted by the client, if any exists next to the file.
+// Sidecars go through the same media root check as the file itself.
+fn find_sidecar(req: &HttpRequest, file: &Path) -> Option<(PathBuf, ContentEncoding)> {
+    let accepted = req
+        .headers()
+        .get(header::ACCEPT_ENCODING)
+        .and_then(|accept_encoding| accept_encoding.to_str().ok())
+        .unwrap_or("");
+
+    [("br", ContentEncoding::Br), ("gz", ContentEncoding::Gzip)]
+        .into_iter()
+        .filter(|(extension, _)| {
+            let encoding = if *extension == "gz" {
+                "gzip"
+            } else {
+                *extension
+            };
+            accepted
+                .split(',')
+                .any(|accepted| accepted.trim().split(';').next() == Some(encoding))
+        })
+        .filter_map(|(extension, encoding)| {
+            let mut sidecar = file.as_os_str().to_owned();
+            sidecar.push(".");
+            sidecar.push(extension);
+            inside_root(Path::new(&sidecar)).map(|sidecar| (sidecar, encoding))
+        })
+        .find(|(sidecar, _)| sidecar.is_file())
+}
+
+fn list_directory(req: &HttpRequest, directory: &Path) -> Result<HttpResponse> {
+    let base = req.path().trim_end_matches('/');
+    let mut entries: Vec<String> = fs::read_dir(directory)?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|name| !name.starts_with('.'))
+        .collect();
+    entries.sort();
+
+    let mut body = String::from("<html><body><ul>");
+    for entry in &entries {
+        let entry = escape_html(entry);
+        body.push_str(&format!(
+            "<li><a href=\"{}/{}\">{}</a></li>",
+            escape_html(base),
+            entry,
+            entry
+        ));
+    }
+    body.push_str("</ul></body></html>");
+
+    Ok(HttpResponse::Ok()
+        .content_type("text/html; charset=utf-8")
+        .body(body))
+}
+
+pub async fn serve(req: HttpRequest) -> Result<HttpResponse> {
+    let file = match resolve(req.match_info().query("path")) {
+        Some(file) => file,
+        None => return Ok(HttpResponse::NotFound().finish()),
+    };
+
+    if file.is_dir() {
+        return if is_listing_enabled() {
+            list_directory(&req, &file)
+        } else {
+            Ok(HttpResponse::NotFound().finish())
+        };
+    }
+
+    let sidecar = if is_text(&file) {
+        find_sidecar(&req, &file)
+    } else {
+        None
+    };
+    let named_file = match &sidecar {
+        Some((sidecar, encoding)) => {
+            let extension = file
+                .extension()
+                .and_then(|extension| extension.to_str())
+                .unwrap_or("");
+            NamedFile::open(sidecar)?
+                .set_content_type(file_extension_to_mime(extension))
+                .set_content_encoding(*encoding)
+        }
+        None => NamedFile::open(&file)?,
+    };
+
+    let mut response = named_file
+        .use_etag(true)
+        .use_last_modified(true)
+        .into_response(&req)?;
+
+    let headers = response.headers_mut();
+    headers.insert(
+        header::CACHE_CONTROL,
+        HeaderValue::from_static(if is_content_addressed(&file) {
+            IMMUTABLE_CACHE_CONTROL
+        } else {
+            REVALIDATE_CACHE_CONTROL
+        }),
+    );
+    if is_text(&file) {
+        headers.insert(header::VARY, HeaderValue::from_static("Accept-Encoding"));
+    }
+    if let Some((_, encoding)) = sidecar {
+        headers.insert(
+            header::CONTENT_ENCODING,
+            HeaderValue::from_static(encoding.as_str()),
+        );
+    }
+
+    Ok(response)
+}
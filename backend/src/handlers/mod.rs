@@ -1,7 +1,19 @@
 pub mod articles;
+#[cfg(feature = "editable")]
+pub mod cache;
 pub mod chapters;
+pub mod code;
 pub mod contents;
+pub mod media;
+pub mod media_files;
+pub mod preview;
 pub mod projects;
+pub mod references;
 pub mod resume_projects;
+pub mod revisions;
 pub mod search;
+pub mod series;
 pub mod tags;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,75 @@
+use {
+    crate::{
+        errors::api_error::ApiError,
+        models::{articles::Article, visibility::Visibility},
+        preview::verify,
+        Pool,
+    },
+    actix_web::{http::header, web, HttpResponse},
+};
+
+#[cfg(feature = "editable")]
+use {
+    crate::{
+        cache::env_or,
+        interfaces::{InputPreviewLink, PreviewLink},
+        preview::sign,
+        validation::validate,
+    },
+    chrono::{Duration, Utc},
+};
+
+// Where the public frontend is served, the preview links point to its /preview/<token> page.
+#[cfg(feature = "editable")]
+const DEFAULT_PUBLIC_URL: &str = "https://guimauve.io";
+
+// Mint a preview link for an unpublished article.
+#[cfg(feature = "editable")]
+pub async fn create(
+    pool: web::Data<Pool>,
+    id: web::Path<i32>,
+    payload: web::Json<InputPreviewLink>,
+) -> Result<HttpResponse, ApiError> {
+    validate(&*payload)?;
+    let connection = pool.get()?;
+    let id = *id;
+    let article = web::block(move || Article::get(id, Visibility::Admin, &connection)).await?;
+    if article.published {
+        return Err(ApiError::Conflict(
+            "The article is already published.".to_owned(),
+        ));
+    }
+
+    let expires = Utc::now().naive_utc() + Duration::hours(payload.hours);
+    let token = sign(id, &expires).ok_or(ApiError::Unavailable)?;
+    let url = format!(
+        "{}/preview/{}",
+        env_or("PUBLIC_URL", DEFAULT_PUBLIC_URL.to_owned()).trim_end_matches('/'),
+        token
+    );
+    Ok(HttpResponse::Created().json(PreviewLink {
+        token,
+        url,
+        expires,
+    }))
+}
+
+// The article a preview token gives access to, published or not (see Visibility::Preview). An invalid or expired
+// token is answered like a missing article, and the response is neither cached nor indexed.
+pub async fn get(
+    pool: web::Data<Pool>,
+    token: web::Path<String>,
+) -> Result<HttpResponse, ApiError> {
+    let id = verify(&token).ok_or(ApiError::NotFound)?;
+    let connection = pool.get()?;
+    Ok(
+        web::block(move || Article::get(id, Visibility::Preview(id), &connection))
+            .await
+            .map(|article| {
+                HttpResponse::Ok()
+                    .header(header::CACHE_CONTROL, "no-store")
+                    .header("X-Robots-Tag", "noindex")
+                    .json(article)
+            })?,
+    )
+}
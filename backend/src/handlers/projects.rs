@@ -1,21 +1,60 @@
 use {
-    crate::{errors::database_error::DatabaseError, models::projects::Project, Pool},
-    actix_web::{web, Error, HttpResponse},
+    crate::{
+        cache::{Dependency, ResponseCache},
+        errors::api_error::ApiError,
+        models::projects::Project,
+        Pool,
+    },
+    actix_web::{web, HttpRequest, HttpResponse},
+    std::{collections::HashSet, iter::once},
 };
 
-pub async fn list(pool: web::Data<Pool>) -> Result<HttpResponse, Error> {
-    let connection = pool.get().unwrap();
+pub async fn list(
+    req: HttpRequest,
+    pool: web::Data<Pool>,
+    cache: web::Data<ResponseCache>,
+) -> Result<HttpResponse, ApiError> {
+    let key = cache.key(&req);
+    if let Some(response) = cache.respond(&req, &key) {
+        return Ok(response);
+    }
+
+    let connection = pool.get()?;
     Ok(web::block(move || Project::list(&connection))
         .await
-        .map(|projects| HttpResponse::Ok().json(projects))
-        .map_err(DatabaseError)?)
+        .map(|projects| {
+            let dependencies: HashSet<Dependency> = projects
+                .keys()
+                .map(|id| Dependency::Project(*id))
+                .chain(once(Dependency::ProjectList))
+                .collect();
+            cache.store(&req, key, &projects, None, dependencies)
+        })?)
 }
 
 #[cfg(feature = "editable")]
-pub async fn get(pool: web::Data<Pool>, id: web::Path<i32>) -> Result<HttpResponse, Error> {
-    let connection = pool.get().unwrap();
-    Ok(web::block(move || Project::find(*id, &connection))
+pub async fn get(
+    req: HttpRequest,
+    pool: web::Data<Pool>,
+    cache: web::Data<ResponseCache>,
+    id: web::Path<i32>,
+) -> Result<HttpResponse, ApiError> {
+    let key = cache.key(&req);
+    if let Some(response) = cache.respond(&req, &key) {
+        return Ok(response);
+    }
+
+    let connection = pool.get()?;
+    let id = *id;
+    Ok(web::block(move || Project::find(id, &connection))
         .await
-        .map(|project| HttpResponse::Ok().json(project))
-        .map_err(DatabaseError)?)
+        .map(|project| {
+            cache.store(
+                &req,
+                key,
+                &project,
+                None,
+                once(Dependency::Project(id)).collect(),
+            )
+        })?)
 }
@@ -0,0 +1,125 @@
+#[cfg(feature = "editable")]
+use {
+    crate::{
+        cache::{Dependency, ResponseCache},
+        errors::api_error::ApiError,
+        handlers::revisions::author,
+        interfaces::{InputReference, InputReferenceUpdate},
+        models::{
+            articles::Article,
+            references::{NewReference, Reference},
+            revisions::Revision,
+            visibility::Visibility,
+        },
+        validation::{Validate, Violations},
+        Pool,
+    },
+    actix_web::{web, HttpRequest, HttpResponse},
+};
+
+#[cfg(feature = "editable")]
+pub async fn update(
+    req: HttpRequest,
+    pool: web::Data<Pool>,
+    cache: web::Data<ResponseCache>,
+    id: web::Path<i32>,
+    body: web::Json<InputReferenceUpdate>,
+) -> Result<HttpResponse, ApiError> {
+    let connection = pool.get()?;
+    let (id, author) = (*id, author(&req));
+    Ok(web::block(move || {
+        // The reference stays in its article, whatever the client sent.
+        let reference = Reference::find(id, &connection)?;
+        let mut violations = Violations::default();
+        body.validate(&mut violations);
+        let same_key = Reference::find_by_key(reference.article_id, &body.key, &connection)?;
+        violations.check(
+            same_key.map_or(true, |same_key| same_key.id == id),
+            "key",
+            "Another reference already uses this key.",
+        );
+        violations.into_result()?;
+
+        let article = Revision::record(reference.article_id, &author, &connection, || {
+            Reference::update(&reference, &body, &connection)
+        })?;
+        Ok::<_, ApiError>((reference.article_id, article))
+    })
+    .await
+    .map(|(article_id, article)| {
+        cache.invalidate(&[Dependency::Article(article_id)]);
+        HttpResponse::Ok().json(article)
+    })?)
+}
+
+#[cfg(feature = "editable")]
+pub async fn add(
+    req: HttpRequest,
+    pool: web::Data<Pool>,
+    cache: web::Data<ResponseCache>,
+    json_reference: web::Json<InputReference>,
+) -> Result<HttpResponse, ApiError> {
+    let connection = pool.get()?;
+    let (article_id, author) = (json_reference.article_id, author(&req));
+    Ok(web::block(move || {
+        let siblings = Reference::count(article_id, &connection)?;
+        let mut violations = Violations::default();
+        json_reference.validate(&mut violations);
+        violations.check(
+            i64::from(json_reference.index) <= siblings,
+            "index",
+            "Index should follow the existing references of the article.",
+        );
+        violations.check(
+            Reference::find_by_key(article_id, &json_reference.key, &connection)?.is_none(),
+            "key",
+            "Another reference already uses this key.",
+        );
+        violations.into_result()?;
+
+        Revision::record(article_id, &author, &connection, || {
+            Reference::add(
+                &NewReference {
+                    article_id,
+                    index: json_reference.index,
+                    key: &json_reference.key,
+                    title: &json_reference.title,
+                    url: json_reference
+                        .url
+                        .as_deref()
+                        .filter(|url| !url.trim().is_empty()),
+                },
+                &connection,
+            )
+        })?;
+        Ok::<_, ApiError>(Article::get(article_id, Visibility::Admin, &connection)?)
+    })
+    .await
+    .map(|article| {
+        cache.invalidate(&[Dependency::Article(article_id)]);
+        HttpResponse::Ok().json(article)
+    })?)
+}
+
+#[cfg(feature = "editable")]
+pub async fn delete(
+    req: HttpRequest,
+    pool: web::Data<Pool>,
+    cache: web::Data<ResponseCache>,
+    id: web::Path<i32>,
+) -> Result<HttpResponse, ApiError> {
+    let connection = pool.get()?;
+    let author = author(&req);
+    Ok(web::block(move || {
+        let article_id = Reference::find(*id, &connection)?.article_id;
+        let response = Revision::record(article_id, &author, &connection, || {
+            Reference::delete(*id, &connection)
+        })?;
+        Ok::<_, diesel::result::Error>((article_id, response))
+    })
+    .await
+    .map(|(article_id, response)| {
+        cache.invalidate(&[Dependency::Article(article_id)]);
+        HttpResponse::Ok().json(response)
+    })?)
+}
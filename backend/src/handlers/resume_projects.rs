@@ -1,14 +1,13 @@
 #[cfg(feature = "editable")]
 use {
-    crate::{errors::database_error::DatabaseError, models::projects::Project, Pool},
-    actix_web::{web, Error, HttpResponse},
+    crate::{errors::api_error::ApiError, models::projects::Project, Pool},
+    actix_web::{web, HttpResponse},
 };
 
 #[cfg(feature = "editable")]
-pub async fn list(pool: web::Data<Pool>) -> Result<HttpResponse, Error> {
-    let connection = pool.get().unwrap();
+pub async fn list(pool: web::Data<Pool>) -> Result<HttpResponse, ApiError> {
+    let connection = pool.get()?;
     Ok(web::block(move || Project::resume_projects(&connection))
         .await
-        .map(|resume_projects| HttpResponse::Ok().json(resume_projects))
-        .map_err(DatabaseError)?)
+        .map(|resume_projects| HttpResponse::Ok().json(resume_projects))?)
 }
@@ -0,0 +1,79 @@
+#[cfg(feature = "editable")]
+use {
+    crate::{
+        cache::{Dependency, ResponseCache},
+        diff::diff_revisions,
+        errors::api_error::ApiError,
+        interfaces::RevisionDiffQuery,
+        models::revisions::Revision,
+        Pool,
+    },
+    actix_web::{web, HttpRequest, HttpResponse},
+};
+
+// There are no accounts, the editor may name itself in an X-Author header, otherwise the client address is recorded.
+#[cfg(feature = "editable")]
+pub fn author(req: &HttpRequest) -> String {
+    req.headers()
+        .get("X-Author")
+        .and_then(|author| author.to_str().ok())
+        .map(str::trim)
+        .filter(|author| !author.is_empty())
+        .map(str::to_owned)
+        .or_else(|| {
+            req.connection_info()
+                .realip_remote_addr()
+                .map(str::to_owned)
+        })
+        .unwrap_or_else(|| "unknown".to_owned())
+}
+
+#[cfg(feature = "editable")]
+pub async fn list(pool: web::Data<Pool>, id: web::Path<i32>) -> Result<HttpResponse, ApiError> {
+    let connection = pool.get()?;
+    Ok(web::block(move || Revision::list(*id, &connection))
+        .await
+        .map(|revisions| HttpResponse::Ok().json(revisions))?)
+}
+
+#[cfg(feature = "editable")]
+pub async fn diff(
+    pool: web::Data<Pool>,
+    id: web::Path<i32>,
+    query: web::Query<RevisionDiffQuery>,
+) -> Result<HttpResponse, ApiError> {
+    let connection = pool.get()?;
+    let (from, to) = (query.from, query.to);
+    Ok(web::block(move || {
+        let before = Revision::find(*id, from, &connection)?.snapshot()?;
+        let after = Revision::find(*id, to, &connection)?.snapshot()?;
+        Ok::<_, diesel::result::Error>(diff_revisions((from, &before), (to, &after)))
+    })
+    .await
+    .map(|diff| HttpResponse::Ok().json(diff))?)
+}
+
+#[cfg(feature = "editable")]
+pub async fn restore(
+    req: HttpRequest,
+    pool: web::Data<Pool>,
+    cache: web::Data<ResponseCache>,
+    path: web::Path<(i32, i32)>,
+) -> Result<HttpResponse, ApiError> {
+    let connection = pool.get()?;
+    let (id, revision_id) = path.into_inner();
+    let author = author(&req);
+    Ok(
+        web::block(move || Revision::restore(id, revision_id, &author, &connection))
+            .await
+            .map(|article| {
+                cache.invalidate(&[
+                    Dependency::Article(id),
+                    Dependency::ArticleList,
+                    Dependency::TagResults,
+                    Dependency::Search,
+                ]);
+                HttpResponse::Ok().json(article)
+            })?,
+    )
+}
@@ -1,18 +1,42 @@
 use {
     crate::{
-        errors::database_error::DatabaseError, interfaces::BlogQuery,
-        service::search::search as search_service, Pool,
+        cache::{Dependency, ResponseCache},
+        errors::api_error::ApiError,
+        interfaces::BlogQuery,
+        models::visibility::Visibility,
+        service::search::search as search_service,
+        Pool,
     },
-    actix_web::{web, Error, HttpResponse},
+    actix_web::{web, HttpRequest, HttpResponse},
+    std::{collections::HashSet, iter::once},
 };
 
 pub async fn search(
+    req: HttpRequest,
     pool: web::Data<Pool>,
+    cache: web::Data<ResponseCache>,
+    visibility: web::Data<Visibility>,
     query: web::Query<BlogQuery>,
-) -> Result<HttpResponse, Error> {
-    let connection = pool.get().unwrap();
-    Ok(web::block(move || search_service(&connection, &query.text))
-        .await
-        .map(|results| HttpResponse::Ok().json(results))
-        .map_err(DatabaseError)?)
+) -> Result<HttpResponse, ApiError> {
+    let key = cache.key(&req);
+    if let Some(response) = cache.respond(&req, &key) {
+        return Ok(response);
+    }
+
+    let connection = pool.get()?;
+    let visibility = **visibility;
+    Ok(
+        web::block(move || search_service(&connection, &query.text, visibility))
+            .await
+            .map(|results| {
+                let dependencies: HashSet<Dependency> = results
+                    .articles
+                    .keys()
+                    .map(|id| Dependency::Article(*id))
+                    .chain(results.projects.keys().map(|id| Dependency::Project(*id)))
+                    .chain(once(Dependency::Search))
+                    .collect();
+                cache.store(&req, key, &results, None, dependencies)
+            })?,
+    )
 }
@@ -0,0 +1,176 @@
+use {
+    crate::{
+        cache::{Dependency, ResponseCache},
+        errors::api_error::ApiError,
+        models::{series::Series, visibility::Visibility},
+        Pool,
+    },
+    actix_web::{web, HttpRequest, HttpResponse},
+    std::collections::HashSet,
+};
+
+#[cfg(feature = "editable")]
+use {
+    crate::{
+        interfaces::{InputSeries, InputSeriesParts},
+        validation::validate,
+    },
+    diesel::connection::Connection,
+};
+
+pub async fn list(
+    req: HttpRequest,
+    pool: web::Data<Pool>,
+    cache: web::Data<ResponseCache>,
+    visibility: web::Data<Visibility>,
+) -> Result<HttpResponse, ApiError> {
+    let key = cache.key(&req);
+    if let Some(response) = cache.respond(&req, &key) {
+        return Ok(response);
+    }
+
+    let connection = pool.get()?;
+    let visibility = **visibility;
+    Ok(web::block(move || Series::list(visibility, &connection))
+        .await
+        .map(|series| {
+            // Publishing the first part of a series drops the article list.
+            let dependencies: HashSet<Dependency> =
+                vec![Dependency::SeriesList, Dependency::ArticleList]
+                    .into_iter()
+                    .collect();
+            cache.store(&req, key, &series, None, dependencies)
+        })?)
+}
+
+pub async fn get(
+    req: HttpRequest,
+    pool: web::Data<Pool>,
+    cache: web::Data<ResponseCache>,
+    visibility: web::Data<Visibility>,
+    id: web::Path<i32>,
+) -> Result<HttpResponse, ApiError> {
+    let key = cache.key(&req);
+    if let Some(response) = cache.respond(&req, &key) {
+        return Ok(response);
+    }
+
+    let connection = pool.get()?;
+    let (id, visibility) = (*id, **visibility);
+    Ok(web::block(move || Series::get(id, visibility, &connection))
+        .await
+        .map(|series| {
+            // Publishing or editing a part drops the article list.
+            let dependencies: HashSet<Dependency> = series
+                .parts
+                .iter()
+                .map(|part| Dependency::Article(part.id))
+                .chain(vec![Dependency::Series(id), Dependency::ArticleList])
+                .collect();
+            cache.store(&req, key, &series, None, dependencies)
+        })?)
+}
+
+// The cached series and articles embedding them, before and after a change of the parts.
+#[cfg(feature = "editable")]
+fn invalidate(cache: &ResponseCache, ids: &[i32], article_ids: &[i32]) {
+    let mut dependencies: Vec<Dependency> = article_ids
+        .iter()
+        .map(|article_id| Dependency::Article(*article_id))
+        .chain(ids.iter().map(|id| Dependency::Series(*id)))
+        .collect();
+    dependencies.push(Dependency::SeriesList);
+    cache.invalidate(&dependencies);
+}
+
+#[cfg(feature = "editable")]
+pub async fn add(
+    pool: web::Data<Pool>,
+    cache: web::Data<ResponseCache>,
+    payload: web::Json<InputSeries>,
+) -> Result<HttpResponse, ApiError> {
+    validate(&*payload)?;
+    let connection = pool.get()?;
+    Ok(web::block(move || Series::add(&payload, &connection))
+        .await
+        .map(|series| {
+            cache.invalidate(&[Dependency::SeriesList]);
+            HttpResponse::Created().json(series)
+        })?)
+}
+
+#[cfg(feature = "editable")]
+pub async fn update(
+    pool: web::Data<Pool>,
+    cache: web::Data<ResponseCache>,
+    id: web::Path<i32>,
+    payload: web::Json<InputSeries>,
+) -> Result<HttpResponse, ApiError> {
+    validate(&*payload)?;
+    let connection = pool.get()?;
+    let id = *id;
+    Ok(
+        web::block(move || Series::update(id, &payload, &connection))
+            .await
+            .map(|series| {
+                let article_ids: Vec<i32> = series.parts.iter().map(|part| part.id).collect();
+                invalidate(&cache, &[id], &article_ids);
+                HttpResponse::Ok().json(series)
+            })?,
+    )
+}
+
+#[cfg(feature = "editable")]
+pub async fn delete(
+    pool: web::Data<Pool>,
+    cache: web::Data<ResponseCache>,
+    id: web::Path<i32>,
+) -> Result<HttpResponse, ApiError> {
+    let connection = pool.get()?;
+    let id = *id;
+    Ok(web::block(move || {
+        connection.transaction::<_, diesel::result::Error, _>(|| {
+            let article_ids = Series::article_ids(id, &connection)?;
+            Series::delete(id, &connection).map(|deleted| (article_ids, deleted))
+        })
+    })
+    .await
+    .map(|(article_ids, deleted)| {
+        invalidate(&cache, &[id], &article_ids);
+        HttpResponse::Ok().json(deleted)
+    })?)
+}
+
+#[cfg(feature = "editable")]
+pub async fn set_parts(
+    pool: web::Data<Pool>,
+    cache: web::Data<ResponseCache>,
+    id: web::Path<i32>,
+    payload: web::Json<InputSeriesParts>,
+) -> Result<HttpResponse, ApiError> {
+    validate(&*payload)?;
+    let connection = pool.get()?;
+    let id = *id;
+    Ok(web::block(move || {
+        connection.transaction::<_, diesel::result::Error, _>(|| {
+            // The series the articles are moved from lose a part: their remaining parts change of position too.
+            let mut ids = Series::other_series_of(id, &payload.articles, &connection)?;
+            ids.push(id);
+            let mut previous = Vec::new();
+            for series_id in &ids {
+                previous.extend(Series::article_ids(*series_id, &connection)?);
+            }
+            Series::set_parts(id, &payload.articles, &connection)
+                .map(|series| (ids, previous, series))
+        })
+    })
+    .await
+    .map(|(ids, previous, series)| {
+        let article_ids: Vec<i32> = previous
+            .into_iter()
+            .chain(series.parts.iter().map(|part| part.id))
+            .collect();
+        invalidate(&cache, &ids, &article_ids);
+        HttpResponse::Ok().json(series)
+    })?)
+}
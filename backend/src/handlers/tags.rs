@@ -1,23 +1,57 @@
 use {
-    crate::{errors::database_error::DatabaseError, models::tags::Tag, Pool},
-    actix_web::{web, Error, HttpResponse},
+    crate::{
+        cache::{Dependency, ResponseCache},
+        errors::api_error::ApiError,
+        models::{tags::Tag, visibility::Visibility},
+        Pool,
+    },
+    actix_web::{web, HttpRequest, HttpResponse},
+    std::{collections::HashSet, iter::once},
 };
 
-pub async fn list(pool: web::Data<Pool>) -> Result<HttpResponse, Error> {
-    let connection = pool.get().unwrap();
+pub async fn list(
+    req: HttpRequest,
+    pool: web::Data<Pool>,
+    cache: web::Data<ResponseCache>,
+) -> Result<HttpResponse, ApiError> {
+    let key = cache.key(&req);
+    if let Some(response) = cache.respond(&req, &key) {
+        return Ok(response);
+    }
+
+    let connection = pool.get()?;
     Ok(web::block(move || Tag::list(&connection))
         .await
-        .map(|tags| HttpResponse::Ok().json(tags))
-        .map_err(DatabaseError)?)
+        .map(|tags| cache.store(&req, key, &tags, None, once(Dependency::TagList).collect()))?)
 }
 
 pub async fn get_results_for_tag(
+    req: HttpRequest,
     pool: web::Data<Pool>,
+    cache: web::Data<ResponseCache>,
+    visibility: web::Data<Visibility>,
     tag: web::Path<String>,
-) -> Result<HttpResponse, Error> {
-    let connection = pool.get().unwrap();
-    Ok(web::block(move || Tag::results(&connection, &tag))
-        .await
-        .map(|results| HttpResponse::Ok().json(results))
-        .map_err(DatabaseError)?)
+) -> Result<HttpResponse, ApiError> {
+    let key = cache.key(&req);
+    if let Some(response) = cache.respond(&req, &key) {
+        return Ok(response);
+    }
+
+    let connection = pool.get()?;
+    let visibility = **visibility;
+    Ok(
+        web::block(move || Tag::results(&connection, &tag, visibility))
+            .await
+            .map(|results| {
+                // Like the article list, only validated by its ETag.
+                let dependencies: HashSet<Dependency> = results
+                    .articles
+                    .keys()
+                    .map(|id| Dependency::Article(*id))
+                    .chain(results.projects.keys().map(|id| Dependency::Project(*id)))
+                    .chain(once(Dependency::TagResults))
+                    .collect();
+                cache.store(&req, key, &results, None, dependencies)
+            })?,
+    )
 }
@@ -0,0 +1,374 @@
+/*
+ * Draft visibility of every read endpoint. These tests need a PostgreSQL database with the migrations run, given by
+ * TEST_DATABASE_URL, so they are ignored by default: run them with `cargo test -- --ignored`. The pool holds a single
+ * connection inside a test transaction, so nothing is ever committed.
+ */
+use {
+    crate::{
+        cache::ResponseCache,
+        handlers,
+        models::visibility::Visibility,
+        preview::sign,
+        schema::{article_tags, articles, series, series_articles, tags},
+        Pool,
+    },
+    actix_web::{http::StatusCode, test, web, App},
+    chrono::{Duration, Utc},
+    diesel::{
+        r2d2::{self, ConnectionManager, CustomizeConnection},
+        Connection, ExpressionMethods, PgConnection, QueryDsl, RunQueryDsl,
+    },
+    serde_json::Value,
+};
+
+const TAG: &str = "visibility-test";
+const WORD: &str = "marshmallowvisibility";
+
+#[derive(Debug)]
+struct TestTransaction;
+
+impl CustomizeConnection<PgConnection, r2d2::Error> for TestTransaction {
+    fn on_acquire(&self, connection: &mut PgConnection) -> Result<(), r2d2::Error> {
+        connection
+            .begin_test_transaction()
+            .map_err(r2d2::Error::QueryError)
+    }
+}
+
+fn test_pool() -> Pool {
+    let url = std::env::var("TEST_DATABASE_URL")
+        .expect("TEST_DATABASE_URL should give a PostgreSQL database with the migrations run.");
+    r2d2::Pool::builder()
+        .max_size(1)
+        .connection_customizer(Box::new(TestTransaction))
+        .build(ConnectionManager::<PgConnection>::new(url))
+        .expect("Failed to create the test pool.")
+}
+
+// A published article and a draft, both tagged and matching the same search.
+struct Fixture {
+    published: i32,
+    draft: i32,
+}
+
+fn insert_article(title: &str, published: bool, connection: &PgConnection) -> i32 {
+    diesel::insert_into(articles::table)
+        .values((
+            articles::title.eq(title),
+            articles::headline.eq(WORD),
+            articles::published.eq(published),
+            articles::image.eq(""),
+        ))
+        .returning(articles::id)
+        .get_result(connection)
+        .unwrap()
+}
+
+fn fixture(pool: &Pool) -> Fixture {
+    let connection = pool.get().unwrap();
+    let published = insert_article("Published", true, &connection);
+    let draft = insert_article("Draft", false, &connection);
+    let tag_id: i32 = diesel::insert_into(tags::table)
+        .values(tags::label.eq(TAG))
+        .returning(tags::id)
+        .get_result(&connection)
+        .unwrap();
+    diesel::insert_into(article_tags::table)
+        .values(&vec![
+            (
+                article_tags::article_id.eq(published),
+                article_tags::tag_id.eq(tag_id),
+            ),
+            (
+                article_tags::article_id.eq(draft),
+                article_tags::tag_id.eq(tag_id),
+            ),
+        ])
+        .execute(&connection)
+        .unwrap();
+
+    Fixture { published, draft }
+}
+
+fn setup() -> (Pool, Fixture) {
+    let pool = test_pool();
+    let fixture = fixture(&pool);
+    (pool, fixture)
+}
+
+// Answer a GET through the read routes shared by both servers.
+async fn get(pool: &Pool, visibility: Visibility, uri: &str) -> (StatusCode, Value) {
+    let mut app = test::init_service(
+        App::new()
+            .data(pool.clone())
+            .app_data(web::Data::new(ResponseCache::from_env()))
+            .app_data(web::Data::new(visibility))
+            .route("/articles/{id}", web::get().to(handlers::articles::get))
+            .route(
+                "/articles/{id}/markdown",
+                web::get().to(handlers::articles::export_markdown),
+            )
+            .route(
+                "/articles/{id}/html",
+                web::get().to(handlers::articles::export_html),
+            )
+            .route("/articles", web::get().to(handlers::articles::list))
+            .route(
+                "/articles/{id}/related",
+                web::get().to(handlers::articles::related),
+            )
+            .route("/preview/{token}", web::get().to(handlers::preview::get))
+            .route(
+                "/tags/{tag}",
+                web::get().to(handlers::tags::get_results_for_tag),
+            )
+            .route("/search", web::get().to(handlers::search::search))
+            .route("/series", web::get().to(handlers::series::list))
+            .route("/series/{id}", web::get().to(handlers::series::get)),
+    )
+    .await;
+    let response =
+        test::call_service(&mut app, test::TestRequest::get().uri(uri).to_request()).await;
+    let status = response.status();
+    let body = test::read_body(response).await;
+    (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+}
+
+fn contains(articles: &Value, id: i32) -> bool {
+    articles.get(id.to_string()).is_some()
+}
+
+#[actix_rt::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn get_hides_drafts_from_the_public() {
+    let (pool, fixture) = setup();
+
+    let uri = |id: i32| format!("/articles/{}", id);
+    let (status, _) = get(&pool, Visibility::Public, &uri(fixture.published)).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = get(&pool, Visibility::Public, &uri(fixture.draft)).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (status, _) = get(&pool, Visibility::Admin, &uri(fixture.draft)).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[actix_rt::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn exports_hide_drafts_from_the_public() {
+    let (pool, fixture) = setup();
+
+    for format in &["markdown", "html"] {
+        let uri = |id: i32| format!("/articles/{}/{}", id, format);
+        let (status, _) = get(&pool, Visibility::Public, &uri(fixture.published)).await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, _) = get(&pool, Visibility::Public, &uri(fixture.draft)).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        let (status, _) = get(&pool, Visibility::Admin, &uri(fixture.draft)).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+}
+
+#[actix_rt::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn list_hides_drafts_from_the_public() {
+    let (pool, fixture) = setup();
+
+    let (status, articles) = get(&pool, Visibility::Public, "/articles").await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(contains(&articles, fixture.published));
+    assert!(!contains(&articles, fixture.draft));
+
+    let (_, articles) = get(&pool, Visibility::Admin, "/articles").await;
+    assert!(contains(&articles, fixture.draft));
+}
+
+#[actix_rt::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn tag_results_hide_drafts_from_the_public() {
+    let (pool, fixture) = setup();
+    let uri = format!("/tags/{}", TAG);
+
+    let (status, results) = get(&pool, Visibility::Public, &uri).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(contains(&results["articles"], fixture.published));
+    assert!(!contains(&results["articles"], fixture.draft));
+
+    let (_, results) = get(&pool, Visibility::Admin, &uri).await;
+    assert!(contains(&results["articles"], fixture.draft));
+}
+
+#[actix_rt::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn search_hides_drafts_from_the_public() {
+    let (pool, fixture) = setup();
+    let uri = format!("/search?text={}", WORD);
+
+    let (status, results) = get(&pool, Visibility::Public, &uri).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(contains(&results["articles"], fixture.published));
+    assert!(!contains(&results["articles"], fixture.draft));
+
+    let (_, results) = get(&pool, Visibility::Admin, &uri).await;
+    assert!(contains(&results["articles"], fixture.draft));
+}
+
+#[actix_rt::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn preview_shows_the_draft_of_a_valid_token_only() {
+    std::env::set_var("PREVIEW_SECRET", "visibility-test-secret");
+    let (pool, fixture) = setup();
+
+    let token = sign(
+        fixture.draft,
+        &(Utc::now() + Duration::hours(1)).naive_utc(),
+    )
+    .unwrap();
+    let (status, article) = get(&pool, Visibility::Public, &format!("/preview/{}", token)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(article["id"], fixture.draft);
+
+    // Signed for another article.
+    let tampered = token.replacen(
+        &fixture.draft.to_string(),
+        &fixture.published.to_string(),
+        1,
+    );
+    let (status, _) = get(&pool, Visibility::Public, &format!("/preview/{}", tampered)).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let expired = sign(
+        fixture.draft,
+        &(Utc::now() - Duration::hours(1)).naive_utc(),
+    )
+    .unwrap();
+    let (status, _) = get(&pool, Visibility::Public, &format!("/preview/{}", expired)).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[actix_rt::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn series_hide_draft_parts_from_the_public() {
+    let (pool, fixture) = setup();
+    let series_id: i32 = {
+        let connection = pool.get().unwrap();
+        let series_id = diesel::insert_into(series::table)
+            .values(series::title.eq(TAG))
+            .returning(series::id)
+            .get_result(&connection)
+            .unwrap();
+        diesel::insert_into(series_articles::table)
+            .values(&vec![
+                (
+                    series_articles::series_id.eq(series_id),
+                    series_articles::article_id.eq(fixture.draft),
+                    series_articles::index.eq(0),
+                ),
+                (
+                    series_articles::series_id.eq(series_id),
+                    series_articles::article_id.eq(fixture.published),
+                    series_articles::index.eq(1),
+                ),
+            ])
+            .execute(&connection)
+            .unwrap();
+        series_id
+    };
+
+    let (status, series) = get(&pool, Visibility::Public, &format!("/series/{}", series_id)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(series["parts"].as_array().unwrap().len(), 1);
+    let uri = format!("/articles/{}", fixture.published);
+    let (_, article) = get(&pool, Visibility::Public, &uri).await;
+    assert_eq!(article["series"]["part"], 1);
+    assert_eq!(article["series"]["previous"], Value::Null);
+
+    let (_, article) = get(&pool, Visibility::Admin, &uri).await;
+    assert_eq!(article["series"]["part"], 2);
+    assert_eq!(article["series"]["previous"]["id"], fixture.draft);
+}
+
+#[actix_rt::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn series_list_hides_series_without_published_parts() {
+    let (pool, fixture) = setup();
+    let (drafts, empty) = {
+        let connection = pool.get().unwrap();
+        let new_series = |title: &str| -> i32 {
+            diesel::insert_into(series::table)
+                .values(series::title.eq(title))
+                .returning(series::id)
+                .get_result(&connection)
+                .unwrap()
+        };
+        let (drafts, empty) = (new_series("Drafts"), new_series("Empty"));
+        diesel::insert_into(series_articles::table)
+            .values((
+                series_articles::series_id.eq(drafts),
+                series_articles::article_id.eq(fixture.draft),
+                series_articles::index.eq(0),
+            ))
+            .execute(&connection)
+            .unwrap();
+        (drafts, empty)
+    };
+    let listed = |list: &Value, id: i32| {
+        list.as_array()
+            .unwrap()
+            .iter()
+            .any(|series| series["id"] == id)
+    };
+
+    let (status, list) = get(&pool, Visibility::Public, "/series").await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(!listed(&list, drafts));
+    assert!(!listed(&list, empty));
+
+    let (_, list) = get(&pool, Visibility::Admin, "/series").await;
+    assert!(listed(&list, drafts));
+    assert!(listed(&list, empty));
+}
+
+#[actix_rt::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn related_articles_are_published_only() {
+    let (pool, fixture) = setup();
+    // Shares the tag and the headline of both articles of the fixture.
+    let other = {
+        let connection = pool.get().unwrap();
+        let other = insert_article("Other", true, &connection);
+        let tag_id: i32 = tags::table
+            .filter(tags::label.eq(TAG))
+            .select(tags::id)
+            .first(&connection)
+            .unwrap();
+        diesel::insert_into(article_tags::table)
+            .values((
+                article_tags::article_id.eq(other),
+                article_tags::tag_id.eq(tag_id),
+            ))
+            .execute(&connection)
+            .unwrap();
+        other
+    };
+
+    let uri = format!("/articles/{}/related?limit=20", fixture.published);
+    let (status, items) = get(&pool, Visibility::Public, &uri).await;
+    assert_eq!(status, StatusCode::OK);
+    let ids: Vec<&Value> = items
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|item| item["kind"] == "article")
+        .map(|item| &item["id"])
+        .collect();
+    assert_eq!(ids.first(), Some(&&Value::from(other)));
+    assert!(!ids.contains(&&Value::from(fixture.draft)));
+    assert!(!ids.contains(&&Value::from(fixture.published)));
+
+    // Related to a draft, for its readers only.
+    let uri = format!("/articles/{}/related", fixture.draft);
+    let (status, _) = get(&pool, Visibility::Public, &uri).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (status, _) = get(&pool, Visibility::Admin, &uri).await;
+    assert_eq!(status, StatusCode::OK);
+}
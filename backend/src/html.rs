@@ -0,0 +1,281 @@
+/*
+ * HTML export of whole articles, a standalone document using the markup stored when the contents were saved (rich
+ * text, MathML, highlighted code) and ending with the numbered references, each one linked back to its citations.
+ */
+use {
+    crate::{
+        models::{
+            articles::ArticleRepresentation, contents::ContentRepresentation,
+            references::ReferenceRepresentation,
+        },
+        types::{
+            callout::Callout,
+            content_type::ContentType,
+            image::Image,
+            list::{List, ListItem},
+            player::{EmbedProvider, Player},
+            quote::Quote,
+            table::{Alignment, Table},
+        },
+        API_URL,
+    },
+    std::fmt::Write,
+};
+
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn figcaption(caption: Option<&str>) -> String {
+    caption
+        .filter(|caption| !caption.is_empty())
+        .map(|caption| format!("<figcaption>{}</figcaption>", escape_html(caption)))
+        .unwrap_or_default()
+}
+
+fn table_to_html(table: &Table) -> String {
+    let cell = |tag: &str, column: usize, text: &str| match table.alignment(column) {
+        Alignment::None => format!("<{tag}>{}</{tag}>", escape_html(text)),
+        alignment => format!(
+            "<{tag} style=\"text-align: {}\">{}</{tag}>",
+            format!("{:?}", alignment).to_lowercase(),
+            escape_html(text)
+        ),
+    };
+    let mut html = String::from("<figure><table><thead><tr>");
+    for (column, text) in table.header.iter().enumerate() {
+        html.push_str(&cell("th", column, text));
+    }
+    html.push_str("</tr></thead><tbody>");
+    for row in &table.rows {
+        html.push_str("<tr>");
+        for (column, text) in row.iter().enumerate() {
+            html.push_str(&cell("td", column, text));
+        }
+        html.push_str("</tr>");
+    }
+    html.push_str("</tbody></table>");
+    html.push_str(&figcaption(table.caption.as_deref()));
+    html.push_str("</figure>");
+    html
+}
+
+fn list_items_to_html(items: &[ListItem], ordered: bool) -> String {
+    let tag = if ordered { "ol" } else { "ul" };
+    let mut html = format!("<{}>", tag);
+    for item in items {
+        let _ = write!(html, "<li>{}", escape_html(&item.text));
+        if !item.items.is_empty() {
+            html.push_str(&list_items_to_html(&item.items, ordered));
+        }
+        html.push_str("</li>");
+    }
+    let _ = write!(html, "</{}>", tag);
+    html
+}
+
+fn player_to_html(content: &ContentRepresentation) -> String {
+    if let Some(url) = content.url.as_deref().filter(|url| !url.is_empty()) {
+        let name = match EmbedProvider::parse(url) {
+            Some((EmbedProvider::Vimeo, _)) => "Vimeo",
+            _ => "YouTube",
+        };
+        return format!(
+            "<figure><a href=\"{}\">Watch on {}</a>{}</figure>",
+            escape_html(url),
+            name,
+            figcaption(content.caption.as_deref())
+        );
+    }
+
+    let player = Player::from_data(&content.data).unwrap_or_default();
+    let element = match content.content_type {
+        ContentType::Video => "video",
+        _ => "audio",
+    };
+    let mut html = format!(
+        "<figure><{} controls src=\"{}\"",
+        element,
+        escape_html(&content.content)
+    );
+    if let Some(poster) = &player.poster {
+        let _ = write!(html, " poster=\"{}{}\"", API_URL, escape_html(poster));
+    }
+    html.push('>');
+    for track in &player.tracks {
+        let _ = write!(
+            html,
+            "<track kind=\"{}\" src=\"{}{}\" srclang=\"{}\" label=\"{}\">",
+            format!("{:?}", track.kind).to_lowercase(),
+            API_URL,
+            escape_html(&track.src),
+            escape_html(&track.srclang),
+            escape_html(&track.label)
+        );
+    }
+    let _ = write!(
+        html,
+        "</{}>{}</figure>",
+        element,
+        figcaption(content.caption.as_deref())
+    );
+    html
+}
+
+fn image_to_html(content: &ContentRepresentation) -> String {
+    let image = Image::from_data(&content.data).unwrap_or_default();
+    let mut html = format!(
+        "<figure><img src=\"{}\" alt=\"{}\"",
+        escape_html(&content.content),
+        escape_html(&image.alt)
+    );
+    if let (Some(width), Some(height)) = (image.width, image.height) {
+        let _ = write!(html, " width=\"{}\" height=\"{}\"", width, height);
+    }
+    html.push('>');
+    let caption = [content.caption.as_deref(), image.credits.as_deref()]
+        .iter()
+        .flatten()
+        .filter(|text| !text.is_empty())
+        .copied()
+        .collect::<Vec<_>>()
+        .join(" — ");
+    html.push_str(&figcaption(Some(&caption)));
+    html.push_str("</figure>");
+    html
+}
+
+fn content_to_html(content: &ContentRepresentation) -> String {
+    match content.content_type {
+        ContentType::Text => match &content.rendered_html {
+            Some(html) => format!("<div class=\"rich-text\">{}</div>", html),
+            None => format!("<p>{}</p>", escape_html(&content.content)),
+        },
+        ContentType::Comment => {
+            format!("<blockquote>{}</blockquote>", escape_html(&content.content))
+        }
+        ContentType::Link => format!(
+            "<p><a href=\"{}\">{}</a></p>",
+            escape_html(content.url.as_deref().unwrap_or_default()),
+            escape_html(&content.content)
+        ),
+        ContentType::Image => image_to_html(content),
+        ContentType::Code => format!(
+            "<figure><pre><code>{}</code></pre>{}</figure>",
+            content
+                .highlighted_code
+                .clone()
+                .unwrap_or_else(|| escape_html(&content.content)),
+            figcaption(content.caption.as_deref())
+        ),
+        ContentType::Table => Table::from_data(&content.data)
+            .map(|table| table_to_html(&table))
+            .unwrap_or_default(),
+        ContentType::Math => content.rendered_html.clone().unwrap_or_default(),
+        ContentType::Callout => {
+            let callout = Callout::from_data(&content.data).unwrap_or_default();
+            format!(
+                "<aside class=\"callout callout-{}\"><strong>{}</strong> {}</aside>",
+                format!("{:?}", callout.kind).to_lowercase(),
+                callout.kind,
+                escape_html(&content.content)
+            )
+        }
+        ContentType::Quote => {
+            let quote = Quote::from_data(&content.data).unwrap_or_default();
+            let attribution = match (quote.attribution.as_deref(), quote.source_url.as_deref()) {
+                (Some(attribution), Some(url)) => format!(
+                    "<figcaption>— <a href=\"{}\">{}</a></figcaption>",
+                    escape_html(url),
+                    escape_html(attribution)
+                ),
+                (Some(attribution), None) => {
+                    format!("<figcaption>— {}</figcaption>", escape_html(attribution))
+                }
+                _ => String::new(),
+            };
+            format!(
+                "<figure><blockquote>{}</blockquote>{}</figure>",
+                escape_html(&content.content),
+                attribution
+            )
+        }
+        ContentType::List => List::from_data(&content.data)
+            .map(|list| list_items_to_html(&list.items, list.ordered))
+            .unwrap_or_default(),
+        ContentType::Video | ContentType::Audio => player_to_html(content),
+    }
+}
+
+fn references_to_html(references: &[ReferenceRepresentation]) -> String {
+    let mut html = String::from("<section class=\"references\"><h2>References</h2><ol>");
+    for reference in references {
+        let key = escape_html(&reference.key);
+        let _ = write!(html, "<li id=\"reference-{}\">", key);
+        match &reference.url {
+            Some(url) => {
+                let _ = write!(
+                    html,
+                    "<a href=\"{}\">{}</a>",
+                    escape_html(url),
+                    escape_html(&reference.title)
+                );
+            }
+            None => html.push_str(&escape_html(&reference.title)),
+        }
+        for citation in 1..=reference.citations {
+            let _ = write!(
+                html,
+                " <a class=\"back-link\" href=\"#citation-{}-{}\">↩</a>",
+                key, citation
+            );
+        }
+        html.push_str("</li>");
+    }
+    html.push_str("</ol></section>");
+    html
+}
+
+pub fn export_article(article: &ArticleRepresentation) -> String {
+    let title = escape_html(&article.title);
+    let mut html = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n</head>\n<body>\n<article>\n<h1>{}</h1>\n",
+        title, title
+    );
+    let _ = writeln!(html, "<p>{}</p>", escape_html(&article.headline));
+    // The image is prefixed by the API URL even when there is none.
+    if article.image != API_URL {
+        let _ = writeln!(
+            html,
+            "<figure><img src=\"{}\" alt=\"{}\">{}</figure>",
+            escape_html(&article.image),
+            escape_html(&article.image_alt),
+            figcaption(article.image_credits.as_deref())
+        );
+    }
+    for chapter in &article.chapters {
+        let _ = writeln!(html, "<h2>{}</h2>", escape_html(&chapter.title));
+        for content in &chapter.contents {
+            html.push_str(&content_to_html(content));
+            html.push('\n');
+        }
+    }
+    if !article.references.is_empty() {
+        html.push_str(&references_to_html(&article.references));
+        html.push('\n');
+    }
+    html.push_str("</article>\n</body>\n</html>\n");
+
+    html
+}
@@ -7,6 +7,9 @@ use {
     std::{collections::HashMap, fmt::Debug},
 };
 
+#[cfg(feature = "editable")]
+use crate::code::CodeLines;
+
 #[cfg(feature = "editable")]
 #[derive(Debug, Serialize)]
 pub enum Status {
@@ -43,7 +46,7 @@ pub struct InputTag {
     pub id: i32,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct InputContent {
     pub chapter_id: i32,
     pub article_id: i32,
@@ -53,9 +56,27 @@ pub struct InputContent {
     pub language: Option<Language>,
     pub highlighted_code: Option<String>,
     pub url: Option<String>,
+    pub caption: Option<String>,
+    pub start_line: Option<i32>,
+    pub emphasized_lines: Option<String>,
+    pub added_lines: Option<String>,
+    pub removed_lines: Option<String>,
+    pub data: Option<serde_json::Value>,
+}
+
+#[cfg(feature = "editable")]
+impl InputContent {
+    pub fn code_lines(&self) -> CodeLines {
+        CodeLines::new(
+            self.start_line,
+            self.emphasized_lines.as_deref(),
+            self.added_lines.as_deref(),
+            self.removed_lines.as_deref(),
+        )
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct InputChapter {
     pub article_id: i32,
     pub index: i32,
@@ -63,15 +84,55 @@ pub struct InputChapter {
     pub contents: Vec<InputContent>,
 }
 
+// New position of a moved chapter, in the same or another article.
+#[derive(Debug, Deserialize)]
+pub struct InputChapterMove {
+    pub article_id: i32,
+    pub index: i32,
+}
+
+// New position of a moved content, in the same or another chapter.
 #[derive(Debug, Deserialize)]
+pub struct InputContentMove {
+    pub chapter_id: i32,
+    pub index: i32,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct InputReference {
+    pub article_id: i32,
+    pub index: i32,
+    pub key: String,
+    pub title: String,
+    pub url: Option<String>,
+}
+
+// Edited fields of an existing reference, which keeps its article and its index.
+#[cfg(feature = "editable")]
+#[derive(Debug, Deserialize)]
+pub struct InputReferenceUpdate {
+    pub key: String,
+    pub title: String,
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
 pub struct InputArticle {
     pub title: String,
     pub published: bool,
     pub headline: String,
     pub image: String,
+    #[serde(default)]
+    pub image_alt: String,
     pub image_credits: Option<String>,
+    #[serde(default)]
+    pub publish_at: Option<chrono::NaiveDateTime>,
+    #[serde(default)]
+    pub unpublish_at: Option<chrono::NaiveDateTime>,
     pub tags: Vec<InputTag>,
     pub chapters: Vec<InputChapter>,
+    #[serde(default)]
+    pub references: Vec<InputReference>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -79,7 +140,69 @@ pub struct InputPublishArticle {
     pub published: bool,
 }
 
+// Both times are optional, a missing one cancels the transition.
+#[cfg(feature = "editable")]
+#[derive(Debug, Deserialize)]
+pub struct InputScheduleArticle {
+    pub publish_at: Option<chrono::NaiveDateTime>,
+    pub unpublish_at: Option<chrono::NaiveDateTime>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct BlogQuery {
     pub text: String,
 }
+
+#[derive(Debug, Deserialize)]
+pub struct RelatedQuery {
+    pub limit: Option<i64>,
+}
+
+#[cfg(feature = "editable")]
+#[derive(Debug, Deserialize)]
+pub struct MediaQuery {
+    pub orphans: Option<bool>,
+}
+
+#[cfg(feature = "editable")]
+#[derive(Debug, Deserialize)]
+pub struct RevisionDiffQuery {
+    pub from: i32,
+    pub to: i32,
+}
+
+#[cfg(feature = "editable")]
+#[derive(Debug, Deserialize)]
+pub struct InputSeries {
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+}
+
+// Ids of the articles of a series, in order.
+#[cfg(feature = "editable")]
+#[derive(Debug, Deserialize)]
+pub struct InputSeriesParts {
+    pub articles: Vec<i32>,
+}
+
+#[cfg(feature = "editable")]
+fn default_preview_hours() -> i64 {
+    72
+}
+
+// Validity of a preview link, in hours.
+#[cfg(feature = "editable")]
+#[derive(Debug, Deserialize)]
+pub struct InputPreviewLink {
+    #[serde(default = "default_preview_hours")]
+    pub hours: i64,
+}
+
+#[cfg(feature = "editable")]
+#[derive(Debug, Serialize)]
+pub struct PreviewLink {
+    pub token: String,
+    pub url: String,
+    pub expires: chrono::NaiveDateTime,
+}
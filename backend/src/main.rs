@@ -8,30 +8,48 @@ extern crate log;
 
 use {
     actix_cors::Cors,
-    actix_files as fs,
     actix_web::{http, middleware, web, App, HttpServer},
+    cache::ResponseCache,
     diesel::r2d2::{self, ConnectionManager},
     diesel::PgConnection,
     logger::custom_logger::Logger,
+    models::visibility::Visibility,
 };
 
 #[cfg(debug_assertions)]
 use log::info;
 
+mod cache;
 mod code;
+mod conditional;
+#[cfg(feature = "editable")]
+mod diff;
 mod errors;
 mod handlers;
+mod html;
 mod interfaces;
 mod logger;
+mod markdown;
+#[cfg(feature = "editable")]
+mod math;
 mod models;
+mod preview;
+#[cfg(feature = "editable")]
+mod rich_text;
+mod scheduler;
 mod schema;
 mod service;
 mod types;
+#[cfg(feature = "editable")]
+mod validation;
 
 // Constants
 const API_URL: &str = dotenv!("API_URL");
 const DATABASE_URL: &str = dotenv!("DATABASE_URL");
 const DISPLAY_UNPUBLISHED_ARTICLES: &str = dotenv!("DISPLAY_UNPUBLISHED_ARTICLES");
+const MEDIA_ROOT: &str = "./media";
+#[cfg(feature = "editable")]
+const MEDIA_URL_PREFIX: &str = "/media";
 
 pub type Pool = r2d2::Pool<ConnectionManager<PgConnection>>;
 
@@ -53,6 +71,13 @@ async fn main() -> std::io::Result<()> {
         info!("Database URL: {}", DATABASE_URL);
     }
 
+    // Shared by all the workers.
+    let cache = web::Data::new(ResponseCache::from_env());
+    let visibility = web::Data::new(Visibility::reader());
+
+    scheduler::start(pool.clone(), cache.clone());
+    cache::watch(pool.clone(), cache.clone());
+
     HttpServer::new(move || {
         #[cfg(not(feature = "editable"))]
         return App::new()
@@ -69,19 +94,50 @@ async fn main() -> std::io::Result<()> {
                     .allowed_methods(vec!["GET", "OPTIONS"])
                     .allowed_headers(vec![http::header::AUTHORIZATION, http::header::ACCEPT])
                     .allowed_header(http::header::CONTENT_TYPE)
+                    .allowed_headers(vec![
+                        http::header::IF_NONE_MATCH,
+                        http::header::IF_MODIFIED_SINCE,
+                    ])
+                    .expose_headers(vec![http::header::ETAG, http::header::LAST_MODIFIED])
                     .max_age(3600),
             )
-            .service(fs::Files::new("/media", "./media").show_files_listing())
+            .service(
+                web::resource("/media/{path:.*}")
+                    .route(web::get().to(handlers::media_files::serve))
+                    .route(web::head().to(handlers::media_files::serve)),
+            )
             .data(pool.clone())
+            .app_data(cache.clone())
+            .app_data(visibility.clone())
             .route("/articles/{id}", web::get().to(handlers::articles::get))
+            .route(
+                "/articles/{id}/markdown",
+                web::get().to(handlers::articles::export_markdown),
+            )
+            .route(
+                "/articles/{id}/html",
+                web::get().to(handlers::articles::export_html),
+            )
+            .route(
+                "/articles/{id}/related",
+                web::get().to(handlers::articles::related),
+            )
             .route("/articles", web::get().to(handlers::articles::list))
+            .route("/preview/{token}", web::get().to(handlers::preview::get))
             .route(
                 "/tags/{tag}",
                 web::get().to(handlers::tags::get_results_for_tag),
             )
             .route("/tags", web::get().to(handlers::tags::list))
             .route("/search", web::get().to(handlers::search::search))
-            .route("/projects", web::get().to(handlers::projects::list));
+            .route("/projects", web::get().to(handlers::projects::list))
+            .route("/series", web::get().to(handlers::series::list))
+            .route("/series/{id}", web::get().to(handlers::series::get))
+            .route("/code/themes", web::get().to(handlers::code::themes))
+            .route(
+                "/code/themes/{theme}",
+                web::get().to(handlers::code::stylesheet),
+            );
 
         #[cfg(feature = "editable")]
         return App::new()
@@ -96,37 +152,101 @@ async fn main() -> std::io::Result<()> {
                     .allowed_methods(vec!["GET", "POST", "PATCH", "OPTIONS", "DELETE"])
                     .allowed_headers(vec![http::header::AUTHORIZATION, http::header::ACCEPT])
                     .allowed_header(http::header::CONTENT_TYPE)
+                    .allowed_header("X-Author")
+                    .allowed_headers(vec![
+                        http::header::IF_NONE_MATCH,
+                        http::header::IF_MODIFIED_SINCE,
+                    ])
+                    .expose_headers(vec![http::header::ETAG, http::header::LAST_MODIFIED])
                     .max_age(3600),
             )
-            .service(fs::Files::new("/media", "./media").show_files_listing())
+            .service(
+                web::resource("/media/{path:.*}")
+                    .route(web::get().to(handlers::media_files::serve))
+                    .route(web::head().to(handlers::media_files::serve)),
+            )
             .data(pool.clone())
+            .app_data(cache.clone())
+            .app_data(visibility.clone())
+            .app_data(web::JsonConfig::default().error_handler(errors::api_error::json_error))
             .service(
                 web::resource("/articles")
                     .route(web::get().to(handlers::articles::list))
                     .route(web::post().to(handlers::articles::add)),
             )
+            .service(
+                web::resource("/articles/scheduled")
+                    .route(web::get().to(handlers::articles::scheduled)),
+            )
             .service(
                 web::resource("/articles/{id}")
                     .route(web::get().to(handlers::articles::get))
                     .route(web::patch().to(handlers::articles::update))
                     .route(web::delete().to(handlers::articles::delete)),
             )
+            .route(
+                "/articles/{id}/markdown",
+                web::get().to(handlers::articles::export_markdown),
+            )
+            .route(
+                "/articles/{id}/html",
+                web::get().to(handlers::articles::export_html),
+            )
+            .route(
+                "/articles/{id}/revisions",
+                web::get().to(handlers::revisions::list),
+            )
+            .route(
+                "/articles/{id}/revisions/diff",
+                web::get().to(handlers::revisions::diff),
+            )
+            .route(
+                "/articles/{id}/revisions/{revision_id}/restore",
+                web::post().to(handlers::revisions::restore),
+            )
+            .route(
+                "/articles/{id}/preview",
+                web::post().to(handlers::preview::create),
+            )
+            .route(
+                "/articles/{id}/related",
+                web::get().to(handlers::articles::related),
+            )
+            .route("/preview/{token}", web::get().to(handlers::preview::get))
             .service(
                 web::resource("/articles/publish/{id}")
                     .route(web::patch().to(handlers::articles::publish)),
             )
+            .service(
+                web::resource("/articles/schedule/{id}")
+                    .route(web::patch().to(handlers::articles::schedule)),
+            )
             .service(web::resource("/chapters").route(web::post().to(handlers::chapters::add)))
             .service(
                 web::resource("/chapters/{id}")
                     .route(web::patch().to(handlers::chapters::update))
                     .route(web::delete().to(handlers::chapters::delete)),
             )
+            .service(
+                web::resource("/chapters/{id}/move")
+                    .route(web::patch().to(handlers::chapters::move_to)),
+            )
             .service(web::resource("/contents").route(web::post().to(handlers::contents::add)))
             .service(
                 web::resource("/contents/{id}")
                     .route(web::patch().to(handlers::contents::update))
                     .route(web::delete().to(handlers::contents::delete)),
             )
+            .service(
+                web::resource("/contents/{id}/move")
+                    .route(web::patch().to(handlers::contents::move_to)),
+            )
+            .service(web::resource("/references").route(web::post().to(handlers::references::add)))
+            .service(
+                web::resource("/references/{id}")
+                    .route(web::patch().to(handlers::references::update))
+                    .route(web::delete().to(handlers::references::delete)),
+            )
             .route(
                 "/tags/{tag}",
                 web::get().to(handlers::tags::get_results_for_tag),
@@ -135,9 +255,41 @@ async fn main() -> std::io::Result<()> {
             .route("/search", web::get().to(handlers::search::search))
             .route("/projects", web::get().to(handlers::projects::list))
             .route("/projects/{id}", web::get().to(handlers::projects::get))
+            .service(
+                web::resource("/series")
+                    .route(web::get().to(handlers::series::list))
+                    .route(web::post().to(handlers::series::add)),
+            )
+            .service(
+                web::resource("/series/{id}")
+                    .route(web::get().to(handlers::series::get))
+                    .route(web::patch().to(handlers::series::update))
+                    .route(web::delete().to(handlers::series::delete)),
+            )
+            .service(
+                web::resource("/series/{id}/articles")
+                    .route(web::patch().to(handlers::series::set_parts)),
+            )
             .route(
                 "/resume-projects",
                 web::get().to(handlers::resume_projects::list),
+            )
+            .route("/cache/stats", web::get().to(handlers::cache::stats))
+            .route("/code/themes", web::get().to(handlers::code::themes))
+            .route(
+                "/code/themes/{theme}",
+                web::get().to(handlers::code::stylesheet),
+            )
+            .route(
+                "/code/rehighlight",
+                web::post().to(handlers::code::rehighlight),
+            )
+            .route("/media-library", web::get().to(handlers::media::list))
+            .route("/media-library/scan", web::post().to(handlers::media::scan))
+            .service(
+                web::resource("/media-library/{id}")
+                    .route(web::get().to(handlers::media::get))
+                    .route(web::delete().to(handlers::media::delete)),
             );
     })
     .bind(("0.0.0.0", 8080))?
@@ -0,0 +1,279 @@
+/*
+ * Markdown export of whole articles.
+ *     # Title
+ *     Headline paragraphs and an optional ![alt text](image "credits") before the first chapter.
+ *     ## Chapter
+ *     Contents: paragraphs (Text), > quotes (Comment), [text](url) alone on a paragraph (Link), ![alt text](path "caption") (Image),
+ *     fenced code with an optional title="caption" (Code), pipe tables followed by an optional "Table: caption" line (Table),
+ *     $$ formulas $$ (Math), > [!WARNING] quotes (Callout), quotes ending with a "— attribution (url)" line (Quote)
+ *     and - / 1. lists, nested with 4 spaces (List).
+ *     Videos and audios are exported as <video> and <audio> elements (or links for external videos).
+ *     [^key]: Title <https://...>       (a reference, cited with [^key] in texts)
+ */
+use {
+    crate::{
+        models::{articles::ArticleRepresentation, contents::ContentRepresentation},
+        types::{
+            callout::Callout,
+            content_type::ContentType,
+            image::Image,
+            language::Language,
+            list::{List, ListItem},
+            player::Player,
+            quote::Quote,
+            table::{Alignment, Table},
+        },
+        API_URL,
+    },
+    std::fmt::Write,
+};
+
+const TABLE_CAPTION_PREFIX: &str = "Table: ";
+const ATTRIBUTION_PREFIX: &str = "— ";
+
+// Info string of the fenced code blocks.
+fn language_token(language: &Language) -> &'static str {
+    match language {
+        Language::Rust => "rust",
+        Language::Bash => "bash",
+        Language::Python => "python",
+        Language::Sql => "sql",
+        Language::Html => "html",
+        Language::Css => "css",
+        Language::Javascript => "javascript",
+        Language::Typescript => "typescript",
+        Language::Yaml => "yaml",
+        Language::C => "c",
+        Language::Cpp => "cpp",
+        Language::Haskell => "haskell",
+        Language::Go => "go",
+        Language::Toml => "toml",
+        Language::Json => "json",
+        Language::Dockerfile => "dockerfile",
+        Language::Markdown => "markdown",
+        Language::Diff => "diff",
+        Language::PlainText => "text",
+    }
+}
+
+fn escape_cell(cell: &str) -> String {
+    cell.replace('|', "\\|").replace('\n', " ")
+}
+
+fn table_to_markdown(table: &Table) -> String {
+    let row = |cells: &mut dyn Iterator<Item = String>| {
+        let mut row = String::from("|");
+        for cell in cells {
+            row.push(' ');
+            row.push_str(&cell);
+            row.push_str(" |");
+        }
+        row.push('\n');
+        row
+    };
+
+    let mut markdown = row(&mut table.header.iter().map(|cell| escape_cell(cell)));
+    markdown.push_str(&row(&mut (0..table.header.len()).map(|column| {
+        match table.alignment(column) {
+            Alignment::None => "---",
+            Alignment::Left => ":---",
+            Alignment::Center => ":---:",
+            Alignment::Right => "---:",
+        }
+        .to_owned()
+    })));
+    for cells in &table.rows {
+        markdown.push_str(&row(&mut cells.iter().map(|cell| escape_cell(cell))));
+    }
+    if let Some(caption) = table
+        .caption
+        .as_deref()
+        .filter(|caption| !caption.is_empty())
+    {
+        markdown.push('\n');
+        markdown.push_str(TABLE_CAPTION_PREFIX);
+        markdown.push_str(caption);
+        markdown.push('\n');
+    }
+
+    markdown
+}
+
+fn quote_lines(text: &str) -> String {
+    text.lines()
+        .map(|line| format!("> {}\n", line).replace("> \n", ">\n"))
+        .collect()
+}
+
+fn list_items_to_markdown(items: &[ListItem], ordered: bool, depth: usize, markdown: &mut String) {
+    for (index, item) in items.iter().enumerate() {
+        let indent = "    ".repeat(depth);
+        let _ = if ordered {
+            writeln!(markdown, "{}{}. {}", indent, index + 1, item.text)
+        } else {
+            writeln!(markdown, "{}- {}", indent, item.text)
+        };
+        list_items_to_markdown(&item.items, ordered, depth + 1, markdown);
+    }
+}
+
+fn player_to_markdown(content: &ContentRepresentation) -> String {
+    let caption = content
+        .caption
+        .as_deref()
+        .filter(|caption| !caption.is_empty());
+    if let Some(url) = content.url.as_deref().filter(|url| !url.is_empty()) {
+        return format!("[{}]({})\n", caption.unwrap_or("Video"), url);
+    }
+
+    let player = Player::from_data(&content.data).unwrap_or_default();
+    let element = match content.content_type {
+        ContentType::Video => "video",
+        _ => "audio",
+    };
+    let mut markdown = format!("<{} controls src=\"{}\"", element, content.content);
+    if let Some(poster) = &player.poster {
+        let _ = write!(markdown, " poster=\"{}{}\"", API_URL, poster);
+    }
+    if let Some(caption) = caption {
+        let _ = write!(markdown, " title=\"{}\"", caption.replace('"', "'"));
+    }
+    markdown.push('>');
+    for track in &player.tracks {
+        let _ = write!(
+            markdown,
+            "\n  <track kind=\"{}\" src=\"{}{}\" srclang=\"{}\" label=\"{}\">",
+            format!("{:?}", track.kind).to_lowercase(),
+            API_URL,
+            track.src,
+            track.srclang,
+            track.label.replace('"', "'")
+        );
+    }
+    let _ = writeln!(markdown, "\n</{}>", element);
+    markdown
+}
+
+// The optional title of an image destination, ` "title"`.
+fn image_title(title: Option<&str>) -> String {
+    title
+        .filter(|title| !title.is_empty())
+        .map(|title| format!(" \"{}\"", title.replace('"', "'")))
+        .unwrap_or_default()
+}
+
+fn content_to_markdown(content: &ContentRepresentation) -> String {
+    match content.content_type {
+        ContentType::Text => format!("{}\n", content.content),
+        ContentType::Comment => quote_lines(&content.content),
+        ContentType::Link => format!(
+            "[{}]({})\n",
+            content.content,
+            content.url.as_deref().unwrap_or_default()
+        ),
+        ContentType::Image => {
+            let image = Image::from_data(&content.data).unwrap_or_default();
+            format!(
+                "![{}]({}{})\n",
+                image.alt,
+                content.content,
+                image_title(content.caption.as_deref())
+            )
+        }
+        ContentType::Code => {
+            let language = content.language.clone().unwrap_or_default();
+            let mut info = language_token(&language).to_owned();
+            if let Some(caption) = content
+                .caption
+                .as_deref()
+                .filter(|caption| !caption.is_empty())
+            {
+                let _ = write!(info, " title=\"{}\"", caption.replace('"', "'"));
+            }
+            // The fence is longer than any backtick run of the code.
+            let fence = "`".repeat(
+                content
+                    .content
+                    .split(|c| c != '`')
+                    .map(str::len)
+                    .max()
+                    .unwrap_or_default()
+                    .max(2)
+                    + 1,
+            );
+            format!("{}{}\n{}\n{}\n", fence, info, content.content, fence)
+        }
+        ContentType::Table => Table::from_data(&content.data)
+            .map(|table| table_to_markdown(&table))
+            .unwrap_or_default(),
+        ContentType::Math => format!("$$\n{}\n$$\n", content.content.trim()),
+        ContentType::Callout => {
+            let kind = Callout::from_data(&content.data).unwrap_or_default().kind;
+            quote_lines(&format!(
+                "[!{}]\n{}",
+                kind.to_string().to_uppercase(),
+                content.content
+            ))
+        }
+        ContentType::Quote => {
+            let quote = Quote::from_data(&content.data).unwrap_or_default();
+            let mut markdown = quote_lines(&content.content);
+            let attribution = match (quote.attribution, quote.source_url) {
+                (Some(attribution), Some(url)) => Some(format!("{} ({})", attribution, url)),
+                (Some(attribution), None) => Some(attribution),
+                (None, Some(url)) => Some(url),
+                (None, None) => None,
+            };
+            if let Some(attribution) = attribution {
+                let _ = write!(markdown, ">\n> {}{}\n", ATTRIBUTION_PREFIX, attribution);
+            }
+            markdown
+        }
+        ContentType::List => {
+            let mut markdown = String::new();
+            if let Some(list) = List::from_data(&content.data) {
+                list_items_to_markdown(&list.items, list.ordered, 0, &mut markdown);
+            }
+            markdown
+        }
+        ContentType::Video | ContentType::Audio => player_to_markdown(content),
+    }
+}
+
+pub fn export_article(article: &ArticleRepresentation) -> String {
+    let mut markdown = format!("# {}\n", article.title);
+    if !article.headline.is_empty() {
+        let _ = write!(markdown, "\n{}\n", article.headline);
+    }
+    if article.image != API_URL {
+        let _ = write!(
+            markdown,
+            "\n![{}]({}{})\n",
+            article.image_alt,
+            article.image,
+            image_title(article.image_credits.as_deref())
+        );
+    }
+    for chapter in &article.chapters {
+        let _ = write!(markdown, "\n## {}\n", chapter.title);
+        for content in &chapter.contents {
+            markdown.push('\n');
+            markdown.push_str(&content_to_markdown(content));
+        }
+    }
+    if !article.references.is_empty() {
+        markdown.push('\n');
+    }
+    for reference in &article.references {
+        let _ = match reference.url.as_deref() {
+            Some(url) => writeln!(
+                markdown,
+                "[^{}]: {} <{}>",
+                reference.key, reference.title, url
+            ),
+            None => writeln!(markdown, "[^{}]: {}", reference.key, reference.title),
+        };
+    }
+
+    markdown
+}
@@ -0,0 +1,252 @@
+/*
+ * LaTeX rendered to MathML when a content is saved, like highlight_code does for code, so that the frontend only injects
+ * the stored markup (browsers render MathML natively, no JavaScript library needed).
+ * Math contents hold a single formula displayed as a block. Text contents can hold inline formulas between dollars
+ * ("the area is $\pi r^2$"), "\$" being a literal dollar, see crate::rich_text.
+ * latex2mathml writes the symbols of the formula as they are ("a<b" gives <mo><</mo>) and has a few malformed templates,
+ * so its output is checked: the tags it generates are kept, any other "<", ">" or "&" is escaped, and a formula whose
+ * tags do not nest is rejected.
+ */
+use latex2mathml::{latex_to_mathml, DisplayStyle};
+
+const INVALID_MATHML: &str = "This formula cannot be converted to valid MathML.";
+
+// The elements and attributes generated by latex2mathml.
+const ELEMENTS: [&str; 20] = [
+    "math",
+    "mi",
+    "mn",
+    "mo",
+    "mtext",
+    "mspace",
+    "mrow",
+    "mstyle",
+    "mfrac",
+    "msqrt",
+    "mroot",
+    "msub",
+    "msup",
+    "msubsup",
+    "mover",
+    "munder",
+    "munderover",
+    "mtable",
+    "mtr",
+    "mtd",
+];
+const ATTRIBUTES: [&str; 12] = [
+    "xmlns",
+    "display",
+    "displaystyle",
+    "mathvariant",
+    "width",
+    "accent",
+    "stretchy",
+    "form",
+    "minsize",
+    "maxsize",
+    "linethickness",
+    "columnalign",
+];
+
+enum MathTag<'a> {
+    Open(&'a str),
+    Close(&'a str),
+    Empty,
+}
+
+// A tag of one of the generated elements starting at the current "<", whose end is returned with it.
+fn tag(mathml: &str, start: usize) -> Option<(MathTag<'_>, usize)> {
+    let rest = &mathml[start + 1..];
+    let (closing, rest) = match rest.strip_prefix('/') {
+        Some(rest) => (true, rest),
+        None => (false, rest),
+    };
+    let name_end = rest
+        .find(|c: char| !c.is_ascii_alphanumeric())
+        .unwrap_or(rest.len());
+    let name = &rest[..name_end];
+    if !ELEMENTS.contains(&name) {
+        return None;
+    }
+    let mut rest = &rest[name_end..];
+    loop {
+        let trimmed = rest.trim_start_matches(' ');
+        if let Some(after) = trimmed.strip_prefix('>') {
+            let end = mathml.len() - after.len();
+            return Some((
+                if closing {
+                    MathTag::Close(name)
+                } else {
+                    MathTag::Open(name)
+                },
+                end,
+            ));
+        }
+        if let Some(after) = trimmed.strip_prefix("/>") {
+            return (!closing).then(|| (MathTag::Empty, mathml.len() - after.len()));
+        }
+        // An attribute, its value quoted or not (columnalign=left).
+        if closing || trimmed.len() == rest.len() {
+            return None;
+        }
+        let (attribute, value) = trimmed.split_once('=')?;
+        if !ATTRIBUTES.contains(&attribute) {
+            return None;
+        }
+        rest = match value.strip_prefix('"') {
+            Some(value) => {
+                let end = value.find(['"', '<', '>'])?;
+                value[end..].strip_prefix('"')?
+            }
+            None => {
+                let end =
+                    value.find(|c: char| !(c.is_ascii_alphanumeric() || c == '.' || c == '-'))?;
+                &value[end..]
+            }
+        };
+    }
+}
+
+// The numeric character reference (&#x2061;) starting a text, the only kind latex2mathml writes.
+fn character_reference(text: &str) -> Option<&str> {
+    let (code, _) = text.strip_prefix("&#")?.split_once(';')?;
+    let valid = match code.strip_prefix('x') {
+        Some(hex) => !hex.is_empty() && hex.chars().all(|c| c.is_ascii_hexdigit()),
+        None => !code.is_empty() && code.chars().all(|c| c.is_ascii_digit()),
+    };
+    valid.then(|| &text[..code.len() + 3])
+}
+
+fn check_mathml(mathml: &str) -> Result<String, String> {
+    let mut checked = String::with_capacity(mathml.len());
+    let mut open: Vec<&str> = Vec::new();
+    let mut chars = mathml.char_indices().peekable();
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '<' => match tag(mathml, index) {
+                Some((tag, end)) => {
+                    match tag {
+                        MathTag::Open(name) => open.push(name),
+                        MathTag::Close(name) if open.pop() != Some(name) => {
+                            return Err(INVALID_MATHML.to_owned())
+                        }
+                        _ => {}
+                    }
+                    checked.push_str(&mathml[index..end]);
+                    while chars.next_if(|(next, _)| *next < end).is_some() {}
+                }
+                None => checked.push_str("&lt;"),
+            },
+            '>' => checked.push_str("&gt;"),
+            '&' => match character_reference(&mathml[index..]) {
+                Some(reference) => {
+                    checked.push_str(reference);
+                    let end = index + reference.len();
+                    while chars.next_if(|(next, _)| *next < end).is_some() {}
+                }
+                None => checked.push_str("&amp;"),
+            },
+            c => checked.push(c),
+        }
+    }
+    if !open.is_empty() {
+        return Err(INVALID_MATHML.to_owned());
+    }
+
+    Ok(checked)
+}
+
+fn render(latex: &str, display: DisplayStyle) -> Result<String, String> {
+    let mathml = latex_to_mathml(latex.trim(), display).map_err(|error| error.to_string())?;
+    check_mathml(&mathml)
+}
+
+pub fn render_block(latex: &str) -> Result<String, String> {
+    render(latex, DisplayStyle::Block)
+}
+
+pub enum Segment {
+    Text(String),
+    Math(String),
+}
+
+// Splits a text on its inline formulas. Code spans are kept as text, a dollar in `code` is not math.
+pub fn segments(text: &str) -> Result<Vec<Segment>, String> {
+    let mut segments = Vec::new();
+    let (mut current, mut in_math) = (String::new(), false);
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek() == Some(&'$') => {
+                chars.next();
+                current.push('$');
+            }
+            '$' => {
+                let segment = std::mem::take(&mut current);
+                segments.push(if in_math {
+                    Segment::Math(segment)
+                } else {
+                    Segment::Text(segment)
+                });
+                in_math = !in_math;
+            }
+            '`' if !in_math => {
+                let mut fence = 1;
+                while chars.next_if_eq(&'`').is_some() {
+                    fence += 1;
+                }
+                current.push_str(&"`".repeat(fence));
+                // Up to the closing run of as many backticks, or the end of the text.
+                let mut run = 0;
+                while let Some(c) = chars.next() {
+                    current.push(c);
+                    run = if c == '`' { run + 1 } else { 0 };
+                    if run == fence && chars.peek() != Some(&'`') {
+                        break;
+                    }
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if in_math {
+        return Err("Inline math is not closed, a \"$\" is missing.".to_owned());
+    }
+    segments.push(Segment::Text(current));
+
+    Ok(segments)
+}
+
+pub fn render_inline(latex: &str) -> Result<String, String> {
+    render(latex, DisplayStyle::Inline)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_mathml, render_inline};
+
+    #[test]
+    fn symbols_of_the_formula_are_escaped() {
+        let mathml = render_inline("a < b").unwrap();
+        assert!(mathml.contains("<mo>&lt;</mo>"), "{}", mathml);
+        assert!(mathml.ends_with("</math>"));
+        assert!(render_inline("a > b").unwrap().contains("<mo>&gt;</mo>"));
+    }
+
+    #[test]
+    fn ampersands_are_escaped() {
+        let mathml = render_inline(r"a \& b").unwrap();
+        assert!(mathml.contains(">&amp;</mi>"), "{}", mathml);
+    }
+
+    #[test]
+    fn malformed_mathml_is_rejected() {
+        assert!(check_mathml("<mtext><mi></mtext>").is_err());
+        assert!(check_mathml("<mrow><mo maxsize=\"2em\" minsize=\"2em\">(</mro></mrow>").is_err());
+        assert_eq!(
+            check_mathml("<mi>f</mi><mo>&#x2061;</mo><mtext>R&D</mtext>").unwrap(),
+            "<mi>f</mi><mo>&#x2061;</mo><mtext>R&amp;D</mtext>"
+        );
+    }
+}
@@ -5,23 +5,30 @@ use {
         contents::{Content, NewContent},
     },
     crate::interfaces::{Status, TAPIResponse},
-    diesel::connection::Connection,
 };
 
 use {
     super::{
         chapters::{Chapter, ChapterRepresentation, NewChapterForm},
         from_model::FromModel,
+        references::{number_citations, NewReference, Reference, ReferenceRepresentation},
+        series::{ArticleSeries, Series},
         tags::Tag,
+        visibility::Visibility,
     },
     crate::{
         diesel::{
-            pg::expression::dsl::any, BelongingToDsl, ExpressionMethods, PgConnection, QueryDsl,
-            RunQueryDsl,
+            pg::expression::dsl::any, BelongingToDsl, BoolExpressionMethods, ExpressionMethods,
+            PgConnection, QueryDsl, RunQueryDsl,
         },
-        diesel_full_text_search::{plainto_tsquery, TsVectorExtensions},
-        schema::{article_tags, articles, tags},
-        API_URL, DISPLAY_UNPUBLISHED_ARTICLES,
+        diesel_full_text_search::{plainto_tsquery, TsVector, TsVectorExtensions},
+        schema::{article_tags, articles, contents, tags},
+        API_URL,
+    },
+    diesel::{
+        connection::Connection,
+        dsl::{now, sql},
+        NullableExpressionMethods,
     },
     serde::{Deserialize, Serialize},
     std::collections::HashMap,
@@ -47,8 +54,14 @@ pub struct Article {
     pub published: bool,
     pub headline: String,
     pub image: String,
+    pub image_alt: String,
     pub image_credits: Option<String>,
     pub updated: Option<chrono::NaiveDateTime>,
+    // Set through Article::schedule, applied by crate::scheduler.
+    #[serde(default)]
+    pub publish_at: Option<chrono::NaiveDateTime>,
+    #[serde(default)]
+    pub unpublish_at: Option<chrono::NaiveDateTime>,
 }
 
 type ArticleColumns = (
@@ -58,8 +71,11 @@ type ArticleColumns = (
     articles::published,
     articles::headline,
     articles::image,
+    articles::image_alt,
     articles::image_credits,
     articles::updated,
+    articles::publish_at,
+    articles::unpublish_at,
 );
 
 pub const ARTICLE_COLUMNS: ArticleColumns = (
@@ -69,10 +85,16 @@ pub const ARTICLE_COLUMNS: ArticleColumns = (
     articles::published,
     articles::headline,
     articles::image,
+    articles::image_alt,
     articles::image_credits,
     articles::updated,
+    articles::publish_at,
+    articles::unpublish_at,
 );
 
+// Search vector of a content, written as in contents_plain_text_search_index for the index to be used.
+const CONTENT_DOCUMENT: &str = "to_tsvector('english', contents.plain_text)";
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ArticleRepresentation {
     pub id: i32,
@@ -81,10 +103,48 @@ pub struct ArticleRepresentation {
     pub published: bool,
     pub headline: String,
     pub image: String,
+    pub image_alt: String,
     pub image_credits: Option<String>,
     pub updated: Option<chrono::NaiveDateTime>,
+    pub publish_at: Option<chrono::NaiveDateTime>,
+    pub unpublish_at: Option<chrono::NaiveDateTime>,
     pub tags: Vec<Tag>,
     pub chapters: Vec<ChapterRepresentation>,
+    pub references: Vec<ReferenceRepresentation>,
+    // Only loaded for a single article, see Article::get.
+    #[serde(default)]
+    pub series: Option<ArticleSeries>,
+}
+
+// An article without its chapters and references, as linked from another page.
+#[derive(Debug, Serialize)]
+pub struct ArticleSummary {
+    pub id: i32,
+    pub title: String,
+    pub pub_date: chrono::NaiveDateTime,
+    pub updated: Option<chrono::NaiveDateTime>,
+    pub headline: String,
+    pub image: String,
+    pub image_alt: String,
+    pub tags: Vec<Tag>,
+}
+
+// A pending transition, as listed in the editor.
+#[cfg(feature = "editable")]
+#[derive(Debug, Serialize, Queryable)]
+pub struct ScheduledArticle {
+    pub id: i32,
+    pub title: String,
+    pub published: bool,
+    pub publish_at: Option<chrono::NaiveDateTime>,
+    pub unpublish_at: Option<chrono::NaiveDateTime>,
+}
+
+// Articles published and unpublished by one run of the scheduler.
+#[derive(Debug, Default)]
+pub struct ScheduleTransitions {
+    pub published: Vec<i32>,
+    pub unpublished: Vec<i32>,
 }
 
 #[derive(Insertable, Debug, Serialize, Deserialize)]
@@ -94,7 +154,10 @@ pub struct NewArticleHeader<'a> {
     pub headline: &'a str,
     pub published: bool,
     pub image: &'a str,
+    pub image_alt: &'a str,
     pub image_credits: Option<&'a str>,
+    pub publish_at: Option<chrono::NaiveDateTime>,
+    pub unpublish_at: Option<chrono::NaiveDateTime>,
 }
 
 #[derive(Insertable, Debug, Serialize, Deserialize)]
@@ -111,29 +174,48 @@ pub struct NewArticle<'a> {
     pub tags: Vec<NewArticleTag>,
     #[serde(borrow)]
     pub chapters: Vec<NewChapterForm<'a>>,
+    #[serde(borrow)]
+    pub references: Vec<NewReference<'a>>,
 }
 
 impl FromModel<Article> for ArticleRepresentation {
     fn from_model(article: Article, connection: Option<&PgConnection>) -> Self {
+        let mut chapters = article
+            .chapters(connection.unwrap())
+            .expect("Error loading chapters.");
+        let mut references = article
+            .references(connection.unwrap())
+            .expect("Error loading references.");
+        number_citations(&mut chapters, &mut references);
         Self {
             tags: article
                 .tags(connection.unwrap())
                 .expect("Error loading article tags."),
-            chapters: article
-                .chapters(connection.unwrap())
-                .expect("Error loading chapters."),
+            chapters,
+            references,
             id: article.id,
             title: article.title,
             pub_date: article.pub_date,
             published: article.published,
             headline: article.headline,
             image: API_URL.to_owned() + &article.image,
+            image_alt: article.image_alt,
             image_credits: article.image_credits,
             updated: article.updated,
+            publish_at: article.publish_at,
+            unpublish_at: article.unpublish_at,
+            series: None,
         }
     }
 }
 
+impl ArticleRepresentation {
+    // Used as the Last-Modified date of the article.
+    pub fn last_modified(&self) -> &chrono::NaiveDateTime {
+        self.updated.as_ref().unwrap_or(&self.pub_date)
+    }
+}
+
 impl Article {
     fn tags(&self, connection: &PgConnection) -> Result<Vec<Tag>, diesel::result::Error> {
         let tags_ids = ArticleTag::belonging_to(self).select(article_tags::tag_id);
@@ -151,16 +233,29 @@ impl Article {
         Chapter::belonging_to_article(self, connection)
     }
 
+    fn references(
+        &self,
+        connection: &PgConnection,
+    ) -> Result<Vec<ReferenceRepresentation>, diesel::result::Error> {
+        Reference::belonging_to_article(self, connection)
+    }
+
+    // An article hidden from the reader is reported as not found.
     pub fn get(
         id: i32,
+        visibility: Visibility,
         connection: &PgConnection,
     ) -> Result<ArticleRepresentation, diesel::result::Error> {
         let article = articles::table
             .select(ARTICLE_COLUMNS)
-            .find(id)
+            .filter(articles::id.eq(id))
+            .filter(visibility.scope())
             .first::<Self>(connection)?;
 
-        Ok(ArticleRepresentation::from_model(article, Some(connection)))
+        Ok(ArticleRepresentation {
+            series: Series::of_article(id, visibility, connection)?,
+            ..ArticleRepresentation::from_model(article, Some(connection))
+        })
     }
 
     #[cfg(feature = "editable")]
@@ -200,6 +295,16 @@ impl Article {
                 }
             }
 
+            for new_reference in &new_article.references {
+                Reference::add(
+                    &NewReference {
+                        article_id: inserted_article.id,
+                        ..new_reference.clone()
+                    },
+                    connection,
+                )?;
+            }
+
             Ok(inserted_article)
         })?;
 
@@ -228,12 +333,22 @@ impl Article {
         updated_article: &Self,
         connection: &PgConnection,
     ) -> Result<ArticleRepresentation, diesel::result::Error> {
+        // The schedule is only changed by Article::schedule, a stale editor could otherwise set it back after the
+        // scheduler cleared it (None values are skipped by the changeset).
+        let updated_article = Self {
+            publish_at: None,
+            unpublish_at: None,
+            ..updated_article.clone()
+        };
         let article = diesel::update(articles::table.find(id))
-            .set(updated_article)
+            .set(&updated_article)
             .returning(ARTICLE_COLUMNS)
             .get_result::<Self>(connection)?;
 
-        Ok(ArticleRepresentation::from_model(article, Some(connection)))
+        Ok(ArticleRepresentation {
+            series: Series::of_article(id, Visibility::Admin, connection)?,
+            ..ArticleRepresentation::from_model(article, Some(connection))
+        })
     }
 
     #[cfg(feature = "editable")]
@@ -247,22 +362,98 @@ impl Article {
             .returning(ARTICLE_COLUMNS)
             .get_result::<Self>(connection)?;
 
-        Ok(ArticleRepresentation::from_model(article, Some(connection)))
+        Ok(ArticleRepresentation {
+            series: Series::of_article(id, Visibility::Admin, connection)?,
+            ..ArticleRepresentation::from_model(article, Some(connection))
+        })
+    }
+
+    #[cfg(feature = "editable")]
+    pub fn schedule(
+        id: i32,
+        publish_at: Option<chrono::NaiveDateTime>,
+        unpublish_at: Option<chrono::NaiveDateTime>,
+        connection: &PgConnection,
+    ) -> Result<ArticleRepresentation, diesel::result::Error> {
+        let article = diesel::update(articles::table.find(id))
+            .set((
+                articles::publish_at.eq(publish_at),
+                articles::unpublish_at.eq(unpublish_at),
+            ))
+            .returning(ARTICLE_COLUMNS)
+            .get_result::<Self>(connection)?;
+
+        Ok(ArticleRepresentation {
+            series: Series::of_article(id, Visibility::Admin, connection)?,
+            ..ArticleRepresentation::from_model(article, Some(connection))
+        })
+    }
+
+    // Pending transitions, the nearest first.
+    #[cfg(feature = "editable")]
+    pub fn scheduled(
+        connection: &PgConnection,
+    ) -> Result<Vec<ScheduledArticle>, diesel::result::Error> {
+        let mut articles = articles::table
+            .filter(
+                articles::publish_at
+                    .is_not_null()
+                    .or(articles::unpublish_at.is_not_null()),
+            )
+            .select((
+                articles::id,
+                articles::title,
+                articles::published,
+                articles::publish_at,
+                articles::unpublish_at,
+            ))
+            .load::<ScheduledArticle>(connection)?;
+        articles.sort_by_key(|article| article.publish_at.or(article.unpublish_at));
+
+        Ok(articles)
+    }
+
+    // Publish the articles whose publish_at has come (their pub_date becoming the actual publication time) and
+    // unpublish the ones whose unpublish_at has come, clearing the applied times. An article whose both times passed
+    // while the API was down ends up unpublished.
+    pub fn apply_schedule(
+        connection: &PgConnection,
+    ) -> Result<ScheduleTransitions, diesel::result::Error> {
+        connection.transaction(|| {
+            let published =
+                diesel::update(articles::table.filter(articles::publish_at.le(now.nullable())))
+                    .set((
+                        articles::published.eq(true),
+                        articles::pub_date.eq(now),
+                        articles::publish_at.eq(None::<chrono::NaiveDateTime>),
+                    ))
+                    .returning(articles::id)
+                    .get_results::<i32>(connection)?;
+
+            let unpublished =
+                diesel::update(articles::table.filter(articles::unpublish_at.le(now.nullable())))
+                    .set((
+                        articles::published.eq(false),
+                        articles::unpublish_at.eq(None::<chrono::NaiveDateTime>),
+                    ))
+                    .returning(articles::id)
+                    .get_results::<i32>(connection)?;
+
+            Ok(ScheduleTransitions {
+                published,
+                unpublished,
+            })
+        })
     }
 
     pub fn list(
+        visibility: Visibility,
         connection: &PgConnection,
     ) -> Result<HashMap<i32, ArticleRepresentation>, diesel::result::Error> {
-        let articles = if DISPLAY_UNPUBLISHED_ARTICLES == "false" {
-            articles::table
-                .select(ARTICLE_COLUMNS)
-                .filter(articles::published.eq(true))
-                .load::<Self>(connection)?
-        } else {
-            articles::table
-                .select(ARTICLE_COLUMNS)
-                .load::<Self>(connection)?
-        };
+        let articles = articles::table
+            .select(ARTICLE_COLUMNS)
+            .filter(visibility.scope())
+            .load::<Self>(connection)?;
 
         let results: HashMap<i32, ArticleRepresentation> = articles
             .into_iter()
@@ -279,20 +470,21 @@ impl Article {
 
     pub fn search(
         query: &str,
+        visibility: Visibility,
         connection: &PgConnection,
     ) -> Result<HashMap<i32, ArticleRepresentation>, diesel::result::Error> {
-        let articles = if DISPLAY_UNPUBLISHED_ARTICLES == "false" {
-            articles::table
-                .select(ARTICLE_COLUMNS)
-                .filter(articles::published.eq(true))
-                .filter(articles::text_searchable_article.matches(plainto_tsquery(&query)))
-                .load::<Self>(connection)?
-        } else {
-            articles::table
-                .select(ARTICLE_COLUMNS)
-                .filter(articles::text_searchable_article.matches(plainto_tsquery(&query)))
-                .load::<Self>(connection)?
-        };
+        // Articles whose title and headline or the plain text of a content match.
+        let matching_contents = contents::table
+            .select(contents::article_id)
+            .filter(sql::<TsVector>(CONTENT_DOCUMENT).matches(plainto_tsquery(query)));
+        let matches = articles::text_searchable_article
+            .matches(plainto_tsquery(query))
+            .or(articles::id.eq_any(matching_contents));
+        let articles = articles::table
+            .select(ARTICLE_COLUMNS)
+            .filter(visibility.scope())
+            .filter(matches)
+            .load::<Self>(connection)?;
 
         let results: HashMap<i32, ArticleRepresentation> = articles
             .into_iter()
@@ -307,8 +499,50 @@ impl Article {
         Ok(results)
     }
 
+    // Published articles only, keyed by id.
+    pub fn summaries(
+        ids: &[i32],
+        connection: &PgConnection,
+    ) -> Result<HashMap<i32, ArticleSummary>, diesel::result::Error> {
+        let articles = articles::table
+            .select(ARTICLE_COLUMNS)
+            .filter(articles::id.eq_any(ids))
+            .filter(Visibility::Public.scope())
+            .load::<Self>(connection)?;
+        let mut tags_of: HashMap<i32, Vec<Tag>> = HashMap::new();
+        for (article_id, tag) in article_tags::table
+            .inner_join(tags::table)
+            .filter(article_tags::article_id.eq_any(ids))
+            .order_by(article_tags::id)
+            .select((article_tags::article_id, (tags::id, tags::label)))
+            .load::<(i32, Tag)>(connection)?
+        {
+            tags_of.entry(article_id).or_default().push(tag);
+        }
+
+        Ok(articles
+            .into_iter()
+            .map(|article: Self| {
+                (
+                    article.id,
+                    ArticleSummary {
+                        tags: tags_of.remove(&article.id).unwrap_or_default(),
+                        id: article.id,
+                        title: article.title,
+                        pub_date: article.pub_date,
+                        updated: article.updated,
+                        headline: article.headline,
+                        image: API_URL.to_owned() + &article.image,
+                        image_alt: article.image_alt,
+                    },
+                )
+            })
+            .collect())
+    }
+
     pub fn tagged(
         tag: &str,
+        visibility: Visibility,
         connection: &PgConnection,
     ) -> Result<HashMap<i32, ArticleRepresentation>, diesel::result::Error> {
         let tag = tags::table
@@ -321,6 +555,7 @@ impl Article {
         let articles = articles::table
             .select(ARTICLE_COLUMNS)
             .filter(articles::id.eq(any(article_ids)))
+            .filter(visibility.scope())
             .load::<Self>(connection)?;
 
         // TODO - Use a vec
@@ -13,16 +13,27 @@ use {
 
 #[cfg(feature = "editable")]
 use {
-    super::articles::{ArticleRepresentation, ARTICLE_COLUMNS},
+    super::{
+        articles::{ArticleRepresentation, ARTICLE_COLUMNS},
+        visibility::Visibility,
+    },
     crate::{
         interfaces::{Status, TAPIResponse},
-        schema::articles,
+        schema::{articles, contents},
     },
     diesel::{connection::Connection, pg::expression::dsl::any, ExpressionMethods},
 };
 
 #[derive(
-    Debug, Identifiable, Queryable, Associations, Serialize, Deserialize, Clone, AsChangeset,
+    Debug,
+    Identifiable,
+    Queryable,
+    Insertable,
+    Associations,
+    Serialize,
+    Deserialize,
+    Clone,
+    AsChangeset,
 )]
 #[belongs_to(parent = "Article")]
 #[table_name = "chapters"]
@@ -50,6 +61,14 @@ pub struct NewChapter<'a> {
     pub title: &'a str,
 }
 
+// The title is the only column an update changes, see Chapter::move_to for the position and the article.
+#[cfg(feature = "editable")]
+#[derive(AsChangeset, Debug)]
+#[table_name = "chapters"]
+struct ChapterChangeset<'a> {
+    title: &'a str,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct NewChapterForm<'a> {
     #[serde(borrow)]
@@ -78,6 +97,19 @@ impl Chapter {
         Content::belonging_to_chapter(self, connection)
     }
 
+    #[cfg(feature = "editable")]
+    pub fn find(id: i32, connection: &PgConnection) -> Result<Self, diesel::result::Error> {
+        chapters::table.find(id).first::<Self>(connection)
+    }
+
+    #[cfg(feature = "editable")]
+    pub fn count(article_id: i32, connection: &PgConnection) -> Result<i64, diesel::result::Error> {
+        chapters::table
+            .filter(chapters::article_id.eq(article_id))
+            .count()
+            .get_result(connection)
+    }
+
     #[cfg(feature = "editable")]
     pub fn delete(
         chapter_id: i32,
@@ -147,18 +179,69 @@ impl Chapter {
 
     // TODO - Bulk insert
 
+    // Move a chapter (and its contents) to an index of the same or another article, in one transaction. The chapters
+    // after its former position are shifted back and the ones from its new position forward, the (article_id, index)
+    // constraint being deferred until the commit.
     #[cfg(feature = "editable")]
-    pub fn update(
+    pub fn move_to(
         id: i32,
+        article_id: i32,
+        index: i32,
+        connection: &PgConnection,
+    ) -> Result<ArticleRepresentation, diesel::result::Error> {
+        connection.transaction::<_, diesel::result::Error, _>(|| {
+            let chapter = chapters::table.find(id).first::<Chapter>(connection)?;
+            articles::table
+                .find(article_id)
+                .select(articles::id)
+                .first::<i32>(connection)?;
+
+            diesel::update(
+                chapters::table
+                    .filter(chapters::article_id.eq(chapter.article_id))
+                    .filter(chapters::index.gt(chapter.index)),
+            )
+            .set(chapters::index.eq(chapters::index - 1))
+            .execute(connection)?;
+
+            diesel::update(
+                chapters::table
+                    .filter(chapters::article_id.eq(article_id))
+                    .filter(chapters::id.ne(id))
+                    .filter(chapters::index.ge(index)),
+            )
+            .set(chapters::index.eq(chapters::index + 1))
+            .execute(connection)?;
+
+            diesel::update(chapters::table.find(id))
+                .set((
+                    chapters::article_id.eq(article_id),
+                    chapters::index.eq(index),
+                ))
+                .execute(connection)?;
+            diesel::update(contents::table.filter(contents::chapter_id.eq(id)))
+                .set(contents::article_id.eq(article_id))
+                .execute(connection)?;
+
+            Ok(())
+        })?;
+
+        Article::get(article_id, Visibility::Admin, connection)
+    }
+
+    #[cfg(feature = "editable")]
+    pub fn update(
+        chapter: &Chapter,
         updated_chapter: &Chapter,
         connection: &PgConnection,
     ) -> Result<ArticleRepresentation, diesel::result::Error> {
-        let article_id = updated_chapter.article_id;
-        diesel::update(chapters::table.find(id))
-            .set(updated_chapter)
+        diesel::update(chapters::table.find(chapter.id))
+            .set(&ChapterChangeset {
+                title: &updated_chapter.title,
+            })
             .execute(connection)?;
 
-        Article::get(article_id, connection)
+        Article::get(chapter.article_id, Visibility::Admin, connection)
     }
 
     pub fn belonging_to_article(
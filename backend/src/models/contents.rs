@@ -11,17 +11,27 @@ use {
 
 #[cfg(feature = "editable")]
 use {
-    super::articles::ArticleRepresentation,
+    super::{articles::ArticleRepresentation, visibility::Visibility},
     crate::{
-        code::highlight_code,
+        code::{highlight_code, CodeLines},
         interfaces::{Status, TAPIResponse},
+        rich_text::render_content,
         schema::chapters,
+        service::media::measure_image,
     },
     diesel::{connection::Connection, pg::expression::dsl::any, ExpressionMethods},
 };
 
 #[derive(
-    Debug, Identifiable, Queryable, Associations, Serialize, Deserialize, Clone, AsChangeset,
+    Debug,
+    Identifiable,
+    Queryable,
+    Insertable,
+    Associations,
+    Serialize,
+    Deserialize,
+    Clone,
+    AsChangeset,
 )]
 #[belongs_to(parent = "Article")]
 #[belongs_to(parent = "Chapter")]
@@ -36,6 +46,16 @@ pub struct Content {
     pub language: Option<Language>,
     pub highlighted_code: Option<String>,
     pub url: Option<String>,
+    pub caption: Option<String>,
+    pub start_line: Option<i32>,
+    pub emphasized_lines: Option<String>,
+    pub added_lines: Option<String>,
+    pub removed_lines: Option<String>,
+    pub data: Option<serde_json::Value>,
+    pub rendered_html: Option<String>,
+    // Text indexed by the search, computed when the content is saved.
+    #[serde(default)]
+    pub plain_text: String,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -49,6 +69,13 @@ pub struct ContentRepresentation {
     pub language: Option<Language>,
     pub highlighted_code: Option<String>,
     pub url: Option<String>,
+    pub caption: Option<String>,
+    pub start_line: Option<i32>,
+    pub emphasized_lines: Option<String>,
+    pub added_lines: Option<String>,
+    pub removed_lines: Option<String>,
+    pub data: Option<serde_json::Value>,
+    pub rendered_html: Option<String>,
 }
 
 #[derive(Insertable, Debug, Serialize, Deserialize, Clone)]
@@ -62,6 +89,33 @@ pub struct NewContent<'a> {
     pub language: Option<Language>,
     pub highlighted_code: Option<String>,
     pub url: Option<&'a str>,
+    pub caption: Option<&'a str>,
+    pub start_line: Option<i32>,
+    pub emphasized_lines: Option<&'a str>,
+    pub added_lines: Option<&'a str>,
+    pub removed_lines: Option<&'a str>,
+    pub data: Option<serde_json::Value>,
+    pub rendered_html: Option<String>,
+    pub plain_text: String,
+}
+
+// An update keeps the content at its position, see Content::move_to to change it.
+#[cfg(feature = "editable")]
+#[derive(AsChangeset, Debug)]
+#[table_name = "contents"]
+struct ContentChangeset {
+    content_type: ContentType,
+    content: String,
+    language: Option<Language>,
+    highlighted_code: Option<String>,
+    url: Option<String>,
+    caption: Option<String>,
+    start_line: Option<i32>,
+    emphasized_lines: Option<String>,
+    added_lines: Option<String>,
+    removed_lines: Option<String>,
+    data: Option<serde_json::Value>,
+    plain_text: String,
 }
 
 impl FromModel<Content> for ContentRepresentation {
@@ -73,37 +127,92 @@ impl FromModel<Content> for ContentRepresentation {
             index: content.index,
             content: match content.content_type {
                 ContentType::Image => API_URL.to_owned() + &content.content,
+                // External videos have no file.
+                ContentType::Video | ContentType::Audio if !content.content.is_empty() => {
+                    API_URL.to_owned() + &content.content
+                }
                 _ => content.content,
             },
             content_type: content.content_type,
             language: content.language,
             highlighted_code: content.highlighted_code,
             url: content.url,
+            caption: content.caption,
+            start_line: content.start_line,
+            emphasized_lines: content.emphasized_lines,
+            added_lines: content.added_lines,
+            removed_lines: content.removed_lines,
+            data: content.data,
+            rendered_html: content.rendered_html,
         }
     }
 }
 
 impl Content {
+    #[cfg(feature = "editable")]
+    pub fn code_lines(&self) -> CodeLines {
+        CodeLines::new(
+            self.start_line,
+            self.emphasized_lines.as_deref(),
+            self.added_lines.as_deref(),
+            self.removed_lines.as_deref(),
+        )
+    }
+
+    #[cfg(feature = "editable")]
+    pub fn find(id: i32, connection: &PgConnection) -> Result<Self, diesel::result::Error> {
+        contents::table.find(id).first::<Self>(connection)
+    }
+
+    #[cfg(feature = "editable")]
+    pub fn count(chapter_id: i32, connection: &PgConnection) -> Result<i64, diesel::result::Error> {
+        contents::table
+            .filter(contents::chapter_id.eq(chapter_id))
+            .count()
+            .get_result(connection)
+    }
+
     #[cfg(feature = "editable")]
     pub fn update(
-        id: i32,
-        mut content: Content,
+        stored: &Content,
+        content: Content,
         connection: &PgConnection,
     ) -> Result<ArticleRepresentation, diesel::result::Error> {
-        let article_id = content.article_id;
-        if content.content_type == ContentType::Code {
-            let language = content
-                .language
-                .as_ref()
-                .expect("Code content should specify a language!")
-                .to_string();
-            content.highlighted_code = Some(highlight_code(&content.content, &language));
-        }
-        diesel::update(contents::table.find(id))
-            .set(content)
-            .execute(connection)?;
+        let highlighted_code = match (&content.content_type, &content.language) {
+            (ContentType::Code, Some(language)) => Some(highlight_code(
+                &content.content,
+                language,
+                &content.code_lines(),
+            )),
+            _ => content.highlighted_code,
+        };
+        let data = measure_image(&content.content_type, &content.content, &content.data);
+        // Set on its own, the changeset skips None values and would keep a stale rendering.
+        let rendering = render_content(&content.content_type, &content.content);
+        let changeset = ContentChangeset {
+            content_type: content.content_type,
+            content: content.content,
+            language: content.language,
+            highlighted_code,
+            url: content.url,
+            caption: content.caption,
+            start_line: content.start_line,
+            emphasized_lines: content.emphasized_lines,
+            added_lines: content.added_lines,
+            removed_lines: content.removed_lines,
+            data,
+            plain_text: rendering.plain_text,
+        };
+        connection.transaction::<_, diesel::result::Error, _>(|| {
+            diesel::update(contents::table.find(stored.id))
+                .set(changeset)
+                .execute(connection)?;
+            diesel::update(contents::table.find(stored.id))
+                .set(contents::rendered_html.eq(rendering.html))
+                .execute(connection)
+        })?;
 
-        Article::get(article_id, connection)
+        Article::get(stored.article_id, Visibility::Admin, connection)
     }
 
     #[cfg(feature = "editable")]
@@ -138,6 +247,93 @@ impl Content {
 
     // TODO - Bulk insert
 
+    // Move a content to an index of the same or another chapter, in one transaction, see Chapter::move_to.
+    #[cfg(feature = "editable")]
+    pub fn move_to(
+        id: i32,
+        chapter_id: i32,
+        index: i32,
+        connection: &PgConnection,
+    ) -> Result<ArticleRepresentation, diesel::result::Error> {
+        let article_id = connection.transaction::<_, diesel::result::Error, _>(|| {
+            let content = contents::table.find(id).first::<Content>(connection)?;
+            let chapter = chapters::table
+                .find(chapter_id)
+                .first::<Chapter>(connection)?;
+
+            diesel::update(
+                contents::table
+                    .filter(contents::chapter_id.eq(content.chapter_id))
+                    .filter(contents::index.gt(content.index)),
+            )
+            .set(contents::index.eq(contents::index - 1))
+            .execute(connection)?;
+
+            diesel::update(
+                contents::table
+                    .filter(contents::chapter_id.eq(chapter_id))
+                    .filter(contents::id.ne(id))
+                    .filter(contents::index.ge(index)),
+            )
+            .set(contents::index.eq(contents::index + 1))
+            .execute(connection)?;
+
+            diesel::update(contents::table.find(id))
+                .set((
+                    contents::article_id.eq(chapter.article_id),
+                    contents::chapter_id.eq(chapter_id),
+                    contents::index.eq(index),
+                ))
+                .execute(connection)?;
+
+            Ok(chapter.article_id)
+        })?;
+
+        Article::get(article_id, Visibility::Admin, connection)
+    }
+
+    // Highlight every Code content again (after a syntax or theme change) and render every Text and Math content
+    // again, returns the ids of the updated articles.
+    #[cfg(feature = "editable")]
+    pub fn rehighlight_all(connection: &PgConnection) -> Result<Vec<i32>, diesel::result::Error> {
+        connection.transaction::<_, diesel::result::Error, _>(|| {
+            let rendered_contents = contents::table
+                .filter(contents::content_type.eq_any(vec![
+                    ContentType::Code,
+                    ContentType::Text,
+                    ContentType::Math,
+                ]))
+                .load::<Content>(connection)?;
+
+            let mut article_ids = Vec::with_capacity(rendered_contents.len());
+            for content in rendered_contents {
+                if content.content_type == ContentType::Code {
+                    let language = content.language.clone().unwrap_or_default();
+                    diesel::update(contents::table.find(content.id))
+                        .set(contents::highlighted_code.eq(Some(highlight_code(
+                            &content.content,
+                            &language,
+                            &content.code_lines(),
+                        ))))
+                        .execute(connection)?;
+                } else {
+                    let rendering = render_content(&content.content_type, &content.content);
+                    diesel::update(contents::table.find(content.id))
+                        .set((
+                            contents::rendered_html.eq(rendering.html),
+                            contents::plain_text.eq(rendering.plain_text),
+                        ))
+                        .execute(connection)?;
+                }
+                article_ids.push(content.article_id);
+            }
+            article_ids.sort_unstable();
+            article_ids.dedup();
+
+            Ok(article_ids)
+        })
+    }
+
     #[cfg(feature = "editable")]
     pub fn delete(
         id: i32,
@@ -0,0 +1,333 @@
+use {
+    crate::{
+        diesel::{BoolExpressionMethods, ExpressionMethods, PgConnection, QueryDsl, RunQueryDsl},
+        errors::media_error::MediaError,
+        interfaces::{Status, TAPIResponse},
+        schema::{articles, contents, media, project_images, projects},
+        service::media::{to_file_path, ScannedFile},
+        types::{content_type::ContentType, player::Player},
+        API_URL,
+    },
+    diesel::{
+        connection::Connection, dsl::now, pg::expression::dsl::any, NullableExpressionMethods,
+    },
+    serde::{Deserialize, Serialize},
+    std::{collections::HashMap, fs, io},
+};
+
+#[derive(Identifiable, Debug, Serialize, Deserialize, Queryable, Clone)]
+#[table_name = "media"]
+pub struct Media {
+    pub id: i32,
+    pub path: String,
+    pub size: i64,
+    pub mime_type: String,
+    pub checksum: String,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub created: chrono::NaiveDateTime,
+    pub updated: Option<chrono::NaiveDateTime>,
+}
+
+#[derive(Insertable, AsChangeset, Debug)]
+#[table_name = "media"]
+pub struct NewMedia<'a> {
+    pub path: &'a str,
+    pub size: i64,
+    pub mime_type: &'a str,
+    pub checksum: &'a str,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub enum MediaUsageKind {
+    ArticleImage,
+    ContentImage,
+    // The file, poster or a text track of a Video or an Audio content.
+    ContentPlayer,
+    ProjectImage,
+    ProjectGallery,
+}
+
+// Where an asset is used. `id` is the id of the referencing row, `article_id`/`project_id` the entity it belongs to.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MediaUsage {
+    pub kind: MediaUsageKind,
+    pub id: i32,
+    pub article_id: Option<i32>,
+    pub project_id: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MediaRepresentation {
+    pub id: i32,
+    pub path: String,
+    pub url: String,
+    pub size: i64,
+    pub mime_type: String,
+    pub checksum: String,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub created: chrono::NaiveDateTime,
+    pub updated: Option<chrono::NaiveDateTime>,
+    pub usages: Vec<MediaUsage>,
+    pub orphan: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ScanReport {
+    pub registered: usize,
+    pub removed: usize,
+    pub orphans: usize,
+}
+
+impl MediaRepresentation {
+    fn new(media: Media, usages: Vec<MediaUsage>) -> Self {
+        Self {
+            url: API_URL.to_owned() + &media.path,
+            orphan: usages.is_empty(),
+            usages,
+            id: media.id,
+            path: media.path,
+            size: media.size,
+            mime_type: media.mime_type,
+            checksum: media.checksum,
+            width: media.width,
+            height: media.height,
+            created: media.created,
+            updated: media.updated,
+        }
+    }
+}
+
+// Files played by a Video or an Audio content: the content itself when self-hosted, its poster and its tracks.
+fn player_paths(content: &str, data: &Option<serde_json::Value>) -> Vec<String> {
+    let player = Player::from_data(data).unwrap_or_default();
+    std::iter::once(content.to_owned())
+        .chain(player.poster)
+        .chain(player.tracks.into_iter().map(|track| track.src))
+        .filter(|path| to_file_path(path).is_some())
+        .collect()
+}
+
+impl Media {
+    /* Collect every media path referenced in the database, along with the rows referencing it.
+     * Paths are stored as free text ("/media/images/..."), so this is the only way to know whether an asset is still in use. */
+    pub fn references(
+        connection: &PgConnection,
+    ) -> Result<HashMap<String, Vec<MediaUsage>>, diesel::result::Error> {
+        let mut references: HashMap<String, Vec<MediaUsage>> = HashMap::new();
+
+        let article_images = articles::table
+            .select((articles::id, articles::image))
+            .load::<(i32, String)>(connection)?;
+        for (id, image) in article_images {
+            references.entry(image).or_default().push(MediaUsage {
+                kind: MediaUsageKind::ArticleImage,
+                id,
+                article_id: Some(id),
+                project_id: None,
+            });
+        }
+
+        let content_images = contents::table
+            .select((contents::id, contents::article_id, contents::content))
+            .filter(contents::content_type.eq(ContentType::Image))
+            .load::<(i32, i32, String)>(connection)?;
+        for (id, article_id, image) in content_images {
+            references.entry(image).or_default().push(MediaUsage {
+                kind: MediaUsageKind::ContentImage,
+                id,
+                article_id: Some(article_id),
+                project_id: None,
+            });
+        }
+
+        let content_players = contents::table
+            .select((
+                contents::id,
+                contents::article_id,
+                contents::content,
+                contents::data,
+            ))
+            .filter(
+                contents::content_type
+                    .eq(ContentType::Video)
+                    .or(contents::content_type.eq(ContentType::Audio)),
+            )
+            .load::<(i32, i32, String, Option<serde_json::Value>)>(connection)?;
+        for (id, article_id, content, data) in content_players {
+            for path in player_paths(&content, &data) {
+                references.entry(path).or_default().push(MediaUsage {
+                    kind: MediaUsageKind::ContentPlayer,
+                    id,
+                    article_id: Some(article_id),
+                    project_id: None,
+                });
+            }
+        }
+
+        let project_images = projects::table
+            .select((projects::id, projects::image))
+            .load::<(i32, String)>(connection)?;
+        for (id, image) in project_images {
+            references.entry(image).or_default().push(MediaUsage {
+                kind: MediaUsageKind::ProjectImage,
+                id,
+                article_id: None,
+                project_id: Some(id),
+            });
+        }
+
+        let gallery_images = project_images::table
+            .select((
+                project_images::id,
+                project_images::project_id,
+                project_images::image,
+            ))
+            .load::<(i32, i32, String)>(connection)?;
+        for (id, project_id, image) in gallery_images {
+            references.entry(image).or_default().push(MediaUsage {
+                kind: MediaUsageKind::ProjectGallery,
+                id,
+                article_id: None,
+                project_id: Some(project_id),
+            });
+        }
+
+        Ok(references)
+    }
+
+    pub fn list(
+        orphans_only: bool,
+        connection: &PgConnection,
+    ) -> Result<Vec<MediaRepresentation>, diesel::result::Error> {
+        let mut references = Self::references(connection)?;
+        let media = media::table
+            .order_by(media::path)
+            .load::<Self>(connection)?;
+
+        Ok(media
+            .into_iter()
+            .map(|media| {
+                let usages = references.remove(&media.path).unwrap_or_default();
+                MediaRepresentation::new(media, usages)
+            })
+            .filter(|media| !orphans_only || media.orphan)
+            .collect())
+    }
+
+    pub fn get(
+        id: i32,
+        connection: &PgConnection,
+    ) -> Result<MediaRepresentation, diesel::result::Error> {
+        let media = media::table.find(id).first::<Self>(connection)?;
+        let usages = Self::references(connection)?
+            .remove(&media.path)
+            .unwrap_or_default();
+
+        Ok(MediaRepresentation::new(media, usages))
+    }
+
+    // Register every scanned file, update the ones whose content changed and forget the ones that no longer exist on disk.
+    pub fn register(
+        scanned_files: &[ScannedFile],
+        connection: &PgConnection,
+    ) -> Result<ScanReport, diesel::result::Error> {
+        let removed = connection.transaction::<usize, diesel::result::Error, _>(|| {
+            for file in scanned_files {
+                let new_media = NewMedia {
+                    path: &file.path,
+                    size: file.size,
+                    mime_type: &file.mime_type,
+                    checksum: &file.checksum,
+                    width: file.width,
+                    height: file.height,
+                };
+                diesel::insert_into(media::table)
+                    .values(&new_media)
+                    .on_conflict(media::path)
+                    .do_update()
+                    .set((&new_media, media::updated.eq(now.nullable())))
+                    .execute(connection)?;
+            }
+
+            let paths: Vec<&str> = scanned_files.iter().map(|f| f.path.as_str()).collect();
+            diesel::delete(media::table.filter(diesel::dsl::not(media::path.eq(any(paths)))))
+                .execute(connection)
+        })?;
+
+        let orphans = Self::list(true, connection)?.len();
+
+        Ok(ScanReport {
+            registered: scanned_files.len(),
+            removed,
+            orphans,
+        })
+    }
+
+    /* Delete an asset from the library and from the disk, unless a row still references it. The check and the deletion
+     * run in a serializable transaction, so that a reference added meanwhile aborts it, and the file is only removed
+     * once the row is gone for good. */
+    pub fn delete(id: i32, connection: &PgConnection) -> Result<TAPIResponse<()>, MediaError> {
+        let path = connection
+            .build_transaction()
+            .serializable()
+            .run::<_, MediaError, _>(|| {
+                let media = Self::get(id, connection)?;
+                if !media.orphan {
+                    return Err(MediaError::InUse);
+                }
+                diesel::delete(media::table.find(id)).execute(connection)?;
+                Ok(media.path)
+            })?;
+
+        if let Some(file) = to_file_path(&path) {
+            match fs::remove_file(file) {
+                Err(error) if error.kind() != io::ErrorKind::NotFound => return Err(error.into()),
+                _ => (),
+            }
+        }
+
+        Ok(TAPIResponse {
+            status: Status::Success,
+            content: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::player_paths, serde_json::json};
+
+    #[test]
+    fn player_paths_include_the_poster_and_the_tracks() {
+        let data = json!({
+            "poster": "/media/images/talk.webp",
+            "tracks": [
+                { "src": "/media/videos/talk.en.vtt", "kind": "subtitles", "srclang": "en", "label": "English" },
+                { "src": "/media/videos/talk.fr.vtt", "kind": "captions", "srclang": "fr", "label": "Français" }
+            ]
+        });
+        assert_eq!(
+            player_paths("/media/videos/talk.mp4", &Some(data)),
+            [
+                "/media/videos/talk.mp4",
+                "/media/images/talk.webp",
+                "/media/videos/talk.en.vtt",
+                "/media/videos/talk.fr.vtt",
+            ]
+        );
+    }
+
+    #[test]
+    fn external_players_only_reference_their_poster() {
+        let data = json!({ "poster": "/media/images/talk.webp" });
+        assert_eq!(
+            player_paths("https://www.youtube.com/watch?v=dQw4w9WgXcQ", &Some(data)),
+            ["/media/images/talk.webp"]
+        );
+        assert!(player_paths("https://vimeo.com/76979871", &None).is_empty());
+    }
+}
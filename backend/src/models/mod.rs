@@ -3,5 +3,12 @@ pub mod chapters;
 pub mod contents;
 pub mod from_model;
 pub mod logs;
+#[cfg(feature = "editable")]
+pub mod media;
 pub mod projects;
+pub mod references;
+#[cfg(feature = "editable")]
+pub mod revisions;
+pub mod series;
 pub mod tags;
+pub mod visibility;
@@ -68,7 +68,7 @@ pub struct ProjectRepresentation {
     pub download_link: Option<String>,
     pub git: Option<String>,
     pub tags: Vec<Tag>,
-    pub gallery: Vec<String>,
+    pub gallery: Vec<ProjectImageRepresentation>,
     pub category: ProjectCategory,
 }
 
@@ -80,6 +80,17 @@ pub struct ProjectImage {
     pub id: i32,
     pub project_id: i32,
     pub image: String,
+    pub alt: String,
+    pub caption: Option<String>,
+    pub credits: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectImageRepresentation {
+    pub image: String,
+    pub alt: String,
+    pub caption: Option<String>,
+    pub credits: Option<String>,
 }
 
 #[derive(
@@ -125,14 +136,22 @@ impl Project {
         Ok(tags)
     }
 
-    fn gallery(&self, connection: &PgConnection) -> Result<Vec<String>, diesel::result::Error> {
+    fn gallery(
+        &self,
+        connection: &PgConnection,
+    ) -> Result<Vec<ProjectImageRepresentation>, diesel::result::Error> {
         let images = ProjectImage::belonging_to(self)
-            .select(project_images::image)
-            .load::<String>(connection)?;
+            .order_by(project_images::id)
+            .load::<ProjectImage>(connection)?;
 
         Ok(images
-            .iter()
-            .map(|image| API_URL.to_owned() + image)
+            .into_iter()
+            .map(|image| ProjectImageRepresentation {
+                image: API_URL.to_owned() + &image.image,
+                alt: image.alt,
+                caption: image.caption,
+                credits: image.credits,
+            })
             .collect())
     }
 
@@ -188,6 +207,26 @@ impl Project {
         Ok(results)
     }
 
+    pub fn with_ids(
+        ids: &[i32],
+        connection: &PgConnection,
+    ) -> Result<HashMap<i32, ProjectRepresentation>, diesel::result::Error> {
+        let projects = projects::table
+            .select(PROJECT_COLUMNS)
+            .filter(projects::id.eq_any(ids))
+            .load::<Self>(connection)?;
+
+        Ok(projects
+            .into_iter()
+            .map(|project: Self| {
+                (
+                    project.id,
+                    ProjectRepresentation::from_model(project, Some(connection)),
+                )
+            })
+            .collect())
+    }
+
     pub fn tagged(
         label: &str,
         connection: &PgConnection,
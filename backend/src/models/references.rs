@@ -0,0 +1,271 @@
+use {
+    super::{articles::Article, chapters::ChapterRepresentation, from_model::FromModel},
+    crate::{
+        diesel::{BelongingToDsl, PgConnection, QueryDsl, RunQueryDsl},
+        schema::article_references,
+    },
+    serde::{Deserialize, Serialize},
+    std::fmt::Write,
+};
+
+#[cfg(feature = "editable")]
+use {
+    super::{
+        articles::{ArticleRepresentation, ARTICLE_COLUMNS},
+        visibility::Visibility,
+    },
+    crate::{
+        interfaces::{InputReferenceUpdate, Status, TAPIResponse},
+        schema::articles,
+    },
+    diesel::{
+        connection::Connection, pg::expression::dsl::any, ExpressionMethods, OptionalExtension,
+    },
+};
+
+// A [^key] citation is rendered between these markers (see crate::rich_text) and numbered when the article is read,
+// so that reordering the references does not require rendering the contents again.
+pub const CITATION_START: char = '\u{E002}';
+pub const CITATION_END: char = '\u{E003}';
+
+// Keys are used in the markers and in HTML ids.
+pub fn is_key_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '-' || c == '_'
+}
+
+#[derive(
+    Debug,
+    Identifiable,
+    Queryable,
+    Insertable,
+    Associations,
+    Serialize,
+    Deserialize,
+    Clone,
+    AsChangeset,
+)]
+#[belongs_to(parent = "Article")]
+#[table_name = "article_references"]
+pub struct Reference {
+    pub id: i32,
+    pub article_id: i32,
+    pub index: i32,
+    pub key: String,
+    pub title: String,
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReferenceRepresentation {
+    pub id: i32,
+    pub article_id: i32,
+    pub index: i32,
+    pub key: String,
+    pub title: String,
+    pub url: Option<String>,
+    // Number of [^key] markers in the article, each one has a back-link.
+    pub citations: i32,
+}
+
+#[derive(Insertable, Debug, Serialize, Deserialize, Clone)]
+#[table_name = "article_references"]
+pub struct NewReference<'a> {
+    pub article_id: i32,
+    pub index: i32,
+    pub key: &'a str,
+    pub title: &'a str,
+    pub url: Option<&'a str>,
+}
+
+// A None url removes the link of the reference.
+#[cfg(feature = "editable")]
+#[derive(AsChangeset, Debug)]
+#[table_name = "article_references"]
+#[changeset_options(treat_none_as_null = "true")]
+struct ReferenceChangeset<'a> {
+    key: &'a str,
+    title: &'a str,
+    url: Option<&'a str>,
+}
+
+impl FromModel<Reference> for ReferenceRepresentation {
+    fn from_model(reference: Reference, _: Option<&PgConnection>) -> Self {
+        Self {
+            id: reference.id,
+            article_id: reference.article_id,
+            index: reference.index,
+            key: reference.key,
+            title: reference.title,
+            url: reference.url,
+            citations: 0,
+        }
+    }
+}
+
+// Replace the citation markers by the number of their reference, linked to it, and count the citations of every
+// reference.
+fn number_citations_of(html: &str, references: &mut [ReferenceRepresentation]) -> String {
+    let mut numbered = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find(CITATION_START) {
+        numbered.push_str(&rest[..start]);
+        let marker = &rest[start + CITATION_START.len_utf8()..];
+        let end = match marker.find(CITATION_END) {
+            Some(end) => end,
+            None => break,
+        };
+        let key = &marker[..end];
+        match references.iter_mut().find(|reference| reference.key == key) {
+            Some(reference) => {
+                reference.citations += 1;
+                let _ = write!(
+                    numbered,
+                    "<sup class=\"citation\" id=\"citation-{}-{}\"><a href=\"#reference-{}\">[{}]</a></sup>",
+                    key,
+                    reference.citations,
+                    key,
+                    reference.index + 1
+                );
+            }
+            None => numbered.push_str("<sup class=\"citation citation-missing\">[?]</sup>"),
+        }
+        rest = &marker[end + CITATION_END.len_utf8()..];
+    }
+    numbered.push_str(rest);
+
+    numbered
+}
+
+// Contents are numbered in reading order, which is the order of the back-links.
+pub fn number_citations(
+    chapters: &mut [ChapterRepresentation],
+    references: &mut [ReferenceRepresentation],
+) {
+    for content in chapters
+        .iter_mut()
+        .flat_map(|chapter| chapter.contents.iter_mut())
+    {
+        if let Some(html) = content.rendered_html.as_mut() {
+            if html.contains(CITATION_START) {
+                *html = number_citations_of(html, references);
+            }
+        }
+    }
+}
+
+impl Reference {
+    #[cfg(feature = "editable")]
+    pub fn find(id: i32, connection: &PgConnection) -> Result<Self, diesel::result::Error> {
+        article_references::table.find(id).first::<Self>(connection)
+    }
+
+    #[cfg(feature = "editable")]
+    pub fn find_by_key(
+        article_id: i32,
+        key: &str,
+        connection: &PgConnection,
+    ) -> Result<Option<Self>, diesel::result::Error> {
+        article_references::table
+            .filter(article_references::article_id.eq(article_id))
+            .filter(article_references::key.eq(key))
+            .first::<Self>(connection)
+            .optional()
+    }
+
+    #[cfg(feature = "editable")]
+    pub fn count(article_id: i32, connection: &PgConnection) -> Result<i64, diesel::result::Error> {
+        article_references::table
+            .filter(article_references::article_id.eq(article_id))
+            .count()
+            .get_result(connection)
+    }
+
+    #[cfg(feature = "editable")]
+    pub fn add(
+        new_reference: &NewReference,
+        connection: &PgConnection,
+    ) -> Result<i32, diesel::result::Error> {
+        connection.transaction::<i32, diesel::result::Error, _>(|| {
+            let article = articles::table
+                .find(&new_reference.article_id)
+                .select(ARTICLE_COLUMNS)
+                .first::<Article>(connection)?;
+
+            let references_ids = Reference::belonging_to(&article)
+                .select(article_references::id)
+                .load::<i32>(connection)?;
+
+            diesel::update(
+                article_references::table.filter(article_references::id.eq(any(references_ids))),
+            )
+            .filter(article_references::index.ge(&new_reference.index))
+            .set(article_references::index.eq(article_references::index + 1))
+            .execute(connection)?;
+
+            diesel::insert_into(article_references::table)
+                .values(new_reference)
+                .returning(article_references::id)
+                .get_result(connection)
+        })
+    }
+
+    #[cfg(feature = "editable")]
+    pub fn update(
+        reference: &Reference,
+        input: &InputReferenceUpdate,
+        connection: &PgConnection,
+    ) -> Result<ArticleRepresentation, diesel::result::Error> {
+        diesel::update(article_references::table.find(reference.id))
+            .set(&ReferenceChangeset {
+                key: &input.key,
+                title: &input.title,
+                url: input.url.as_deref().filter(|url| !url.trim().is_empty()),
+            })
+            .execute(connection)?;
+
+        Article::get(reference.article_id, Visibility::Admin, connection)
+    }
+
+    #[cfg(feature = "editable")]
+    pub fn delete(
+        id: i32,
+        connection: &PgConnection,
+    ) -> Result<TAPIResponse<()>, diesel::result::Error> {
+        connection.transaction::<(), diesel::result::Error, _>(|| {
+            let reference = article_references::table
+                .find(id)
+                .first::<Reference>(connection)?;
+
+            diesel::update(
+                article_references::table
+                    .filter(article_references::article_id.eq(reference.article_id)),
+            )
+            .filter(article_references::index.gt(reference.index))
+            .set(article_references::index.eq(article_references::index - 1))
+            .execute(connection)?;
+
+            diesel::delete(article_references::table.find(id)).execute(connection)?;
+
+            Ok(())
+        })?;
+
+        Ok(TAPIResponse {
+            status: Status::Success,
+            content: None,
+        })
+    }
+
+    pub fn belonging_to_article(
+        article: &Article,
+        connection: &PgConnection,
+    ) -> Result<Vec<ReferenceRepresentation>, diesel::result::Error> {
+        let references = Self::belonging_to(article)
+            .order_by(article_references::index)
+            .load::<Self>(connection)?;
+
+        Ok(references
+            .into_iter()
+            .map(|reference| ReferenceRepresentation::from_model(reference, None))
+            .collect())
+    }
+}
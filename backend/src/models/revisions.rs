@@ -0,0 +1,307 @@
+/*
+ * Every save of an article records a revision, a full snapshot of the rows of the article (header, tags, chapters,
+ * contents and references) taken in the same transaction. Restoring a revision writes the snapshot back, which is
+ * itself recorded as a new revision so that a restore can be undone.
+ */
+use {
+    super::{
+        articles::{Article, ArticleRepresentation, NewArticleTag, ARTICLE_COLUMNS},
+        chapters::Chapter,
+        contents::Content,
+        references::Reference,
+        tags::Tag,
+        visibility::Visibility,
+    },
+    crate::{
+        diesel::{
+            connection::Connection,
+            sql_types::{BigInt, Text},
+            ExpressionMethods, OptionalExtension, PgConnection, QueryDsl, RunQueryDsl,
+        },
+        schema::{
+            article_references, article_revisions, article_tags, articles, chapters, contents, tags,
+        },
+    },
+    serde::{Deserialize, Serialize},
+    std::collections::HashMap,
+};
+
+// Author of the revisions recorded for the articles saved before the history existed.
+const BASELINE_AUTHOR: &str = "baseline";
+
+sql_function!(fn nextval(sequence: Text) -> BigInt);
+
+#[derive(Identifiable, Debug, Queryable)]
+#[table_name = "article_revisions"]
+pub struct Revision {
+    pub id: i32,
+    pub article_id: i32,
+    pub author: String,
+    pub created: chrono::NaiveDateTime,
+    pub snapshot: serde_json::Value,
+}
+
+// A revision without its snapshot, as listed in the history of an article.
+#[derive(Debug, Serialize, Queryable)]
+pub struct RevisionSummary {
+    pub id: i32,
+    pub article_id: i32,
+    pub author: String,
+    pub created: chrono::NaiveDateTime,
+}
+
+#[derive(Insertable, Debug)]
+#[table_name = "article_revisions"]
+struct NewRevision<'a> {
+    article_id: i32,
+    author: &'a str,
+    snapshot: serde_json::Value,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ArticleSnapshot {
+    pub article: Article,
+    pub tags: Vec<Tag>,
+    pub chapters: Vec<Chapter>,
+    pub contents: Vec<Content>,
+    pub references: Vec<Reference>,
+}
+
+impl ArticleSnapshot {
+    fn load(article_id: i32, connection: &PgConnection) -> Result<Self, diesel::result::Error> {
+        Ok(Self {
+            article: articles::table
+                .find(article_id)
+                .select(ARTICLE_COLUMNS)
+                .first::<Article>(connection)?,
+            tags: article_tags::table
+                .inner_join(tags::table)
+                .filter(article_tags::article_id.eq(article_id))
+                .order_by(article_tags::id)
+                .select((tags::id, tags::label))
+                .load::<Tag>(connection)?,
+            chapters: chapters::table
+                .filter(chapters::article_id.eq(article_id))
+                .order_by(chapters::index)
+                .load::<Chapter>(connection)?,
+            contents: contents::table
+                .filter(contents::article_id.eq(article_id))
+                .order_by((contents::chapter_id, contents::index))
+                .load::<Content>(connection)?,
+            references: article_references::table
+                .filter(article_references::article_id.eq(article_id))
+                .order_by(article_references::index)
+                .load::<Reference>(connection)?,
+        })
+    }
+}
+
+// A restored chapter, content or reference keeps its id, unless another article uses it since the revision (e.g. a
+// chapter moved away).
+fn restored_id(
+    id: i32,
+    taken: bool,
+    sequence: &str,
+    connection: &PgConnection,
+) -> Result<i32, diesel::result::Error> {
+    if taken {
+        let id = diesel::select(nextval(sequence)).get_result::<i64>(connection)?;
+        Ok(id as i32)
+    } else {
+        Ok(id)
+    }
+}
+
+impl Revision {
+    pub fn snapshot(&self) -> Result<ArticleSnapshot, diesel::result::Error> {
+        serde_json::from_value(self.snapshot.clone())
+            .map_err(|error| diesel::result::Error::DeserializationError(Box::new(error)))
+    }
+
+    pub fn find(
+        article_id: i32,
+        id: i32,
+        connection: &PgConnection,
+    ) -> Result<Self, diesel::result::Error> {
+        article_revisions::table
+            .filter(article_revisions::id.eq(id))
+            .filter(article_revisions::article_id.eq(article_id))
+            .first::<Self>(connection)
+    }
+
+    // Newest first.
+    pub fn list(
+        article_id: i32,
+        connection: &PgConnection,
+    ) -> Result<Vec<RevisionSummary>, diesel::result::Error> {
+        article_revisions::table
+            .filter(article_revisions::article_id.eq(article_id))
+            .order_by((
+                article_revisions::created.desc(),
+                article_revisions::id.desc(),
+            ))
+            .select((
+                article_revisions::id,
+                article_revisions::article_id,
+                article_revisions::author,
+                article_revisions::created,
+            ))
+            .load::<RevisionSummary>(connection)
+    }
+
+    pub fn add(
+        article_id: i32,
+        author: &str,
+        connection: &PgConnection,
+    ) -> Result<(), diesel::result::Error> {
+        let snapshot = serde_json::to_value(ArticleSnapshot::load(article_id, connection)?)
+            .map_err(|error| diesel::result::Error::SerializationError(Box::new(error)))?;
+        diesel::insert_into(article_revisions::table)
+            .values(&NewRevision {
+                article_id,
+                author,
+                snapshot,
+            })
+            .execute(connection)?;
+
+        Ok(())
+    }
+
+    // Apply a change to an article and record its snapshot once done, in one transaction. The state of an article
+    // saved before the history existed is recorded first, so that this change can be reverted too.
+    pub fn record<T, E, F>(
+        article_id: i32,
+        author: &str,
+        connection: &PgConnection,
+        change: F,
+    ) -> Result<T, E>
+    where
+        E: From<diesel::result::Error>,
+        F: FnOnce() -> Result<T, E>,
+    {
+        connection.transaction::<T, E, _>(|| {
+            let has_revisions = article_revisions::table
+                .filter(article_revisions::article_id.eq(article_id))
+                .select(article_revisions::id)
+                .first::<i32>(connection)
+                .optional()?
+                .is_some();
+            if !has_revisions {
+                Self::add(article_id, BASELINE_AUTHOR, connection)?;
+            }
+
+            let result = change()?;
+            Self::add(article_id, author, connection)?;
+            Ok(result)
+        })
+    }
+
+    // Replace the header, tags, chapters, contents and references of the article by the ones of a revision. Tags
+    // deleted since the revision are left out. The publication state is kept, it is only changed by publishing or
+    // scheduling the article.
+    pub fn restore(
+        article_id: i32,
+        id: i32,
+        author: &str,
+        connection: &PgConnection,
+    ) -> Result<ArticleRepresentation, diesel::result::Error> {
+        let snapshot = Self::find(article_id, id, connection)?.snapshot()?;
+        Self::record::<_, diesel::result::Error, _>(article_id, author, connection, || {
+            let article = &snapshot.article;
+            diesel::update(articles::table.find(article_id))
+                .set((
+                    articles::title.eq(&article.title),
+                    articles::headline.eq(&article.headline),
+                    articles::image.eq(&article.image),
+                    articles::image_alt.eq(&article.image_alt),
+                    articles::image_credits.eq(&article.image_credits),
+                ))
+                .execute(connection)?;
+
+            diesel::delete(contents::table.filter(contents::article_id.eq(article_id)))
+                .execute(connection)?;
+            diesel::delete(chapters::table.filter(chapters::article_id.eq(article_id)))
+                .execute(connection)?;
+            diesel::delete(
+                article_references::table.filter(article_references::article_id.eq(article_id)),
+            )
+            .execute(connection)?;
+            diesel::delete(article_tags::table.filter(article_tags::article_id.eq(article_id)))
+                .execute(connection)?;
+
+            let existing_tags = tags::table
+                .filter(tags::id.eq_any(snapshot.tags.iter().map(|tag| tag.id).collect::<Vec<_>>()))
+                .select(tags::id)
+                .load::<i32>(connection)?;
+            diesel::insert_into(article_tags::table)
+                .values(
+                    existing_tags
+                        .into_iter()
+                        .map(|tag_id| NewArticleTag { article_id, tag_id })
+                        .collect::<Vec<_>>(),
+                )
+                .execute(connection)?;
+
+            let mut chapter_ids = HashMap::with_capacity(snapshot.chapters.len());
+            for chapter in &snapshot.chapters {
+                let taken = chapters::table
+                    .find(chapter.id)
+                    .select(chapters::id)
+                    .first::<i32>(connection)
+                    .optional()?
+                    .is_some();
+                let restored = Chapter {
+                    id: restored_id(chapter.id, taken, "chapters_id_seq", connection)?,
+                    article_id,
+                    ..chapter.clone()
+                };
+                diesel::insert_into(chapters::table)
+                    .values(&restored)
+                    .execute(connection)?;
+                chapter_ids.insert(chapter.id, restored.id);
+            }
+
+            for content in &snapshot.contents {
+                let taken = contents::table
+                    .find(content.id)
+                    .select(contents::id)
+                    .first::<i32>(connection)
+                    .optional()?
+                    .is_some();
+                let restored = Content {
+                    id: restored_id(content.id, taken, "contents_id_seq", connection)?,
+                    article_id,
+                    chapter_id: chapter_ids
+                        .get(&content.chapter_id)
+                        .copied()
+                        .unwrap_or(content.chapter_id),
+                    ..content.clone()
+                };
+                diesel::insert_into(contents::table)
+                    .values(&restored)
+                    .execute(connection)?;
+            }
+
+            for reference in &snapshot.references {
+                let taken = article_references::table
+                    .find(reference.id)
+                    .select(article_references::id)
+                    .first::<i32>(connection)
+                    .optional()?
+                    .is_some();
+                let restored = Reference {
+                    id: restored_id(reference.id, taken, "article_references_id_seq", connection)?,
+                    article_id,
+                    ..reference.clone()
+                };
+                diesel::insert_into(article_references::table)
+                    .values(&restored)
+                    .execute(connection)?;
+            }
+
+            Ok(())
+        })?;
+
+        Article::get(article_id, Visibility::Admin, connection)
+    }
+}
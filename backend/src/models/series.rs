@@ -0,0 +1,283 @@
+/*
+ * Series of articles: a title, a description and ordered parts. An article belongs to one series at most, its
+ * representation carries its position in the series and links to the previous and next parts. Parts hidden from the
+ * reader (see Visibility) are left out, as if they were not part of the series.
+ */
+use {
+    super::visibility::Visibility,
+    crate::{
+        diesel::{ExpressionMethods, OptionalExtension, PgConnection, QueryDsl, RunQueryDsl},
+        schema::{articles, series, series_articles},
+    },
+    serde::{Deserialize, Serialize},
+};
+
+#[cfg(feature = "editable")]
+use {
+    crate::interfaces::InputSeries,
+    diesel::{connection::Connection, BoolExpressionMethods},
+};
+
+#[derive(Identifiable, Debug, Serialize, Deserialize, Queryable, Clone)]
+#[table_name = "series"]
+pub struct Series {
+    pub id: i32,
+    pub title: String,
+    pub description: String,
+}
+
+#[cfg(feature = "editable")]
+#[derive(Insertable, AsChangeset, Debug)]
+#[table_name = "series"]
+struct NewSeries<'a> {
+    title: &'a str,
+    description: &'a str,
+}
+
+#[cfg(feature = "editable")]
+#[derive(Insertable, Debug)]
+#[table_name = "series_articles"]
+struct NewSeriesArticle {
+    series_id: i32,
+    article_id: i32,
+    index: i32,
+}
+
+// An article of a series, as listed on the page of the series or linked from another part.
+#[derive(Debug, Serialize, Deserialize, Queryable, Clone)]
+pub struct SeriesPart {
+    pub id: i32,
+    pub title: String,
+    pub headline: String,
+    pub pub_date: chrono::NaiveDateTime,
+    pub published: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SeriesRepresentation {
+    pub id: i32,
+    pub title: String,
+    pub description: String,
+    pub parts: Vec<SeriesPart>,
+}
+
+// Position of an article in its series, counted from 1.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ArticleSeries {
+    pub id: i32,
+    pub title: String,
+    pub part: i32,
+    pub parts: i32,
+    pub previous: Option<SeriesPart>,
+    pub next: Option<SeriesPart>,
+}
+
+impl Series {
+    fn parts(
+        id: i32,
+        visibility: Visibility,
+        connection: &PgConnection,
+    ) -> Result<Vec<SeriesPart>, diesel::result::Error> {
+        let article_ids = series_articles::table
+            .filter(series_articles::series_id.eq(id))
+            .order_by((series_articles::index, series_articles::id))
+            .select(series_articles::article_id)
+            .load::<i32>(connection)?;
+        let mut parts = articles::table
+            .filter(articles::id.eq_any(&article_ids))
+            .filter(visibility.scope())
+            .select((
+                articles::id,
+                articles::title,
+                articles::headline,
+                articles::pub_date,
+                articles::published,
+            ))
+            .load::<SeriesPart>(connection)?;
+        parts.sort_by_key(|part| article_ids.iter().position(|id| *id == part.id));
+
+        Ok(parts)
+    }
+
+    // Series without a part visible to the reader are left out, the editor lists them all to fill the new ones.
+    pub fn list(
+        visibility: Visibility,
+        connection: &PgConnection,
+    ) -> Result<Vec<Self>, diesel::result::Error> {
+        let query = series::table.order_by(series::title).into_boxed();
+        let query = match visibility {
+            Visibility::Admin => query,
+            _ => {
+                // Loaded on their own, the boxed scope of the articles cannot be nested in a subselect.
+                let visible_articles = articles::table
+                    .filter(visibility.scope())
+                    .select(articles::id)
+                    .load::<i32>(connection)?;
+                query.filter(
+                    series::id.eq_any(
+                        series_articles::table
+                            .filter(series_articles::article_id.eq_any(&visible_articles))
+                            .select(series_articles::series_id),
+                    ),
+                )
+            }
+        };
+
+        query.load::<Self>(connection)
+    }
+
+    pub fn get(
+        id: i32,
+        visibility: Visibility,
+        connection: &PgConnection,
+    ) -> Result<SeriesRepresentation, diesel::result::Error> {
+        let series = series::table.find(id).first::<Self>(connection)?;
+
+        Ok(SeriesRepresentation {
+            parts: Self::parts(id, visibility, connection)?,
+            id: series.id,
+            title: series.title,
+            description: series.description,
+        })
+    }
+
+    // None when the article is not part of a series.
+    pub fn of_article(
+        article_id: i32,
+        visibility: Visibility,
+        connection: &PgConnection,
+    ) -> Result<Option<ArticleSeries>, diesel::result::Error> {
+        let series_id = series_articles::table
+            .filter(series_articles::article_id.eq(article_id))
+            .select(series_articles::series_id)
+            .first::<i32>(connection)
+            .optional()?;
+        let series = match series_id {
+            Some(series_id) => series::table.find(series_id).first::<Self>(connection)?,
+            None => return Ok(None),
+        };
+
+        let parts = Self::parts(series.id, visibility, connection)?;
+        let position = match parts.iter().position(|part| part.id == article_id) {
+            Some(position) => position,
+            None => return Ok(None),
+        };
+        Ok(Some(ArticleSeries {
+            id: series.id,
+            title: series.title,
+            part: position as i32 + 1,
+            parts: parts.len() as i32,
+            previous: position
+                .checked_sub(1)
+                .and_then(|previous| parts.get(previous))
+                .cloned(),
+            next: parts.get(position + 1).cloned(),
+        }))
+    }
+
+    // Every part, whatever its visibility, to drop the cached responses embedding the series.
+    #[cfg(feature = "editable")]
+    pub fn article_ids(
+        id: i32,
+        connection: &PgConnection,
+    ) -> Result<Vec<i32>, diesel::result::Error> {
+        series_articles::table
+            .filter(series_articles::series_id.eq(id))
+            .select(series_articles::article_id)
+            .load::<i32>(connection)
+    }
+
+    // The series the articles are part of, other than the given one.
+    #[cfg(feature = "editable")]
+    pub fn other_series_of(
+        id: i32,
+        article_ids: &[i32],
+        connection: &PgConnection,
+    ) -> Result<Vec<i32>, diesel::result::Error> {
+        series_articles::table
+            .filter(series_articles::article_id.eq_any(article_ids))
+            .filter(series_articles::series_id.ne(id))
+            .select(series_articles::series_id)
+            .distinct()
+            .load::<i32>(connection)
+    }
+
+    #[cfg(feature = "editable")]
+    pub fn add(
+        input: &InputSeries,
+        connection: &PgConnection,
+    ) -> Result<SeriesRepresentation, diesel::result::Error> {
+        let id = diesel::insert_into(series::table)
+            .values(&NewSeries {
+                title: &input.title,
+                description: &input.description,
+            })
+            .returning(series::id)
+            .get_result::<i32>(connection)?;
+
+        Self::get(id, Visibility::Admin, connection)
+    }
+
+    #[cfg(feature = "editable")]
+    pub fn update(
+        id: i32,
+        input: &InputSeries,
+        connection: &PgConnection,
+    ) -> Result<SeriesRepresentation, diesel::result::Error> {
+        diesel::update(series::table.find(id))
+            .set(&NewSeries {
+                title: &input.title,
+                description: &input.description,
+            })
+            .get_result::<Self>(connection)?;
+
+        Self::get(id, Visibility::Admin, connection)
+    }
+
+    #[cfg(feature = "editable")]
+    pub fn delete(id: i32, connection: &PgConnection) -> Result<usize, diesel::result::Error> {
+        diesel::delete(series::table.find(id)).execute(connection)
+    }
+
+    // Replace the parts of the series, in order. Articles already part of another series are moved to this one.
+    #[cfg(feature = "editable")]
+    pub fn set_parts(
+        id: i32,
+        article_ids: &[i32],
+        connection: &PgConnection,
+    ) -> Result<SeriesRepresentation, diesel::result::Error> {
+        connection.transaction::<_, diesel::result::Error, _>(|| {
+            series::table
+                .find(id)
+                .select(series::id)
+                .first::<i32>(connection)?;
+            diesel::delete(
+                series_articles::table.filter(
+                    series_articles::series_id
+                        .eq(id)
+                        .or(series_articles::article_id.eq_any(article_ids)),
+                ),
+            )
+            .execute(connection)?;
+            if article_ids.is_empty() {
+                return Ok(());
+            }
+            diesel::insert_into(series_articles::table)
+                .values(
+                    article_ids
+                        .iter()
+                        .enumerate()
+                        .map(|(index, article_id)| NewSeriesArticle {
+                            series_id: id,
+                            article_id: *article_id,
+                            index: index as i32,
+                        })
+                        .collect::<Vec<_>>(),
+                )
+                .execute(connection)?;
+            Ok(())
+        })?;
+
+        Self::get(id, Visibility::Admin, connection)
+    }
+}
@@ -1,5 +1,5 @@
 use {
-    super::{articles::Article, projects::Project},
+    super::{articles::Article, projects::Project, visibility::Visibility},
     crate::{interfaces::TagResults, schema::tags},
     diesel::{PgConnection, RunQueryDsl},
     serde::{Deserialize, Serialize},
@@ -20,8 +20,9 @@ impl Tag {
     pub fn results(
         connection: &PgConnection,
         label: &str,
+        visibility: Visibility,
     ) -> Result<TagResults, diesel::result::Error> {
-        let articles = Article::tagged(label, connection)?;
+        let articles = Article::tagged(label, visibility, connection)?;
         let projects = Project::tagged(label, connection)?;
 
         Ok(TagResults { articles, projects })
@@ -0,0 +1,83 @@
+/*
+ * Which articles a reader may see. Every query returning articles to a reader (Article::get, list, search and tagged)
+ * is filtered by the scope of its visibility instead of checking the published flag on its own:
+ *     - Public: the published articles, the readers of the public server.
+ *     - Preview: the published articles and the one unpublished article a preview token was minted for.
+ *     - Admin: every article, in the editor.
+ * The servers hand their visibility to the handlers as app data, see Visibility::reader.
+ */
+use {
+    crate::{schema::articles, DISPLAY_UNPUBLISHED_ARTICLES},
+    diesel::{
+        expression::BoxableExpression, pg::Pg, sql_types::Bool, BoolExpressionMethods,
+        ExpressionMethods, IntoSql,
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Visibility {
+    Public,
+    Preview(i32),
+    Admin,
+}
+
+pub type ArticleScope = Box<dyn BoxableExpression<articles::table, Pg, SqlType = Bool>>;
+
+impl Visibility {
+    // The editable server shows every article, the public one only the published ones unless
+    // DISPLAY_UNPUBLISHED_ARTICLES is set.
+    pub fn reader() -> Self {
+        if cfg!(feature = "editable") || DISPLAY_UNPUBLISHED_ARTICLES != "false" {
+            Visibility::Admin
+        } else {
+            Visibility::Public
+        }
+    }
+
+    pub fn scope(self) -> ArticleScope {
+        match self {
+            Visibility::Public => Box::new(articles::published.eq(true)),
+            Visibility::Preview(id) => {
+                Box::new(articles::published.eq(true).or(articles::id.eq(id)))
+            }
+            Visibility::Admin => Box::new(true.into_sql::<Bool>()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::Visibility,
+        crate::{models::articles::ARTICLE_COLUMNS, schema::articles},
+        diesel::{debug_query, pg::Pg, QueryDsl},
+    };
+
+    fn sql(visibility: Visibility) -> String {
+        let query = articles::table
+            .select(ARTICLE_COLUMNS)
+            .filter(visibility.scope());
+        debug_query::<Pg, _>(&query).to_string()
+    }
+
+    #[test]
+    fn public_scope_only_matches_published_articles() {
+        let sql = sql(Visibility::Public);
+        assert!(sql.contains(r#"WHERE "articles"."published" = $1"#));
+        assert!(sql.contains("binds: [true]"));
+    }
+
+    #[test]
+    fn preview_scope_adds_the_previewed_article() {
+        let sql = sql(Visibility::Preview(42));
+        assert!(sql.contains(r#"WHERE ("articles"."published" = $1 OR "articles"."id" = $2)"#));
+        assert!(sql.contains("binds: [true, 42]"));
+    }
+
+    #[test]
+    fn admin_scope_matches_every_article() {
+        let sql = sql(Visibility::Admin);
+        assert!(sql.contains("WHERE $1"));
+        assert!(sql.contains("binds: [true]"));
+    }
+}
@@ -0,0 +1,59 @@
+/*
+ * Preview links for unpublished articles. A preview token is "<article id>.<expiry>.<signature>", the expiry being a
+ * UNIX timestamp and the signature the hex HMAC-SHA256 of "<article id>.<expiry>" keyed by PREVIEW_SECRET.
+ * The editable server mints the tokens and the public one checks them, so both must share the same secret. Without
+ * it, no token can be minted nor accepted.
+ */
+use {
+    chrono::Utc,
+    hmac::{Hmac, Mac, NewMac},
+    once_cell::sync::Lazy,
+    sha2::Sha256,
+};
+
+#[cfg(any(feature = "editable", test))]
+use chrono::NaiveDateTime;
+
+type HmacSha256 = Hmac<Sha256>;
+
+static SECRET: Lazy<Option<Vec<u8>>> = Lazy::new(|| {
+    std::env::var("PREVIEW_SECRET")
+        .ok()
+        .filter(|secret| !secret.is_empty())
+        .map(String::into_bytes)
+});
+
+fn mac(payload: &str) -> Option<HmacSha256> {
+    let mut mac = HmacSha256::new_from_slice(SECRET.as_ref()?).ok()?;
+    mac.update(payload.as_bytes());
+    Some(mac)
+}
+
+fn decode_hex(text: &str) -> Option<Vec<u8>> {
+    if text.len() % 2 != 0 {
+        return None;
+    }
+    (0..text.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(text.get(index..index + 2)?, 16).ok())
+        .collect()
+}
+
+// None when no secret is configured.
+#[cfg(any(feature = "editable", test))]
+pub fn sign(article_id: i32, expires: &NaiveDateTime) -> Option<String> {
+    let payload = format!("{}.{}", article_id, expires.timestamp());
+    let signature = mac(&payload)?.finalize().into_bytes();
+    Some(format!("{}.{:x}", payload, signature))
+}
+
+// Id of the article a token gives access to, if its signature is valid and it has not expired yet.
+pub fn verify(token: &str) -> Option<i32> {
+    let (payload, signature) = token.rsplit_once('.')?;
+    let (article_id, expires) = payload.split_once('.')?;
+    let (article_id, expires) = (article_id.parse().ok()?, expires.parse::<i64>().ok()?);
+
+    // Constant time comparison, see Mac::verify.
+    mac(payload)?.verify(&decode_hex(signature)?).ok()?;
+    (Utc::now().timestamp() < expires).then(|| article_id)
+}
@@ -0,0 +1,289 @@
+/*
+ * Inline formatting of Text contents, rendered to HTML when a content is saved like code and math:
+ *     **bold**, *italics*, ~~strikethrough~~, `code`, [links](https://...), hard line breaks, $inline math$ and [^key]
+ *     citations of the article's references (see crate::models::references).
+ * Only this CommonMark inline subset is rendered: block syntax (headings, lists, quotes...) is kept as text, raw HTML
+ * is escaped and links are limited to http(s), mailto and relative URLs.
+ * The plain text (without the markup) is stored next to the HTML for the search.
+ */
+use {
+    crate::{
+        html::escape_html,
+        math::{render_block, render_inline, segments, Segment},
+        models::references::{is_key_char, CITATION_END, CITATION_START},
+        types::content_type::ContentType,
+    },
+    pulldown_cmark::{Event, Options, Parser, Tag},
+};
+
+// Inline formulas are replaced by these markers while the Markdown is parsed.
+const MATH_START: char = '\u{E000}';
+const MATH_END: char = '\u{E001}';
+
+pub struct RenderedText {
+    pub html: String,
+    pub plain_text: String,
+}
+
+// Rendering stored with a content, see Content::update.
+#[derive(Default)]
+pub struct Rendering {
+    pub html: Option<String>,
+    pub plain_text: String,
+}
+
+pub fn is_safe_url(url: &str) -> bool {
+    let url = url.trim_start().to_lowercase();
+    match url.find(':') {
+        Some(colon) => {
+            let scheme = &url[..colon];
+            // A colon after a path, query or fragment delimiter is not a scheme.
+            scheme.contains(|c| c == '/' || c == '?' || c == '#')
+                || ["http", "https", "mailto"].contains(&scheme)
+        }
+        None => true,
+    }
+}
+
+// Escape what would start a block, every line of a Text being a line of a paragraph.
+fn escape_blocks(text: &str) -> String {
+    text.lines()
+        // Indented lines would be code blocks.
+        .map(str::trim_start)
+        .map(|line| {
+            let digits = line.len() - line.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+            let (number, rest) = line.split_at(digits);
+            let starts_block = if digits > 0 {
+                rest.starts_with(". ") || rest.starts_with(") ")
+            } else {
+                match rest.chars().next() {
+                    Some('#' | '>' | '=') => true,
+                    Some(marker @ ('-' | '+' | '*' | '_')) => {
+                        rest[1..].starts_with(' ') || rest.chars().all(|c| c == marker || c == ' ')
+                    }
+                    Some('`' | '~') => rest.starts_with("```") || rest.starts_with("~~~"),
+                    _ => false,
+                }
+            };
+            if starts_block {
+                format!("{}\\{}", number, rest)
+            } else {
+                line.to_owned()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// Citations become markers in the HTML and are left out of the plain text.
+fn push_text(text: &str, html: &mut String, plain_text: &mut String) {
+    let mut rest = text;
+    while let Some(start) = rest.find("[^") {
+        let after = &rest[start + 2..];
+        let key_length = after.find(|c: char| !is_key_char(c)).unwrap_or(after.len());
+        if key_length > 0 && after[key_length..].starts_with(']') {
+            html.push_str(&escape_html(&rest[..start]));
+            plain_text.push_str(&rest[..start]);
+            html.push(CITATION_START);
+            html.push_str(&after[..key_length]);
+            html.push(CITATION_END);
+            rest = &after[key_length + 1..];
+        } else {
+            html.push_str(&escape_html(&rest[..start + 2]));
+            plain_text.push_str(&rest[..start + 2]);
+            rest = after;
+        }
+    }
+    html.push_str(&escape_html(rest));
+    plain_text.push_str(rest);
+}
+
+pub fn render_text(text: &str) -> Result<RenderedText, String> {
+    let mut source = String::with_capacity(text.len());
+    let mut formulas = Vec::new();
+    for segment in segments(text)? {
+        match segment {
+            Segment::Text(text) => source.push_str(&text),
+            Segment::Math(latex) => {
+                source.push(MATH_START);
+                source.push_str(&formulas.len().to_string());
+                source.push(MATH_END);
+                formulas.push(latex);
+            }
+        }
+    }
+
+    let mut html = String::with_capacity(source.len());
+    let mut plain_text = String::with_capacity(source.len());
+    // Whether each open link was rendered, unsafe ones only keep their text.
+    let mut links = Vec::new();
+    let mut paragraphs = 0;
+    // pulldown-cmark splits texts on brackets, they are joined again to find the citations.
+    let mut text = String::new();
+    let source = escape_blocks(&source);
+    for event in Parser::new_ext(&source, Options::ENABLE_STRIKETHROUGH) {
+        // Raw HTML is shown as it was typed.
+        if let Event::Text(fragment) | Event::Html(fragment) = &event {
+            text.push_str(fragment);
+            continue;
+        }
+        push_text(&std::mem::take(&mut text), &mut html, &mut plain_text);
+        match event {
+            Event::Start(Tag::Paragraph) => {
+                if paragraphs > 0 {
+                    html.push_str("\n\n");
+                    plain_text.push_str("\n\n");
+                }
+                paragraphs += 1;
+            }
+            Event::Start(Tag::Emphasis) => html.push_str("<em>"),
+            Event::End(Tag::Emphasis) => html.push_str("</em>"),
+            Event::Start(Tag::Strong) => html.push_str("<strong>"),
+            Event::End(Tag::Strong) => html.push_str("</strong>"),
+            Event::Start(Tag::Strikethrough) => html.push_str("<del>"),
+            Event::End(Tag::Strikethrough) => html.push_str("</del>"),
+            Event::Start(Tag::Link(_, url, title)) => {
+                let safe = is_safe_url(&url);
+                if safe && title.is_empty() {
+                    html.push_str(&format!(
+                        "<a href=\"{}\" target=\"_blank\" rel=\"noopener noreferrer\">",
+                        escape_html(&url)
+                    ));
+                } else if safe {
+                    html.push_str(&format!(
+                        "<a href=\"{}\" title=\"{}\" target=\"_blank\" rel=\"noopener noreferrer\">",
+                        escape_html(&url),
+                        escape_html(&title)
+                    ));
+                }
+                links.push(safe);
+            }
+            Event::End(Tag::Link(..)) => {
+                if links.pop().unwrap_or_default() {
+                    html.push_str("</a>");
+                }
+            }
+            Event::Code(code) => {
+                html.push_str(&format!("<code>{}</code>", escape_html(&code)));
+                plain_text.push_str(&code);
+            }
+            Event::SoftBreak => {
+                html.push('\n');
+                plain_text.push('\n');
+            }
+            Event::HardBreak => {
+                html.push_str("<br>");
+                plain_text.push('\n');
+            }
+            // Other tags (images) only keep their text.
+            _ => {}
+        }
+    }
+    push_text(&text, &mut html, &mut plain_text);
+
+    // Put the rendered formulas back in place of their markers.
+    for (index, latex) in formulas.iter().enumerate() {
+        let marker = format!("{}{}{}", MATH_START, index, MATH_END);
+        html = html.replacen(&marker, &render_inline(latex)?, 1);
+        plain_text = plain_text.replacen(&marker, latex.trim(), 1);
+    }
+
+    Ok(RenderedText { html, plain_text })
+}
+
+// The rendered_html and plain_text of a content. Invalid contents are rejected by the validation, they are left
+// unrendered here.
+pub fn render_content(content_type: &ContentType, content: &str) -> Rendering {
+    match content_type {
+        ContentType::Text => match render_text(content) {
+            Ok(rendered) => Rendering {
+                html: Some(rendered.html),
+                plain_text: rendered.plain_text,
+            },
+            Err(_) => Rendering {
+                html: None,
+                plain_text: content.to_owned(),
+            },
+        },
+        ContentType::Math => Rendering {
+            html: render_block(content).ok(),
+            plain_text: String::new(),
+        },
+        ContentType::Comment | ContentType::Callout | ContentType::Quote => Rendering {
+            html: None,
+            plain_text: content.to_owned(),
+        },
+        _ => Rendering::default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::render_text,
+        crate::models::references::{CITATION_END, CITATION_START},
+    };
+
+    fn html(text: &str) -> String {
+        render_text(text).unwrap().html
+    }
+
+    #[test]
+    fn safe_links_are_rendered() {
+        assert!(html("[home](https://guimauve.io)").starts_with("<a href=\"https://guimauve.io\""));
+        assert!(html("[article](/articles/1)").starts_with("<a href=\"/articles/1\""));
+        assert!(html("[mail](mailto:contact@guimauve.io)").starts_with("<a href=\"mailto:"));
+    }
+
+    #[test]
+    fn unsafe_links_only_keep_their_text() {
+        for text in [
+            "[click](javascript:alert(1))",
+            "[click](JavaScript:alert(1))",
+            "[click](data:text/html;base64,PHNjcmlwdD5hbGVydCgxKTwvc2NyaXB0Pg==)",
+            "[click](javascript&#58;alert(1))",
+            "[click](&#106;avascript:alert(1))",
+        ] {
+            assert_eq!(html(text), "click", "{}", text);
+        }
+    }
+
+    #[test]
+    fn raw_html_is_escaped() {
+        let script = html("<script>alert(1)</script>");
+        assert!(!script.contains("<script"));
+        assert!(script.contains("&lt;script&gt;alert(1)&lt;/script&gt;"));
+
+        let image = html("Look <img src=x onerror=alert(1)> here");
+        assert!(!image.contains("<img"));
+        assert!(image.contains("&lt;img src=x onerror=alert(1)&gt;"));
+    }
+
+    #[test]
+    fn citations_become_markers() {
+        let rendered = render_text("As shown by Knuth[^knuth-1984], [^not a key] stays.").unwrap();
+        assert_eq!(
+            rendered.html,
+            format!(
+                "As shown by Knuth{}knuth-1984{}, [^not a key] stays.",
+                CITATION_START, CITATION_END
+            )
+        );
+        assert_eq!(
+            rendered.plain_text,
+            "As shown by Knuth, [^not a key] stays."
+        );
+    }
+
+    #[test]
+    fn block_syntax_is_kept_as_text() {
+        let rendered =
+            render_text("# Title\n- item\n1. first\n> quote\n    indented\n***").unwrap();
+        assert_eq!(
+            rendered.plain_text,
+            "# Title\n- item\n1. first\n> quote\nindented\n***"
+        );
+        assert!(!rendered.html.contains("<h1"));
+        assert!(rendered.html.contains("&gt; quote"));
+    }
+}
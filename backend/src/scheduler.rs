@@ -0,0 +1,67 @@
+/*
+ * Scheduled publishing: every SCHEDULER_INTERVAL seconds (60 by default), the articles whose publish_at or
+ * unpublish_at time has come are published or unpublished, see Article::apply_schedule. The transitions are logged and
+ * drop the cached responses they affect.
+ * Both the public and the editable servers run it on the same database, each transition being applied by whichever
+ * comes first (the cache of the other one is cleared when it polls the cache generation).
+ */
+use {
+    crate::{
+        cache::{env_or, Dependency, ResponseCache},
+        errors::api_error::ApiError,
+        models::articles::Article,
+        Pool,
+    },
+    actix_web::{rt, web},
+    log::{error, info},
+    std::time::Duration,
+};
+
+const DEFAULT_INTERVAL_SECONDS: u64 = 60;
+
+async fn run(pool: &Pool, cache: &ResponseCache) {
+    let pool = pool.clone();
+    let transitions = web::block(move || {
+        let connection = pool.get()?;
+        Ok::<_, ApiError>(Article::apply_schedule(&connection)?)
+    })
+    .await;
+
+    match transitions {
+        Ok(transitions) => {
+            for id in &transitions.published {
+                info!("Scheduler: article {} published.", id);
+            }
+            for id in &transitions.unpublished {
+                info!("Scheduler: article {} unpublished.", id);
+            }
+            let dependencies: Vec<Dependency> = transitions
+                .published
+                .iter()
+                .chain(&transitions.unpublished)
+                .map(|id| Dependency::Article(*id))
+                .collect();
+            if !dependencies.is_empty() {
+                cache.invalidate(&dependencies);
+                cache.invalidate(&[
+                    Dependency::ArticleList,
+                    Dependency::TagResults,
+                    Dependency::Search,
+                ]);
+            }
+        }
+        Err(err) => error!("Scheduler: could not apply the schedule ({}).", err),
+    }
+}
+
+// Spawned once on the system arbiter, the first run happens right away.
+pub fn start(pool: Pool, cache: web::Data<ResponseCache>) {
+    let period = Duration::from_secs(env_or("SCHEDULER_INTERVAL", DEFAULT_INTERVAL_SECONDS));
+    rt::spawn(async move {
+        let mut interval = rt::time::interval(period);
+        loop {
+            interval.tick().await;
+            run(&pool, &cache).await;
+        }
+    });
+}
@@ -1,3 +1,24 @@
+table! {
+    article_references (id) {
+        id -> Int4,
+        article_id -> Int4,
+        index -> Int4,
+        key -> Text,
+        title -> Text,
+        url -> Nullable<Text>,
+    }
+}
+
+table! {
+    article_revisions (id) {
+        id -> Int4,
+        article_id -> Int4,
+        author -> Text,
+        created -> Timestamp,
+        snapshot -> Jsonb,
+    }
+}
+
 table! {
     article_tags (id) {
         id -> Int4,
@@ -14,13 +35,23 @@ table! {
         published -> Bool,
         headline -> Text,
         image -> Text,
+        image_alt -> Text,
         image_credits -> Nullable<Text>,
         text_searchable_article -> crate::diesel_full_text_search::TsVector,
         updated -> Nullable<Timestamp>,
+        publish_at -> Nullable<Timestamp>,
+        unpublish_at -> Nullable<Timestamp>,
     }
 
 }
 
+table! {
+    cache_generation (id) {
+        id -> Int4,
+        generation -> Int8,
+    }
+}
+
 table! {
     chapters (id) {
         id -> Int4,
@@ -41,6 +72,14 @@ table! {
         language -> Nullable<crate::types::language::LanguageMapping>,
         highlighted_code -> Nullable<Text>,
         url -> Nullable<Text>,
+        caption -> Nullable<Text>,
+        start_line -> Nullable<Int4>,
+        emphasized_lines -> Nullable<Text>,
+        added_lines -> Nullable<Text>,
+        removed_lines -> Nullable<Text>,
+        data -> Nullable<Jsonb>,
+        rendered_html -> Nullable<Text>,
+        plain_text -> Text,
     }
 }
 
@@ -53,11 +92,28 @@ table! {
     }
 }
 
+table! {
+    media (id) {
+        id -> Int4,
+        path -> Text,
+        size -> Int8,
+        mime_type -> Varchar,
+        checksum -> Varchar,
+        width -> Nullable<Int4>,
+        height -> Nullable<Int4>,
+        created -> Timestamp,
+        updated -> Nullable<Timestamp>,
+    }
+}
+
 table! {
     project_images (id) {
         id -> Int4,
         project_id -> Int4,
         image -> Text,
+        alt -> Text,
+        caption -> Nullable<Text>,
+        credits -> Nullable<Text>,
     }
 }
 
@@ -85,6 +141,23 @@ table! {
     }
 }
 
+table! {
+    series (id) {
+        id -> Int4,
+        title -> Text,
+        description -> Text,
+    }
+}
+
+table! {
+    series_articles (id) {
+        id -> Int4,
+        series_id -> Int4,
+        article_id -> Int4,
+        index -> Int4,
+    }
+}
+
 table! {
     tags (id) {
         id -> Int4,
@@ -92,6 +165,8 @@ table! {
     }
 }
 
+joinable!(article_references -> articles (article_id));
+joinable!(article_revisions -> articles (article_id));
 joinable!(article_tags -> articles (article_id));
 joinable!(article_tags -> tags (tag_id));
 joinable!(chapters -> articles (article_id));
@@ -100,14 +175,22 @@ joinable!(contents -> chapters (chapter_id));
 joinable!(project_images -> projects (project_id));
 joinable!(project_tags -> projects (project_id));
 joinable!(project_tags -> tags (tag_id));
+joinable!(series_articles -> articles (article_id));
+joinable!(series_articles -> series (series_id));
 
 allow_tables_to_appear_in_same_query!(
+    article_references,
+    article_revisions,
     article_tags,
     articles,
+    cache_generation,
     chapters,
     contents,
+    media,
     project_images,
     project_tags,
     projects,
+    series,
+    series_articles,
     tags,
 );
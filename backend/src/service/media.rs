@@ -0,0 +1,109 @@
+use {
+    crate::{
+        types::{content_type::ContentType, image::Image},
+        MEDIA_ROOT, MEDIA_URL_PREFIX,
+    },
+    sha2::{Digest, Sha256},
+    std::{
+        fs, io,
+        path::{Path, PathBuf},
+    },
+};
+
+#[derive(Debug)]
+pub struct ScannedFile {
+    pub path: String,
+    pub size: i64,
+    pub mime_type: String,
+    pub checksum: String,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+}
+
+fn walk(directory: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(directory)? {
+        let entry = entry?;
+        // Symlinks are not followed, only regular files living under the media root are registered.
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            walk(&entry.path(), files)?;
+        } else if file_type.is_file() {
+            files.push(entry.path());
+        }
+    }
+
+    Ok(())
+}
+
+// Turn "./media/images/articles/ferris.webp" into "/media/images/articles/ferris.webp", which is how paths are stored in the database.
+fn to_media_path(file: &Path) -> Option<String> {
+    let relative = file.strip_prefix(MEDIA_ROOT).ok()?;
+    let mut path = String::from(MEDIA_URL_PREFIX);
+    for component in relative.components() {
+        path.push('/');
+        path.push_str(component.as_os_str().to_str()?);
+    }
+
+    Some(path)
+}
+
+// Turn a database path back into a path on disk, or None if it does not point inside the media root.
+pub fn to_file_path(media_path: &str) -> Option<PathBuf> {
+    let relative = media_path.strip_prefix(MEDIA_URL_PREFIX)?;
+    if relative.split('/').any(|segment| segment == "..") {
+        return None;
+    }
+
+    Some(Path::new(MEDIA_ROOT).join(relative.trim_start_matches('/')))
+}
+
+// Image contents keep the dimensions of their file in contents.data, so that the page does not move while it loads.
+pub fn measure_image(
+    content_type: &ContentType,
+    content: &str,
+    data: &Option<serde_json::Value>,
+) -> Option<serde_json::Value> {
+    if *content_type != ContentType::Image {
+        return data.clone();
+    }
+    let mut image = Image::from_data(data).unwrap_or_default();
+    let dimensions = to_file_path(content).and_then(|file| imagesize::size(file).ok());
+    image.width = dimensions.as_ref().map(|d| d.width as i32);
+    image.height = dimensions.as_ref().map(|d| d.height as i32);
+
+    serde_json::to_value(image).ok()
+}
+
+pub fn scan_file(file: &Path) -> io::Result<Option<ScannedFile>> {
+    let path = match to_media_path(file) {
+        Some(path) => path,
+        None => return Ok(None),
+    };
+    let bytes = fs::read(file)?;
+    let dimensions = imagesize::size(file).ok();
+
+    Ok(Some(ScannedFile {
+        path,
+        size: bytes.len() as i64,
+        mime_type: mime_guess::from_path(file)
+            .first_or_octet_stream()
+            .to_string(),
+        checksum: format!("{:x}", Sha256::digest(&bytes)),
+        width: dimensions.as_ref().map(|d| d.width as i32),
+        height: dimensions.as_ref().map(|d| d.height as i32),
+    }))
+}
+
+pub fn scan() -> io::Result<Vec<ScannedFile>> {
+    let mut files = Vec::new();
+    walk(Path::new(MEDIA_ROOT), &mut files)?;
+
+    let mut scanned_files = Vec::with_capacity(files.len());
+    for file in &files {
+        if let Some(scanned_file) = scan_file(file)? {
+            scanned_files.push(scanned_file);
+        }
+    }
+
+    Ok(scanned_files)
+}
@@ -1 +1,4 @@
+#[cfg(feature = "editable")]
+pub mod media;
+pub mod related;
 pub mod search;
@@ -0,0 +1,150 @@
+/*
+ * Published articles and projects related to an article, ranked in a single query by:
+ *     - the tags they share with the article, each weighted by its rarity: ln(1 + tagged items / items with the tag),
+ *     - the similarity of their search vectors with the one of the article: shared lexemes / distinct lexemes.
+ * Only the items sharing a tag or a lexeme with the article are scored, the latter being found through the GIN indexes
+ * of the search vectors. Articles are returned as summaries, projects as they are listed.
+ */
+use {
+    crate::{
+        models::{
+            articles::{Article, ArticleSummary},
+            projects::{Project, ProjectRepresentation},
+            visibility::Visibility,
+        },
+        schema::articles,
+    },
+    diesel::{
+        pg::PgConnection,
+        sql_types::{BigInt, Double, Integer, Text},
+        QueryDsl, RunQueryDsl,
+    },
+    serde::Serialize,
+};
+
+// Identical search vectors weigh about as much as two shared tags found on half of the tagged items.
+const TEXT_WEIGHT: f64 = 2.0;
+
+const RELATED_QUERY: &str = "
+WITH source AS (
+    SELECT tsvector_to_array(text_searchable_article) AS lexemes FROM articles WHERE id = $1
+),
+source_query AS (
+    -- Any lexeme of the article, quoted as in the tsquery syntax.
+    SELECT string_agg(
+        '''' || replace(replace(lexeme, '\\', '\\\\'), '''', '''''') || '''', ' | '
+    )::tsquery AS query
+    FROM source, unnest(source.lexemes) AS lexeme
+),
+source_tags AS (
+    SELECT tag_id FROM article_tags WHERE article_id = $1
+),
+items AS (
+    SELECT 'article' AS kind, id, text_searchable_article AS document FROM articles
+    WHERE published AND id <> $1 AND (
+        text_searchable_article @@ (SELECT query FROM source_query)
+        OR id IN (SELECT article_id FROM article_tags WHERE tag_id IN (SELECT tag_id FROM source_tags))
+    )
+    UNION ALL
+    SELECT 'project', id, text_searchable_project FROM projects
+    WHERE text_searchable_project @@ (SELECT query FROM source_query)
+        OR id IN (SELECT project_id FROM project_tags WHERE tag_id IN (SELECT tag_id FROM source_tags))
+),
+item_tags AS (
+    SELECT 'article' AS kind, article_tags.article_id AS id, article_tags.tag_id
+    FROM article_tags JOIN articles ON articles.id = article_tags.article_id
+    WHERE articles.published
+    UNION ALL
+    SELECT 'project', project_id, tag_id FROM project_tags
+),
+weights AS (
+    SELECT
+        tag_id,
+        ln(1.0 + (SELECT count(DISTINCT (kind, id)) FROM item_tags)::float8 / count(*)::float8) AS weight
+    FROM item_tags
+    GROUP BY tag_id
+),
+shared_tags AS (
+    SELECT item_tags.kind, item_tags.id, sum(weights.weight) AS score
+    FROM item_tags JOIN weights ON weights.tag_id = item_tags.tag_id
+    WHERE item_tags.tag_id IN (SELECT tag_id FROM source_tags)
+    GROUP BY item_tags.kind, item_tags.id
+),
+scores AS (
+    SELECT
+        items.kind,
+        items.id,
+        COALESCE(shared_tags.score, 0) AS tag_score,
+        CASE WHEN shared.common_lexemes = 0 THEN 0
+            ELSE shared.common_lexemes::float8
+                / (length(items.document) + cardinality(source.lexemes) - shared.common_lexemes)
+        END AS text_score
+    FROM items
+    CROSS JOIN source
+    LEFT JOIN shared_tags ON shared_tags.kind = items.kind AND shared_tags.id = items.id
+    CROSS JOIN LATERAL (
+        SELECT count(*) AS common_lexemes FROM (
+            SELECT unnest(tsvector_to_array(items.document))
+            INTERSECT
+            SELECT unnest(source.lexemes)
+        ) AS common
+    ) AS shared
+)
+SELECT kind, id, tag_score + $2 * text_score AS score
+FROM scores
+WHERE tag_score > 0 OR text_score > 0
+ORDER BY score DESC, kind, id DESC
+LIMIT $3
+";
+
+#[derive(QueryableByName, Debug)]
+struct RelatedRow {
+    #[sql_type = "Text"]
+    kind: String,
+    #[sql_type = "Integer"]
+    id: i32,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum RelatedItem {
+    Article(ArticleSummary),
+    Project(ProjectRepresentation),
+}
+
+// Best related items first. The article itself must be visible to the reader, related items are always published.
+pub fn related(
+    connection: &PgConnection,
+    article_id: i32,
+    limit: i64,
+    visibility: Visibility,
+) -> Result<Vec<RelatedItem>, diesel::result::Error> {
+    articles::table
+        .find(article_id)
+        .filter(visibility.scope())
+        .select(articles::id)
+        .first::<i32>(connection)?;
+
+    let rows = diesel::sql_query(RELATED_QUERY)
+        .bind::<Integer, _>(article_id)
+        .bind::<Double, _>(TEXT_WEIGHT)
+        .bind::<BigInt, _>(limit)
+        .load::<RelatedRow>(connection)?;
+
+    let ids = |kind: &str| -> Vec<i32> {
+        rows.iter()
+            .filter(|row| row.kind == kind)
+            .map(|row| row.id)
+            .collect()
+    };
+    let mut related_articles = Article::summaries(&ids("article"), connection)?;
+    let mut related_projects = Project::with_ids(&ids("project"), connection)?;
+
+    Ok(rows
+        .iter()
+        .filter_map(|row| match row.kind.as_str() {
+            "article" => related_articles.remove(&row.id).map(RelatedItem::Article),
+            _ => related_projects.remove(&row.id).map(RelatedItem::Project),
+        })
+        .collect())
+}
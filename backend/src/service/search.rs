@@ -1,7 +1,7 @@
 use {
     crate::{
         interfaces::SearchResults,
-        models::{articles::Article, projects::Project},
+        models::{articles::Article, projects::Project, visibility::Visibility},
     },
     diesel::pg::PgConnection,
 };
@@ -9,8 +9,9 @@ use {
 pub fn search(
     connection: &PgConnection,
     query: &str,
+    visibility: Visibility,
 ) -> Result<SearchResults, diesel::result::Error> {
-    let articles = Article::search(query, connection)?;
+    let articles = Article::search(query, visibility, connection)?;
     let projects = Project::search(query, connection)?;
 
     Ok(SearchResults { articles, projects })
@@ -0,0 +1,40 @@
+/*
+ * Kind of a Callout content, stored as JSON in contents.data ({ "kind": "warning" }), its text being the content.
+ */
+use {
+    serde::{Deserialize, Serialize},
+    std::fmt::{Display, Formatter, Result},
+};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CalloutKind {
+    Info,
+    Tip,
+    Warning,
+    Danger,
+}
+
+impl Default for CalloutKind {
+    fn default() -> Self {
+        Self::Info
+    }
+}
+
+impl Display for CalloutKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{self:?}")
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct Callout {
+    pub kind: CalloutKind,
+}
+
+impl Callout {
+    pub fn from_data(data: &Option<serde_json::Value>) -> Option<Self> {
+        data.as_ref()
+            .and_then(|data| serde_json::from_value(data.clone()).ok())
+    }
+}
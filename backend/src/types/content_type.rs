@@ -11,6 +11,13 @@ pub enum ContentType {
     Link,
     Code,
     Image,
+    Table,
+    Math,
+    Callout,
+    Quote,
+    List,
+    Video,
+    Audio,
 }
 
 impl Default for ContentType {
@@ -0,0 +1,26 @@
+/*
+ * Description of an Image content, stored as JSON in contents.data, the path being the content and the caption
+ * contents.caption:
+ *     { "alt": "Ferris waving", "credits": "Karen Rustad Tölva", "width": 1200, "height": 800 }
+ * Width and height are measured from the file when the content is saved, not sent by the editor.
+ */
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct Image {
+    #[serde(default)]
+    pub alt: String,
+    #[serde(default)]
+    pub credits: Option<String>,
+    #[serde(default)]
+    pub width: Option<i32>,
+    #[serde(default)]
+    pub height: Option<i32>,
+}
+
+impl Image {
+    pub fn from_data(data: &Option<serde_json::Value>) -> Option<Self> {
+        data.as_ref()
+            .and_then(|data| serde_json::from_value(data.clone()).ok())
+    }
+}
@@ -15,6 +15,41 @@ pub enum Language {
     Javascript,
     Typescript,
     Yaml,
+    C,
+    Cpp,
+    Haskell,
+    Go,
+    Toml,
+    Json,
+    Dockerfile,
+    Markdown,
+    Diff,
+    PlainText,
+}
+
+impl Language {
+    // Every language, in declaration order (keep it in sync with the enum).
+    pub const ALL: [Language; 19] = [
+        Language::Rust,
+        Language::Bash,
+        Language::Python,
+        Language::Sql,
+        Language::Html,
+        Language::Css,
+        Language::Javascript,
+        Language::Typescript,
+        Language::Yaml,
+        Language::C,
+        Language::Cpp,
+        Language::Haskell,
+        Language::Go,
+        Language::Toml,
+        Language::Json,
+        Language::Dockerfile,
+        Language::Markdown,
+        Language::Diff,
+        Language::PlainText,
+    ];
 }
 
 impl Default for Language {
@@ -0,0 +1,28 @@
+/*
+ * Items of a List content, stored as JSON in contents.data:
+ *     { "ordered": true, "items": [{ "text": "Parse", "items": [{ "text": "Tokens", "items": [] }] }, { "text": "Render", "items": [] }] }
+ */
+use serde::{Deserialize, Serialize};
+
+pub const MAX_LIST_DEPTH: usize = 4;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct ListItem {
+    pub text: String,
+    #[serde(default)]
+    pub items: Vec<ListItem>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct List {
+    #[serde(default)]
+    pub ordered: bool,
+    pub items: Vec<ListItem>,
+}
+
+impl List {
+    pub fn from_data(data: &Option<serde_json::Value>) -> Option<Self> {
+        data.as_ref()
+            .and_then(|data| serde_json::from_value(data.clone()).ok())
+    }
+}
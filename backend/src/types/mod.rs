@@ -1,3 +1,9 @@
+pub mod callout;
 pub mod content_type;
+pub mod image;
 pub mod language;
+pub mod list;
+pub mod player;
 pub mod project_category;
+pub mod quote;
+pub mod table;
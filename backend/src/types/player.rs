@@ -0,0 +1,83 @@
+/*
+ * Player of a Video or an Audio content, stored as JSON in contents.data:
+ *     { "poster": "/media/images/talk.webp", "tracks": [{ "src": "/media/videos/talk.en.vtt", "kind": "subtitles", "srclang": "en", "label": "English" }] }
+ * A self-hosted file is the content (a /media path), an external video is the url of a supported provider. External
+ * players are only loaded once the reader asks for them.
+ */
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TrackKind {
+    Subtitles,
+    Captions,
+}
+
+impl Default for TrackKind {
+    fn default() -> Self {
+        Self::Subtitles
+    }
+}
+
+// WebVTT subtitles or captions of a self-hosted file.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct TextTrack {
+    pub src: String,
+    #[serde(default)]
+    pub kind: TrackKind,
+    pub srclang: String,
+    pub label: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct Player {
+    #[serde(default)]
+    pub poster: Option<String>,
+    #[serde(default)]
+    pub tracks: Vec<TextTrack>,
+}
+
+impl Player {
+    pub fn from_data(data: &Option<serde_json::Value>) -> Option<Self> {
+        data.as_ref()
+            .and_then(|data| serde_json::from_value(data.clone()).ok())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmbedProvider {
+    YouTube,
+    Vimeo,
+}
+
+impl EmbedProvider {
+    // The provider and the id of the video of a watch or share URL.
+    pub fn parse(url: &str) -> Option<(Self, String)> {
+        let url = url
+            .trim()
+            .strip_prefix("https://")
+            .or_else(|| url.trim().strip_prefix("http://"))?;
+        let url = url.strip_prefix("www.").unwrap_or(url);
+        let (provider, id) = if let Some(query) = url.strip_prefix("youtube.com/watch?") {
+            let id = query
+                .split('&')
+                .find_map(|parameter| parameter.strip_prefix("v="))?;
+            (Self::YouTube, id)
+        } else if let Some(path) = url.strip_prefix("youtu.be/") {
+            (Self::YouTube, path)
+        } else if let Some(path) = url.strip_prefix("vimeo.com/") {
+            (Self::Vimeo, path)
+        } else {
+            return None;
+        };
+        let id: String = id
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+            .collect();
+        match (provider, id.is_empty()) {
+            (_, true) => None,
+            (Self::Vimeo, _) if !id.chars().all(|c| c.is_ascii_digit()) => None,
+            _ => Some((provider, id)),
+        }
+    }
+}
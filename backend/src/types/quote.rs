@@ -0,0 +1,20 @@
+/*
+ * Attribution of a Quote content, stored as JSON in contents.data, the quotation being the content:
+ *     { "attribution": "Edsger W. Dijkstra", "source_url": "https://..." }
+ */
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct Quote {
+    #[serde(default)]
+    pub attribution: Option<String>,
+    #[serde(default)]
+    pub source_url: Option<String>,
+}
+
+impl Quote {
+    pub fn from_data(data: &Option<serde_json::Value>) -> Option<Self> {
+        data.as_ref()
+            .and_then(|data| serde_json::from_value(data.clone()).ok())
+    }
+}
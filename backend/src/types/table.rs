@@ -0,0 +1,44 @@
+/*
+ * Rows and columns of a Table content, stored as JSON in contents.data:
+ *     { "header": ["Crate", "Time"], "alignments": ["left", "right"], "rows": [["serde", "1.2 ms"]], "caption": "..." }
+ * Cells are plain strings, every row has as many cells as the header.
+ */
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Alignment {
+    None,
+    Left,
+    Center,
+    Right,
+}
+
+impl Default for Alignment {
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct Table {
+    pub header: Vec<String>,
+    #[serde(default)]
+    pub alignments: Vec<Alignment>,
+    #[serde(default)]
+    pub rows: Vec<Vec<String>>,
+    #[serde(default)]
+    pub caption: Option<String>,
+}
+
+impl Table {
+    pub fn from_data(data: &Option<serde_json::Value>) -> Option<Self> {
+        data.as_ref()
+            .and_then(|data| serde_json::from_value(data.clone()).ok())
+    }
+
+    // Missing alignments default to None.
+    pub fn alignment(&self, column: usize) -> Alignment {
+        self.alignments.get(column).copied().unwrap_or_default()
+    }
+}
@@ -0,0 +1,548 @@
+/*
+ * Input validation, run by the editable handlers before anything is written to the database.
+ * Every violation is collected (instead of stopping at the first one) and returned at once in a 422 ApiError::Validation,
+ * keyed by the path of the offending field ("chapters[1].contents[0].url").
+ */
+use {
+    crate::{
+        code::LineRanges,
+        errors::api_error::{ApiError, FieldError},
+        interfaces::{
+            InputArticle, InputChapter, InputContent, InputPreviewLink, InputReference,
+            InputReferenceUpdate, InputScheduleArticle, InputSeries, InputSeriesParts,
+        },
+        math::render_block,
+        models::{
+            articles::Article, chapters::Chapter, contents::Content, references::is_key_char,
+        },
+        rich_text::{is_safe_url, render_text},
+        service::media::to_file_path,
+        types::{
+            callout::Callout,
+            content_type::ContentType,
+            image::Image,
+            language::Language,
+            list::{List, ListItem, MAX_LIST_DEPTH},
+            player::{EmbedProvider, Player},
+            quote::Quote,
+            table::Table,
+        },
+    },
+    mime_guess::mime::{self, Name},
+    std::{collections::HashSet, mem},
+};
+
+pub trait Validate {
+    fn validate(&self, violations: &mut Violations);
+}
+
+#[derive(Default)]
+pub struct Violations {
+    prefix: String,
+    errors: Vec<FieldError>,
+}
+
+impl Violations {
+    pub fn check(&mut self, valid: bool, field: &str, message: &str) {
+        if !valid {
+            let field = if self.prefix.is_empty() {
+                field.to_owned()
+            } else {
+                [self.prefix.as_str(), ".", field].concat()
+            };
+            self.errors.push(FieldError::new(field, message));
+        }
+    }
+
+    // Validate a nested value, its fields being reported under "<field>[<index>]".
+    pub fn nested<T: Validate>(&mut self, field: &str, index: usize, value: &T) {
+        let nested = format!("{}[{}]", field, index);
+        let prefix = if self.prefix.is_empty() {
+            nested
+        } else {
+            [self.prefix.as_str(), ".", &nested].concat()
+        };
+        let parent = mem::replace(&mut self.prefix, prefix);
+        value.validate(self);
+        self.prefix = parent;
+    }
+
+    pub fn into_result(self) -> Result<(), ApiError> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ApiError::Validation(self.errors))
+        }
+    }
+}
+
+pub fn validate<T: Validate>(value: &T) -> Result<(), ApiError> {
+    let mut violations = Violations::default();
+    value.validate(&mut violations);
+    violations.into_result()
+}
+
+fn is_blank(text: &str) -> bool {
+    text.trim().is_empty()
+}
+
+// Indexes must be 0, 1, ..., n - 1, in any order.
+fn is_contiguous(indexes: impl Iterator<Item = i32>) -> bool {
+    let mut indexes: Vec<i32> = indexes.collect();
+    indexes.sort_unstable();
+    indexes
+        .iter()
+        .zip(0..)
+        .all(|(index, expected)| *index == expected)
+}
+
+fn is_media_file(path: &str) -> bool {
+    to_file_path(path).map_or(false, |file| file.is_file())
+}
+
+// The type of a self-hosted file is guessed from its extension, like the media library does.
+fn is_media_file_of(path: &str, media_type: Name) -> bool {
+    is_media_file(path)
+        && mime_guess::from_path(path)
+            .first()
+            .map_or(false, |mime| mime.type_() == media_type)
+}
+
+fn validate_content(
+    content_type: &ContentType,
+    content: &str,
+    language: &Option<Language>,
+    url: &Option<String>,
+    data: &Option<serde_json::Value>,
+    violations: &mut Violations,
+) {
+    match content_type {
+        ContentType::Text => {
+            violations.check(!is_blank(content), "content", "Content cannot be empty.");
+            if let Err(message) = render_text(content) {
+                violations.check(false, "content", &message);
+            }
+        }
+        ContentType::Comment => {
+            violations.check(!is_blank(content), "content", "Content cannot be empty.")
+        }
+        ContentType::Math => {
+            violations.check(!is_blank(content), "content", "Formula cannot be empty.");
+            if let Err(message) = render_block(content) {
+                violations.check(false, "content", &message);
+            }
+        }
+        ContentType::Link => violations.check(
+            url.as_deref()
+                .map_or(false, |url| !is_blank(url) && is_safe_url(url)),
+            "url",
+            "A link should specify an http(s), mailto or relative url.",
+        ),
+        ContentType::Image => {
+            violations.check(
+                is_media_file(content),
+                "content",
+                "Image should be an existing file under media/.",
+            );
+            violations.check(
+                Image::from_data(data).map_or(false, |image| !is_blank(&image.alt)),
+                "data.alt",
+                "Image should have an alt text.",
+            );
+        }
+        ContentType::Code => {
+            violations.check(!is_blank(content), "content", "Code cannot be empty.");
+            violations.check(
+                language.is_some(),
+                "language",
+                "Code content should specify a language.",
+            );
+        }
+        ContentType::Callout => {
+            violations.check(!is_blank(content), "content", "Callout cannot be empty.");
+            violations.check(
+                data.is_none() || Callout::from_data(data).is_some(),
+                "data.kind",
+                "Callout kind should be info, tip, warning or danger.",
+            );
+        }
+        ContentType::Quote => {
+            violations.check(!is_blank(content), "content", "Quotation cannot be empty.");
+            match data.as_ref().map(|_| Quote::from_data(data)) {
+                Some(None) => violations.check(false, "data", "Invalid quote attribution."),
+                Some(Some(quote)) => violations.check(
+                    quote.source_url.as_deref().map_or(true, is_http_url),
+                    "data.source_url",
+                    "Source should be an http(s) URL.",
+                ),
+                None => {}
+            }
+        }
+        ContentType::List => match List::from_data(data) {
+            Some(list) => {
+                violations.check(
+                    !list.items.is_empty(),
+                    "data.items",
+                    "A list should have at least one item.",
+                );
+                validate_list_items(&list.items, "data.items", 1, violations);
+            }
+            None => violations.check(false, "data", "A list should have items."),
+        },
+        ContentType::Table => match Table::from_data(data) {
+            Some(table) => validate_table(&table, violations),
+            None => violations.check(
+                false,
+                "data",
+                "A table should have a header row and rows of cells.",
+            ),
+        },
+        ContentType::Video | ContentType::Audio => {
+            validate_player(content_type, content, url, data, violations)
+        }
+    }
+}
+
+// Video and Audio contents: a file under media/ or, for videos only, the URL of an external provider.
+fn validate_player(
+    content_type: &ContentType,
+    content: &str,
+    url: &Option<String>,
+    data: &Option<serde_json::Value>,
+    violations: &mut Violations,
+) {
+    let is_video = content_type == &ContentType::Video;
+    let url = url.as_deref().filter(|url| !is_blank(url));
+    match (is_blank(content), url) {
+        (false, None) if is_video => violations.check(
+            is_media_file_of(content, mime::VIDEO),
+            "content",
+            "Video should be an existing video file under media/.",
+        ),
+        (false, None) => violations.check(
+            is_media_file_of(content, mime::AUDIO),
+            "content",
+            "Audio should be an existing audio file under media/.",
+        ),
+        (true, Some(url)) if is_video => violations.check(
+            EmbedProvider::parse(url).is_some(),
+            "url",
+            "Only YouTube and Vimeo videos can be embedded.",
+        ),
+        (true, Some(_)) => violations.check(
+            false,
+            "url",
+            "Audio should be a file under media/, external players are not supported.",
+        ),
+        _ => violations.check(
+            false,
+            "content",
+            "Either a file under media/ or an external URL is needed.",
+        ),
+    }
+
+    let player = match data.as_ref().map(|_| Player::from_data(data)) {
+        Some(Some(player)) => player,
+        Some(None) => {
+            violations.check(false, "data", "Invalid poster or tracks.");
+            return;
+        }
+        None => return,
+    };
+    violations.check(
+        player
+            .poster
+            .as_deref()
+            .map_or(true, |poster| is_media_file_of(poster, mime::IMAGE)),
+        "data.poster",
+        "Poster should be an existing image under media/.",
+    );
+    violations.check(
+        url.is_none() || player.tracks.is_empty(),
+        "data.tracks",
+        "Subtitles of external videos are handled by their provider.",
+    );
+    for (index, track) in player.tracks.iter().enumerate() {
+        let path = format!("data.tracks[{}]", index);
+        violations.check(
+            track.src.ends_with(".vtt") && is_media_file(&track.src),
+            &format!("{}.src", path),
+            "Track should be an existing WebVTT (.vtt) file under media/.",
+        );
+        violations.check(
+            !is_blank(&track.srclang),
+            &format!("{}.srclang", path),
+            "Track should specify its language (\"en\").",
+        );
+        violations.check(
+            !is_blank(&track.label),
+            &format!("{}.label", path),
+            "Track should have a label.",
+        );
+    }
+}
+
+fn is_http_url(url: &str) -> bool {
+    url.starts_with("https://") || url.starts_with("http://")
+}
+
+fn validate_list_items(items: &[ListItem], path: &str, depth: usize, violations: &mut Violations) {
+    violations.check(
+        items.is_empty() || depth <= MAX_LIST_DEPTH,
+        path,
+        &format!(
+            "Lists cannot be nested more than {} levels deep.",
+            MAX_LIST_DEPTH
+        ),
+    );
+    for (index, item) in items.iter().enumerate() {
+        let path = format!("{}[{}]", path, index);
+        violations.check(
+            !is_blank(&item.text),
+            &format!("{}.text", path),
+            "List item cannot be empty.",
+        );
+        validate_list_items(
+            &item.items,
+            &format!("{}.items", path),
+            depth + 1,
+            violations,
+        );
+    }
+}
+
+fn validate_table(table: &Table, violations: &mut Violations) {
+    let columns = table.header.len();
+    violations.check(
+        columns > 0,
+        "data.header",
+        "A table should have at least one column.",
+    );
+    violations.check(
+        table.alignments.len() <= columns,
+        "data.alignments",
+        "There are more alignments than columns.",
+    );
+    for (index, row) in table.rows.iter().enumerate() {
+        violations.check(
+            row.len() == columns,
+            &format!("data.rows[{}]", index),
+            &format!("Row should have {} cells, like the header.", columns),
+        );
+    }
+}
+
+// Line numbering of Code contents.
+fn validate_lines(
+    start_line: Option<i32>,
+    ranges: [(&str, &Option<String>); 3],
+    violations: &mut Violations,
+) {
+    violations.check(
+        start_line.map_or(true, |line| line > 0),
+        "start_line",
+        "Start line should be positive.",
+    );
+    for (field, ranges) in ranges {
+        if let Some(Err(message)) = ranges.as_deref().map(str::parse::<LineRanges>) {
+            violations.check(false, field, &message);
+        }
+    }
+}
+
+impl Validate for InputContent {
+    fn validate(&self, violations: &mut Violations) {
+        violations.check(self.index >= 0, "index", "Index cannot be negative.");
+        validate_content(
+            &self.content_type,
+            &self.content,
+            &self.language,
+            &self.url,
+            &self.data,
+            violations,
+        );
+        validate_lines(
+            self.start_line,
+            [
+                ("emphasized_lines", &self.emphasized_lines),
+                ("added_lines", &self.added_lines),
+                ("removed_lines", &self.removed_lines),
+            ],
+            violations,
+        );
+    }
+}
+
+impl Validate for Content {
+    fn validate(&self, violations: &mut Violations) {
+        violations.check(self.index >= 0, "index", "Index cannot be negative.");
+        validate_content(
+            &self.content_type,
+            &self.content,
+            &self.language,
+            &self.url,
+            &self.data,
+            violations,
+        );
+        validate_lines(
+            self.start_line,
+            [
+                ("emphasized_lines", &self.emphasized_lines),
+                ("added_lines", &self.added_lines),
+                ("removed_lines", &self.removed_lines),
+            ],
+            violations,
+        );
+    }
+}
+
+impl Validate for InputChapter {
+    fn validate(&self, violations: &mut Violations) {
+        violations.check(!is_blank(&self.title), "title", "Title cannot be empty.");
+        violations.check(self.index >= 0, "index", "Index cannot be negative.");
+        violations.check(
+            is_contiguous(self.contents.iter().map(|content| content.index)),
+            "contents",
+            "Content indexes should be contiguous, starting at 0.",
+        );
+        for (index, content) in self.contents.iter().enumerate() {
+            violations.nested("contents", index, content);
+        }
+    }
+}
+
+impl Validate for Chapter {
+    fn validate(&self, violations: &mut Violations) {
+        violations.check(!is_blank(&self.title), "title", "Title cannot be empty.");
+        violations.check(self.index >= 0, "index", "Index cannot be negative.");
+    }
+}
+
+fn validate_reference(key: &str, title: &str, url: &Option<String>, violations: &mut Violations) {
+    violations.check(
+        !key.is_empty() && key.chars().all(is_key_char),
+        "key",
+        "Key should only contain letters, digits, \"-\" and \"_\".",
+    );
+    violations.check(!is_blank(title), "title", "Title cannot be empty.");
+    violations.check(
+        url.as_deref()
+            .map_or(true, |url| is_blank(url) || is_http_url(url)),
+        "url",
+        "URL should be an http(s) URL.",
+    );
+}
+
+fn validate_article_image(image: &str, image_alt: &str, violations: &mut Violations) {
+    violations.check(
+        is_blank(image) || is_media_file(image),
+        "image",
+        "Image should be an existing file under media/.",
+    );
+    violations.check(
+        is_blank(image) || !is_blank(image_alt),
+        "image_alt",
+        "Image should have an alt text.",
+    );
+}
+
+impl Validate for InputReference {
+    fn validate(&self, violations: &mut Violations) {
+        validate_reference(&self.key, &self.title, &self.url, violations);
+        violations.check(self.index >= 0, "index", "Index cannot be negative.");
+    }
+}
+
+impl Validate for InputReferenceUpdate {
+    fn validate(&self, violations: &mut Violations) {
+        validate_reference(&self.key, &self.title, &self.url, violations);
+    }
+}
+
+// An article scheduled for both cannot be taken down before it goes up.
+fn validate_schedule(
+    publish_at: Option<chrono::NaiveDateTime>,
+    unpublish_at: Option<chrono::NaiveDateTime>,
+    violations: &mut Violations,
+) {
+    if let (Some(publish_at), Some(unpublish_at)) = (publish_at, unpublish_at) {
+        violations.check(
+            unpublish_at > publish_at,
+            "unpublish_at",
+            "Unpublication should come after the publication.",
+        );
+    }
+}
+
+impl Validate for InputScheduleArticle {
+    fn validate(&self, violations: &mut Violations) {
+        validate_schedule(self.publish_at, self.unpublish_at, violations);
+    }
+}
+
+impl Validate for InputSeries {
+    fn validate(&self, violations: &mut Violations) {
+        violations.check(!is_blank(&self.title), "title", "Title cannot be empty.");
+    }
+}
+
+impl Validate for InputSeriesParts {
+    fn validate(&self, violations: &mut Violations) {
+        let mut ids = HashSet::with_capacity(self.articles.len());
+        violations.check(
+            self.articles.iter().all(|id| ids.insert(*id)),
+            "articles",
+            "An article can only be part of a series once.",
+        );
+    }
+}
+
+// Up to 30 days.
+const MAX_PREVIEW_HOURS: i64 = 30 * 24;
+
+impl Validate for InputPreviewLink {
+    fn validate(&self, violations: &mut Violations) {
+        violations.check(
+            (1..=MAX_PREVIEW_HOURS).contains(&self.hours),
+            "hours",
+            "A preview link should be valid from 1 hour to 30 days.",
+        );
+    }
+}
+
+impl Validate for InputArticle {
+    fn validate(&self, violations: &mut Violations) {
+        violations.check(!is_blank(&self.title), "title", "Title cannot be empty.");
+        validate_article_image(&self.image, &self.image_alt, violations);
+        validate_schedule(self.publish_at, self.unpublish_at, violations);
+        violations.check(
+            is_contiguous(self.chapters.iter().map(|chapter| chapter.index)),
+            "chapters",
+            "Chapter indexes should be contiguous, starting at 0.",
+        );
+        for (index, chapter) in self.chapters.iter().enumerate() {
+            violations.nested("chapters", index, chapter);
+        }
+        violations.check(
+            is_contiguous(self.references.iter().map(|reference| reference.index)),
+            "references",
+            "Reference indexes should be contiguous, starting at 0.",
+        );
+        let mut keys = HashSet::new();
+        for (index, reference) in self.references.iter().enumerate() {
+            violations.check(
+                keys.insert(reference.key.as_str()),
+                &format!("references[{}].key", index),
+                "Another reference already uses this key.",
+            );
+            violations.nested("references", index, reference);
+        }
+    }
+}
+
+impl Validate for Article {
+    fn validate(&self, violations: &mut Violations) {
+        violations.check(!is_blank(&self.title), "title", "Title cannot be empty.");
+        validate_article_image(&self.image, &self.image_alt, violations);
+    }
+}
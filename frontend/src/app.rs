@@ -1,10 +1,16 @@
 use {
     crate::{
         components::{footer::Footer, header::Header, page_not_found::PageNotFound},
-        entities::interfaces::{IArticle, IProject, ITag, SearchResults},
+        entities::interfaces::{IArticle, IProject, ITag, SearchResults, Status},
         routes::{
-            about::About, article::Article, articles::Articles, projects::Projects, tag::Tag,
-            tags::Tags, AppRoute,
+            about::About,
+            article::Article,
+            articles::Articles,
+            projects::Projects,
+            series::{Series, SeriesList},
+            tag::Tag,
+            tags::Tags,
+            AppRoute,
         },
         store::store::{reducer, Action, BlogStore},
     },
@@ -17,6 +23,9 @@ use {
 #[cfg(feature = "editable")]
 use crate::routes::new_article::NewArticle;
 
+#[cfg(not(feature = "editable"))]
+use crate::routes::preview::Preview;
+
 #[function_component(App)]
 pub fn app() -> Html {
     type BlogStoreContextProvider = ContextProvider<Rc<BlogStore>>;
@@ -71,9 +80,9 @@ pub fn app() -> Html {
             dispatch(Action::SetTags(values));
         })
     };
-    let dispatch_error: Callback<bool> = {
-        Callback::from(move |value: bool| {
-            dispatch(Action::SetError(value));
+    let dispatch_error: Callback<Status> = {
+        Callback::from(move |status: Status| {
+            dispatch(Action::SetError(status));
         })
     };
 
@@ -98,6 +107,13 @@ pub fn app() -> Html {
                                         dispatch_error={dispatch_error.clone()}
                                     />
                                 },
+                                #[cfg(not(feature = "editable"))]
+                                AppRoute::Preview { token } => html! {
+                                    <Preview
+                                        token={token}
+                                        dispatch_error={dispatch_error.clone()}
+                                    />
+                                },
                                 AppRoute::Article { id } => html! {
                                     <Article
                                         id={id}
@@ -124,6 +140,12 @@ pub fn app() -> Html {
                                         dispatch_error={dispatch_error.clone()}
                                     />
                                 },
+                                AppRoute::Series { id } => html! {
+                                    <Series id={id} dispatch_error={dispatch_error.clone()} />
+                                },
+                                AppRoute::SeriesList => html! {
+                                    <SeriesList dispatch_error={dispatch_error.clone()} />
+                                },
                                 AppRoute::Projects => html! {
                                     <Projects
                                         dispatch_projects={dispatch_projects.clone()}
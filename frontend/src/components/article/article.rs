@@ -1,19 +1,26 @@
 use {
-    super::{article_header::ArticleHeader, chapter_list::ChapterList, chapters::Chapters},
+    super::{
+        article_header::ArticleHeader, chapter_list::ChapterList, chapters::Chapters,
+        references::References, series::SeriesBox,
+    },
     crate::entities::{
         action::Action,
-        interfaces::{IArticle, IArticleHeader},
+        interfaces::{IArticle, IArticleHeader, Status},
     },
     std::rc::Rc,
     yew::{html, Callback, Properties},
     yew_functional::function_component,
 };
 
+#[cfg(not(feature = "editable"))]
+use super::related::Related;
+
 #[cfg(feature = "editable")]
 use {
+    super::{history::History, preview_link::PreviewLink, schedule::Schedule},
     crate::{
         components::{button::Button, loader::Loader, switch::Switch},
-        entities::interfaces::{IPublishArticle, Status},
+        entities::interfaces::IPublishArticle,
         hooks::use_effect_except_on_mount::use_effect_except_on_mount,
         routes::AppRoute,
         service::{
@@ -36,12 +43,18 @@ pub struct ArticleProps {
     #[prop_or_default]
     pub dispatch_article: Callback<IArticle>,
     #[prop_or_default]
-    pub dispatch_error: Callback<bool>,
+    pub dispatch_error: Callback<Status>,
 }
 
 #[cfg(not(feature = "editable"))]
 #[function_component(Article)]
-pub fn article(ArticleProps { article, .. }: &ArticleProps) -> Html {
+pub fn article(
+    ArticleProps {
+        article,
+        dispatch_error,
+        ..
+    }: &ArticleProps,
+) -> Html {
     html! {
         <div style="display: flex; flex: 1;">
             <div style="flex: 1" />
@@ -57,6 +70,7 @@ pub fn article(ArticleProps { article, .. }: &ArticleProps) -> Html {
                                     published: article.published,
                                     headline: article.headline.clone(),
                                     image: article.image.clone(),
+                                    image_alt: article.image_alt.clone(),
                                     image_credits: article.image_credits.clone(),
                                     tags: article.tags.clone(),
                                     updated: article.updated.clone(),
@@ -64,12 +78,18 @@ pub fn article(ArticleProps { article, .. }: &ArticleProps) -> Html {
                             )
                         }
                     />
+                    {match &article.series {
+                        Some(series) => html! { <SeriesBox series={series.clone()} /> },
+                        None => html! {},
+                    }}
                     <div>
                         <ChapterList chapters={&article.chapters} />
                         <Chapters
                             chapters={Rc::new(article.chapters.clone())}
                             article_id={article.id} />
+                        <References references={Rc::new(article.references.clone())} />
                     </div>
+                    <Related article_id={article.id} dispatch_error={dispatch_error} />
                 </div>
             </div>
             <div style="flex: 1;">
@@ -132,7 +152,7 @@ pub fn article(
                                 (AppRoute::Article { id: article.id }).into(),
                             ));
                         }
-                        Err(_) => dispatch_error.emit(true),
+                        Err(status) => dispatch_error.emit(status),
                     };
                     set_loading(false);
                 });
@@ -177,7 +197,7 @@ pub fn article(
                 handle_future(future, move |data: Result<IArticle, Status>| {
                     match data {
                         Ok(article) => dispatch_article.emit(article),
-                        Err(_) => dispatch_error.emit(true),
+                        Err(status) => dispatch_error.emit(status),
                     };
                     set_loading(false);
                 });
@@ -220,6 +240,22 @@ pub fn article(
                             />
                         </div>
                     </div>
+                    {match article_action {
+                        Action::Edit if !article.published => html! {
+                            <PreviewLink article_id={article.id} dispatch_error={dispatch_error} />
+                        },
+                        _ => html! {},
+                    }}
+                    {match article_action {
+                        Action::Edit => html! {
+                            <Schedule
+                                article={Rc::new(article.clone())}
+                                dispatch_article={dispatch_article}
+                                dispatch_error={dispatch_error}
+                            />
+                        },
+                        Action::Add => html! {},
+                    }}
                     <ArticleHeader
                         article_action={article_action}
                         article_header={
@@ -231,6 +267,7 @@ pub fn article(
                                     published: article.published,
                                     headline: article.headline.clone(),
                                     image: article.image.clone(),
+                                    image_alt: article.image_alt.clone(),
                                     image_credits: article.image_credits.clone(),
                                     tags: article.tags.clone(),
                                     updated: article.updated.clone(),
@@ -240,6 +277,10 @@ pub fn article(
                         dispatch_article={dispatch_article}
                         dispatch_error={dispatch_error}
                     />
+                    {match &article.series {
+                        Some(series) => html! { <SeriesBox series={series.clone()} /> },
+                        None => html! {},
+                    }}
                     <div>
                         <div style="margin-bottom: 16px;">
                             <ChapterList chapters={&article.chapters} />
@@ -251,6 +292,23 @@ pub fn article(
                             dispatch_article={dispatch_article}
                             dispatch_error={dispatch_error}
                         />
+                        <References
+                            references={Rc::new(article.references.clone())}
+                            article_action={article_action}
+                            article_id={article.id}
+                            dispatch_article={dispatch_article}
+                            dispatch_error={dispatch_error}
+                        />
+                        {match article_action {
+                            Action::Edit => html! {
+                                <History
+                                    article={Rc::new(article.clone())}
+                                    dispatch_article={dispatch_article}
+                                    dispatch_error={dispatch_error}
+                                />
+                            },
+                            Action::Add => html! {},
+                        }}
                     </div>
                     {if *is_loading {
                         html! {
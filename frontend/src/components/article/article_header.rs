@@ -3,20 +3,23 @@ use {
         components::tag_label::TagLabel,
         entities::{
             action::Action,
-            interfaces::{IArticle, IArticleHeader},
+            interfaces::{IArticle, IArticleHeader, Status},
         },
         utils::date::format_date,
     },
     std::rc::Rc,
-    yew::{html, Callback, Properties},
+    yew::{html, Callback, Html, Properties},
     yew_functional::function_component,
 };
 
 #[cfg(feature = "editable")]
 use {
     crate::{
-        components::{button::Button, loader::Loader, text_area::TextArea},
-        entities::interfaces::Status,
+        components::{
+            button::Button, field_errors::FieldErrors, loader::Loader, text_area::TextArea,
+            text_input::TextInput,
+        },
+        entities::interfaces::IFieldError,
         service::{articles::update_article_header, future::handle_future},
         store::store::BlogStore,
         utils::date::get_current_date,
@@ -36,7 +39,20 @@ pub struct ArticleHeaderProps {
     #[prop_or_default]
     pub dispatch_article: Callback<IArticle>,
     #[prop_or_default]
-    pub dispatch_error: Callback<bool>,
+    pub dispatch_error: Callback<Status>,
+}
+
+fn view_image(article_header: &IArticleHeader) -> Html {
+    html! {
+        <figure style="display: flex; flex: 1; flex-direction: column; margin: 0;">
+            <img src={&article_header.image} alt={&article_header.image_alt} style="width: 100%; object-fit: contain;"/>
+            {article_header.image_credits.as_ref().filter(|credits| !credits.is_empty()).map_or_else(|| html! {}, |credits| html! {
+                <figcaption style="font-style: italic; font-size: .8rem; margin-top: 4px;">
+                    {credits}
+                </figcaption>
+            })}
+        </figure>
+    }
 }
 
 #[cfg(not(feature = "editable"))]
@@ -63,14 +79,7 @@ pub fn article_header(ArticleHeaderProps { article_header, .. }: &ArticleHeaderP
                 )}
             </div>
             <div style="margin-top: 8px; margin-bottom: 12px;">
-                <div style="display: flex; flex: 1; flex-direction: column;">
-                    <img src={&article_header.image} style="width: 100%; object-fit: contain;"/>
-                    {article_header.image_credits.as_ref().map_or_else(|| html! {}, |credits| html! {
-                        <p style="font-style: italic; font-size: .8rem; margin-top: 4px;">
-                            {credits}
-                        </p>
-                    })}
-                </div>
+                {view_image(article_header)}
             </div>
             <div style="margin-top: 8px; margin-bottom: 8px;">
                 <h3 class="comment">{&article_header.headline}</h3>
@@ -97,6 +106,7 @@ pub fn article_header(
     let (is_image_edited, set_image_edited) = use_state(move || edited);
 
     let (is_loading, set_loading) = use_state(|| false);
+    let (field_errors, set_field_errors) = use_state(Vec::<IFieldError>::new);
 
     let (form, update_form) = {
         let article_header = article_header.clone();
@@ -190,6 +200,28 @@ pub fn article_header(
             set_image_edited(true);
         })
     };
+    let on_change_image_alt: Callback<ChangeData> = {
+        let (form, update_form) = (form.clone(), update_form.clone());
+        Callback::from(move |event: ChangeData| {
+            if let ChangeData::Value(image_alt) = event {
+                update_form(IArticleHeader {
+                    image_alt,
+                    ..(*form).clone()
+                });
+            }
+        })
+    };
+    let on_change_image_credits: Callback<ChangeData> = {
+        let (form, update_form) = (form.clone(), update_form.clone());
+        Callback::from(move |event: ChangeData| {
+            if let ChangeData::Value(image_credits) = event {
+                update_form(IArticleHeader {
+                    image_credits: Some(image_credits).filter(|credits| !credits.trim().is_empty()),
+                    ..(*form).clone()
+                });
+            }
+        })
+    };
     let on_change_image: Callback<ChangeData> = {
         let form = form.clone();
         Callback::from(move |event: ChangeData| {
@@ -241,6 +273,8 @@ pub fn article_header(
                         published: form.published,
                         headline: form.headline.clone(),
                         image: form.image.clone(),
+                        image_alt: form.image_alt.clone(),
+                        image_credits: form.image_credits.clone(),
                         tags: form.tags.clone(), // TODO
                         ..article
                     });
@@ -255,12 +289,23 @@ pub fn article_header(
                         })
                         .await
                     };
+                    let set_field_errors = set_field_errors.clone();
                     handle_future(future, move |response: Result<IArticle, Status>| {
                         match response {
-                            Ok(article) => dispatch_article.emit(article),
-                            Err(_) => dispatch_error.emit(true),
+                            Ok(article) => {
+                                set_field_errors(vec![]);
+                                dispatch_article.emit(article);
+                                set_edited(false);
+                            }
+                            // Keep the form open so that the messages are displayed next to the fields.
+                            Err(Status::Failure(error)) if !error.fields.is_empty() => {
+                                set_field_errors(error.fields)
+                            }
+                            Err(status) => {
+                                dispatch_error.emit(status);
+                                set_edited(false);
+                            }
                         };
-                        set_edited(false);
                         set_loading(false);
                     });
                 }
@@ -285,6 +330,7 @@ pub fn article_header(
                 html! {
                     <>
                         <TextArea rows={2} value={&form.title} onchange={on_change_title} />
+                        <FieldErrors errors={field_errors.clone()} field="title" />
                         <div style="display: flex; margin-top: 4px; margin-bottom: 4px; justify-content: flex-end; font-size: .8em;">
                             <>
                                 <Button onclick={&on_cancel_edit_title} label="Cancel"/>
@@ -330,6 +376,18 @@ pub fn article_header(
                     html! {
                         <>
                             <TextArea rows={1} value={&form.image} onchange={on_change_image} />
+                        <FieldErrors errors={field_errors.clone()} field="image" />
+                            <TextInput
+                                placeholder="Alt text, describing the image to screen readers"
+                                value={form.image_alt.clone()}
+                                onchange={on_change_image_alt}
+                            />
+                        <FieldErrors errors={field_errors.clone()} field="image_alt" />
+                            <TextInput
+                                placeholder="Credits"
+                                value={form.image_credits.clone().unwrap_or_default()}
+                                onchange={on_change_image_credits}
+                            />
                             <div style="display: flex; margin-top: 4px; margin-bottom: 4px; justify-content: flex-end; font-size: .8em;">
                             <>
                                 <Button onclick={&on_cancel_edit_image} label="Cancel"/>
@@ -346,14 +404,7 @@ pub fn article_header(
                                 style="width:42px; height:42px; display: flex; justify-content: center; align-items:center; position: absolute; right: -78px; cursor: pointer;">
                                 <i class="fa fa-edit"></i>
                             </div>
-                            <div style="display: flex; flex: 1;">
-                                <img src={&article_header.image} style="width: 100%;" />
-                                {article_header.image_credits.as_ref().map_or_else(|| html! {}, |credits| html! {
-                                    <p style="font-style: italic; font-size: .8rem; margin-top: 4px;">
-                                        {credits}
-                                    </p>
-                                })}
-                            </div>
+                            {view_image(article_header)}
                         </div>
                     }
                 }}
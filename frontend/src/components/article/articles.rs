@@ -7,7 +7,10 @@ use {
 };
 
 #[cfg(feature = "editable")]
-use {crate::routes::AppRoute, yew_router::components::RouterAnchor};
+use {
+    super::schedule::ScheduledArticles, crate::routes::AppRoute,
+    yew_router::components::RouterAnchor,
+};
 
 #[derive(Properties, Clone, PartialEq)]
 pub struct ArticlesProps {
@@ -60,6 +63,7 @@ pub fn articles(ArticlesProps { articles }: &ArticlesProps) -> Html {
                         </div>
                     </RouterAnchor<AppRoute>>
                 </div>
+                <ScheduledArticles />
                 {for articles.iter().map(move |(_, article)| {
                     html! {
                         <div style="margin-bottom: 12px;">
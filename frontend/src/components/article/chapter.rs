@@ -2,7 +2,7 @@ use {
     super::contents::Contents,
     crate::entities::{
         action::Action,
-        interfaces::{IArticle, IChapter},
+        interfaces::{IArticle, IChapter, Status},
     },
     std::rc::Rc,
     yew::{html, Callback, Properties},
@@ -14,17 +14,19 @@ use {
     crate::{
         components::{
             button::{Button, ButtonVariant},
+            field_errors::FieldErrors,
             loader::Loader,
             text_area::TextArea,
         },
-        entities::interfaces::Status,
+        entities::interfaces::IFieldError,
         service::{
             articles::{add_chapter, delete_chapter, get_article, update_chapter},
             future::handle_future,
         },
         store::store::BlogStore,
+        utils::drag::{on_drag_chapter, on_drag_over, on_drop, DropTarget},
     },
-    yew::{ChangeData, MouseEvent},
+    yew::{ChangeData, DragEvent, MouseEvent},
     yew_functional::{use_context, use_effect_with_deps, use_state},
 };
 
@@ -42,7 +44,7 @@ pub struct ChapterProps {
     #[prop_or_default]
     pub dispatch_article: Callback<IArticle>,
     #[prop_or_default]
-    pub dispatch_error: Callback<bool>,
+    pub dispatch_error: Callback<Status>,
 }
 
 #[cfg(not(feature = "editable"))]
@@ -73,6 +75,7 @@ pub fn chapter(
 ) -> Html {
     let contents = &chapter.contents;
     let (is_loading, set_loading) = use_state(|| false);
+    let (field_errors, set_field_errors) = use_state(Vec::<IFieldError>::new);
 
     // Chapter form (which only consists of a title)
     let (chapter_title, set_chapter_title) = {
@@ -148,12 +151,12 @@ pub fn chapter(
                             handle_future(future, move |data: Result<IArticle, Status>| {
                                 match data {
                                     Ok(article) => dispatch_article.emit(article),
-                                    Err(_) => dispatch_error.emit(true),
+                                    Err(status) => dispatch_error.emit(status),
                                 };
                                 set_loading(false);
                             });
                         }
-                        Err(_) => dispatch_error.emit(true),
+                        Err(status) => dispatch_error.emit(status),
                     },
                 );
             }
@@ -200,7 +203,9 @@ pub fn chapter(
             on_edit.clone(),
         );
         let context = use_context::<Rc<BlogStore>>().expect("Could not find context!");
+        let set_field_errors = set_field_errors.clone();
         Callback::from(move |_| {
+            let set_field_errors = set_field_errors.clone();
             let (chapter, set_loading, dispatch_article, dispatch_error, on_edit) = (
                 chapter.clone(),
                 set_loading.clone(),
@@ -219,8 +224,14 @@ pub fn chapter(
                             handle_future(future, move |data: Result<IArticle, Status>| {
                                 let set_loading = set_loading.clone();
                                 match data {
-                                    Ok(article) => dispatch_article.emit(article),
-                                    Err(_) => dispatch_error.emit(true),
+                                    Ok(article) => {
+                                        set_field_errors(vec![]);
+                                        dispatch_article.emit(article);
+                                    }
+                                    Err(Status::Failure(error)) if !error.fields.is_empty() => {
+                                        set_field_errors(error.fields)
+                                    }
+                                    Err(status) => dispatch_error.emit(status),
                                 };
                                 set_loading(false);
                             });
@@ -229,11 +240,21 @@ pub fn chapter(
                             let future = async move { update_chapter(&chapter).await };
                             handle_future(future, move |data: Result<IArticle, Status>| {
                                 match data {
-                                    Ok(article) => dispatch_article.emit(article),
-                                    Err(_) => dispatch_error.emit(true),
+                                    Ok(article) => {
+                                        set_field_errors(vec![]);
+                                        dispatch_article.emit(article);
+                                        on_edit.emit((chapter_index, false));
+                                    }
+                                    // Keep the form open so that the messages are displayed next to the title.
+                                    Err(Status::Failure(error)) if !error.fields.is_empty() => {
+                                        set_field_errors(error.fields)
+                                    }
+                                    Err(status) => {
+                                        dispatch_error.emit(status);
+                                        on_edit.emit((chapter_index, false));
+                                    }
                                 };
                                 set_loading(false);
-                                on_edit.emit((chapter_index, false));
                             });
                         }
                     }
@@ -272,12 +293,38 @@ pub fn chapter(
         })
     };
 
+    // Only the saved chapters of an existing article can be dragged.
+    let movable = *article_action == Action::Edit && *action == Action::Edit && !*edited;
+    let (on_drag_start, on_drag_over, on_drop): (
+        Callback<DragEvent>,
+        Callback<DragEvent>,
+        Callback<DragEvent>,
+    ) = if movable {
+        (
+            on_drag_chapter(chapter.id),
+            on_drag_over(),
+            on_drop(
+                DropTarget::Chapter {
+                    article_id: chapter.article_id,
+                    chapter_id: chapter.id,
+                    index: chapter.index,
+                },
+                dispatch_article.clone(),
+                dispatch_error.clone(),
+            ),
+        )
+    } else {
+        Default::default()
+    };
+
     html! {
         <div>
             {if *edited {
                 html! {
                     <div>
                         <TextArea rows={2} value={&*chapter_title} onchange={on_change_chapter_title} />
+                        <FieldErrors errors={field_errors.clone()} field="title" />
+                        <FieldErrors errors={field_errors.clone()} field="index" />
                         <div style="display: flex; margin-top: 4px; margin-bottom: 4px; justify-content: flex-end; font-size: .8em;">
                         {match action {
                             Action::Edit => html! {
@@ -292,7 +339,12 @@ pub fn chapter(
                 }
             } else {
                 html! {
-                    <div style="align-items: center; position: relative; display: flex; margin-top: 8px; margin-bottom: 8px;">
+                    <div
+                        draggable={movable.to_string()}
+                        ondragstart={on_drag_start}
+                        ondragover={on_drag_over}
+                        ondrop={on_drop}
+                        style="align-items: center; position: relative; display: flex; margin-top: 8px; margin-bottom: 8px;">
                         <div
                             onclick={on_edit_chapter}
                             style="width:42px; height:42px; display: flex; justify-content: center; align-items: center; position: absolute; right:-78px; cursor: pointer;">
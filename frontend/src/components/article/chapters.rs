@@ -2,7 +2,7 @@ use {
     super::chapter::Chapter,
     crate::entities::{
         action::Action,
-        interfaces::{IArticle, IChapter},
+        interfaces::{IArticle, IChapter, Status},
     },
     std::rc::Rc,
     yew::{html, Callback, Properties},
@@ -25,7 +25,7 @@ pub struct ChaptersProps {
     #[prop_or_default]
     pub dispatch_article: Callback<IArticle>,
     #[prop_or_default]
-    pub dispatch_error: Callback<bool>,
+    pub dispatch_error: Callback<Status>,
 }
 
 #[cfg(not(feature = "editable"))]
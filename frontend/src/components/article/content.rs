@@ -1,14 +1,29 @@
 use {
     crate::{
-        components::code::Code,
+        components::{
+            callout::Callout,
+            code::Code,
+            image::Image,
+            list::List,
+            player::{Audio, Video},
+            quote::Quote,
+            table::Table,
+        },
         entities::{
             action::Action,
+            callout::ICallout,
             content_type::ContentType,
-            interfaces::{IArticle, IContent},
+            image::IImage,
+            interfaces::{IArticle, IContent, Status},
+            list::IList,
+            player::IPlayer,
+            quote::IQuote,
+            table::ITable,
         },
+        utils::html::raw_html,
     },
     std::rc::Rc,
-    yew::{html, Callback, Properties},
+    yew::{html, Callback, Html, Properties},
     yew_functional::function_component,
 };
 
@@ -17,13 +32,18 @@ use {
     crate::{
         components::{
             button::{Button, ButtonVariant},
+            checkbox::CheckBox,
+            field_errors::FieldErrors,
             loader::Loader,
             select::Select,
+            table_editor::TableEditor,
             text_area::TextArea,
+            text_input::TextInput,
         },
         entities::{
+            callout::{CalloutKind, CALLOUT_KINDS},
             content_type::CONTENT_TYPES,
-            interfaces::Status,
+            interfaces::IFieldError,
             language::{Language, LANGUAGES},
         },
         service::{
@@ -31,12 +51,95 @@ use {
             future::handle_future,
         },
         store::store::BlogStore,
+        utils::drag::{on_drag_content, on_drag_over, on_drop, DropTarget},
         API_URL,
     },
-    yew::{ChangeData, MouseEvent},
+    yew::{ChangeData, DragEvent, MouseEvent},
     yew_functional::{use_context, use_effect_with_deps, use_state},
 };
 
+#[cfg(feature = "editable")]
+fn non_empty(value: String) -> Option<String> {
+    if value.trim().is_empty() {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+// Inline formatting and math are rendered (and sanitized) by the API.
+fn view_text(content: &IContent) -> Html {
+    match &content.rendered_html {
+        Some(rendered_html) => html! {
+            <div class="rich-text" style="white-space: break-spaces;">{raw_html(rendered_html)}</div>
+        },
+        None => html! {
+            <p style="white-space: break-spaces;">{&content.content}</p>
+        },
+    }
+}
+
+// MathML rendered by the API, the LaTeX source is shown until it is.
+fn view_math(content: &IContent) -> Html {
+    html! {
+        <div class="math-content">
+            {match &content.rendered_html {
+                Some(rendered_html) => raw_html(rendered_html),
+                None => html! { <code>{&content.content}</code> },
+            }}
+        </div>
+    }
+}
+
+fn view_image(content: &IContent) -> Html {
+    html! {
+        <Image
+            src={content.content.clone()}
+            image={Rc::new(IImage::from_data(&content.data))}
+            caption={content.caption.clone()}
+        />
+    }
+}
+
+fn view_callout(content: &IContent) -> Html {
+    html! {
+        <Callout kind={ICallout::from_data(&content.data).kind} text={content.content.clone()} />
+    }
+}
+
+fn view_quote(content: &IContent) -> Html {
+    html! {
+        <Quote quote={Rc::new(IQuote::from_data(&content.data))} text={content.content.clone()} />
+    }
+}
+
+fn view_video(content: &IContent) -> Html {
+    html! {
+        <Video
+            src={content.content.clone()}
+            url={content.url.clone().filter(|url| !url.is_empty())}
+            player={Rc::new(IPlayer::from_data(&content.data))}
+            caption={content.caption.clone()}
+        />
+    }
+}
+
+fn view_audio(content: &IContent) -> Html {
+    html! {
+        <Audio
+            src={content.content.clone()}
+            player={Rc::new(IPlayer::from_data(&content.data))}
+            caption={content.caption.clone()}
+        />
+    }
+}
+
+fn view_list(content: &IContent) -> Html {
+    html! {
+        <List list={Rc::new(IList::from_data(&content.data))} />
+    }
+}
+
 #[derive(Properties, Clone, PartialEq)]
 pub struct ContentProps {
     pub content: Rc<IContent>,
@@ -51,7 +154,7 @@ pub struct ContentProps {
     #[prop_or_default]
     pub dispatch_article: Callback<IArticle>,
     #[prop_or_default]
-    pub dispatch_error: Callback<bool>,
+    pub dispatch_error: Callback<Status>,
 }
 
 #[cfg(not(feature = "editable"))]
@@ -60,9 +163,7 @@ pub fn content(ContentProps { content, .. }: &ContentProps) -> Html {
     html! {
         <div style="align-items: center; position: relative; display: flex; margin-bottom: 24px;">
             {match &content.content_type {
-                ContentType::Text => html! {
-                    <p style="white-space: break-spaces;">{&content.content}</p>
-                },
+                ContentType::Text => view_text(content),
                 ContentType::Comment => html! {
                     <p style="white-space: break-spaces; font-style: italic">{&content.content}</p>
                 },
@@ -76,19 +177,31 @@ pub fn content(ContentProps { content, .. }: &ContentProps) -> Html {
                 },
                 ContentType::Code => html! {
                     <div style="max-width: 100vw; display: flex; flex: 1; overflow-x: auto;">
-                        <Code highlighted_code={match &content.highlighted_code {
-                            Some(code) => code,
-                            None => &content.content,
-                        }}/>
+                        <Code
+                            highlighted_code={match &content.highlighted_code {
+                                Some(code) => code,
+                                None => &content.content,
+                            }}
+                            content={&content.content}
+                            language={content.language.clone()}
+                            caption={content.caption.clone()}
+                            start_line={content.start_line.unwrap_or(1)}
+                            added_lines={content.added_lines.clone().unwrap_or_default()}
+                            removed_lines={content.removed_lines.clone().unwrap_or_default()}
+                        />
                     </div>
                 },
-                ContentType::Image => html! {
-                    <div style="display: flex; justify-content: center;">
-                        <img src={&content.content} style="width: 75%; height: 100%; object-fit: contain;" />
-                    </div>
-                    }
-                }
-            }
+                ContentType::Image => view_image(content),
+                ContentType::Table => html! {
+                    <Table table={Rc::new(ITable::from_data(&content.data))} />
+                },
+                ContentType::Math => view_math(content),
+                ContentType::Callout => view_callout(content),
+                ContentType::Quote => view_quote(content),
+                ContentType::List => view_list(content),
+                ContentType::Video => view_video(content),
+                ContentType::Audio => view_audio(content),
+            }}
         </div>
     }
 }
@@ -109,6 +222,7 @@ pub fn content(
     let content = content.clone();
 
     let (is_loading, set_loading) = use_state(|| false);
+    let (field_errors, set_field_errors) = use_state(Vec::<IFieldError>::new);
     let (form, update_form) = {
         let content = (*content).clone();
         use_state(move || content)
@@ -165,6 +279,205 @@ pub fn content(
         })
     };
 
+    // Table
+    let on_change_content_table: Callback<ITable> = {
+        let (form, update_form) = (form.clone(), update_form.clone());
+        Callback::from(move |table: ITable| {
+            update_form(IContent {
+                data: serde_json::to_value(table).ok(),
+                ..(*form).clone()
+            });
+        })
+    };
+    // Callout
+    let on_change_callout_kind: Callback<CalloutKind> = {
+        let (form, update_form) = (form.clone(), update_form.clone());
+        Callback::from(move |kind| {
+            update_form(IContent {
+                data: serde_json::to_value(ICallout { kind }).ok(),
+                ..(*form).clone()
+            });
+        })
+    };
+    // Quote, empty inputs are sent as null.
+    let on_change_quote_attribution: Callback<ChangeData> = {
+        let (form, update_form) = (form.clone(), update_form.clone());
+        Callback::from(move |event: ChangeData| {
+            if let ChangeData::Value(attribution) = event {
+                update_form(IContent {
+                    data: serde_json::to_value(IQuote {
+                        attribution: non_empty(attribution),
+                        ..IQuote::from_data(&form.data)
+                    })
+                    .ok(),
+                    ..(*form).clone()
+                });
+            }
+        })
+    };
+    let on_change_quote_source_url: Callback<ChangeData> = {
+        let (form, update_form) = (form.clone(), update_form.clone());
+        Callback::from(move |event: ChangeData| {
+            if let ChangeData::Value(source_url) = event {
+                update_form(IContent {
+                    data: serde_json::to_value(IQuote {
+                        source_url: non_empty(source_url),
+                        ..IQuote::from_data(&form.data)
+                    })
+                    .ok(),
+                    ..(*form).clone()
+                });
+            }
+        })
+    };
+    // List
+    let on_change_list_ordered: Callback<bool> = {
+        let (form, update_form) = (form.clone(), update_form.clone());
+        Callback::from(move |ordered| {
+            update_form(IContent {
+                data: serde_json::to_value(IList {
+                    ordered,
+                    ..IList::from_data(&form.data)
+                })
+                .ok(),
+                ..(*form).clone()
+            });
+        })
+    };
+    let on_change_list_outline: Callback<ChangeData> = {
+        let (form, update_form) = (form.clone(), update_form.clone());
+        Callback::from(move |event: ChangeData| {
+            if let ChangeData::Value(outline) = event {
+                update_form(IContent {
+                    data: serde_json::to_value(IList {
+                        items: IList::items_from_outline(&outline),
+                        ..IList::from_data(&form.data)
+                    })
+                    .ok(),
+                    ..(*form).clone()
+                });
+            }
+        })
+    };
+    // Image
+    let on_change_image_alt: Callback<ChangeData> = {
+        let (form, update_form) = (form.clone(), update_form.clone());
+        Callback::from(move |event: ChangeData| {
+            if let ChangeData::Value(alt) = event {
+                update_form(IContent {
+                    data: serde_json::to_value(IImage {
+                        alt,
+                        ..IImage::from_data(&form.data)
+                    })
+                    .ok(),
+                    ..(*form).clone()
+                });
+            }
+        })
+    };
+    let on_change_image_credits: Callback<ChangeData> = {
+        let (form, update_form) = (form.clone(), update_form.clone());
+        Callback::from(move |event: ChangeData| {
+            if let ChangeData::Value(credits) = event {
+                update_form(IContent {
+                    data: serde_json::to_value(IImage {
+                        credits: non_empty(credits),
+                        ..IImage::from_data(&form.data)
+                    })
+                    .ok(),
+                    ..(*form).clone()
+                });
+            }
+        })
+    };
+    // Video and audio, paths are entered without the API URL.
+    let on_change_player_poster: Callback<ChangeData> = {
+        let (form, update_form) = (form.clone(), update_form.clone());
+        Callback::from(move |event: ChangeData| {
+            if let ChangeData::Value(poster) = event {
+                update_form(IContent {
+                    data: serde_json::to_value(IPlayer {
+                        poster: non_empty(poster),
+                        ..IPlayer::from_data(&form.data)
+                    })
+                    .ok(),
+                    ..(*form).clone()
+                });
+            }
+        })
+    };
+    let on_change_player_tracks: Callback<ChangeData> = {
+        let (form, update_form) = (form.clone(), update_form.clone());
+        Callback::from(move |event: ChangeData| {
+            if let ChangeData::Value(outline) = event {
+                update_form(IContent {
+                    data: serde_json::to_value(IPlayer {
+                        tracks: IPlayer::tracks_from_outline(&outline),
+                        ..IPlayer::from_data(&form.data)
+                    })
+                    .ok(),
+                    ..(*form).clone()
+                });
+            }
+        })
+    };
+    // Code metadata, empty inputs are sent as null.
+    let on_change_content_caption: Callback<ChangeData> = {
+        let (form, update_form) = (form.clone(), update_form.clone());
+        Callback::from(move |event: ChangeData| {
+            if let ChangeData::Value(caption) = event {
+                update_form(IContent {
+                    caption: non_empty(caption),
+                    ..(*form).clone()
+                });
+            }
+        })
+    };
+    let on_change_code_start_line: Callback<ChangeData> = {
+        let (form, update_form) = (form.clone(), update_form.clone());
+        Callback::from(move |event: ChangeData| {
+            if let ChangeData::Value(start_line) = event {
+                update_form(IContent {
+                    start_line: start_line.trim().parse().ok(),
+                    ..(*form).clone()
+                });
+            }
+        })
+    };
+    let on_change_code_emphasized_lines: Callback<ChangeData> = {
+        let (form, update_form) = (form.clone(), update_form.clone());
+        Callback::from(move |event: ChangeData| {
+            if let ChangeData::Value(emphasized_lines) = event {
+                update_form(IContent {
+                    emphasized_lines: non_empty(emphasized_lines),
+                    ..(*form).clone()
+                });
+            }
+        })
+    };
+    let on_change_code_added_lines: Callback<ChangeData> = {
+        let (form, update_form) = (form.clone(), update_form.clone());
+        Callback::from(move |event: ChangeData| {
+            if let ChangeData::Value(added_lines) = event {
+                update_form(IContent {
+                    added_lines: non_empty(added_lines),
+                    ..(*form).clone()
+                });
+            }
+        })
+    };
+    let on_change_code_removed_lines: Callback<ChangeData> = {
+        let (form, update_form) = (form.clone(), update_form.clone());
+        Callback::from(move |event: ChangeData| {
+            if let ChangeData::Value(removed_lines) = event {
+                update_form(IContent {
+                    removed_lines: non_empty(removed_lines),
+                    ..(*form).clone()
+                });
+            }
+        })
+    };
+
     {
         use_effect_with_deps(
             move |content| {
@@ -226,12 +539,12 @@ pub fn content(
                             handle_future(future, move |data: Result<IArticle, Status>| {
                                 match data {
                                     Ok(article) => dispatch_article.emit(article),
-                                    Err(_) => dispatch_error.emit(true),
+                                    Err(status) => dispatch_error.emit(status),
                                 };
                                 set_loading(false);
                             });
                         }
-                        Err(_) => dispatch_error.emit(true),
+                        Err(status) => dispatch_error.emit(status),
                     },
                 );
             }),
@@ -269,6 +582,7 @@ pub fn content(
             dispatch_error.clone(),
             on_edit.clone(),
         );
+        let set_field_errors = set_field_errors.clone();
         let context = use_context::<Rc<BlogStore>>().expect("Could not find context!");
         Callback::from(move |_| {
             let (form, content_action, dispatch_article, dispatch_error, set_loading, on_edit) = (
@@ -276,6 +590,11 @@ pub fn content(
                     content: match form.content_type {
                         // Remove the API URL from the image path
                         ContentType::Image => (&form.content[API_URL.len()..]).to_owned(),
+                        ContentType::Video | ContentType::Audio => form
+                            .content
+                            .strip_prefix(API_URL)
+                            .unwrap_or(&form.content)
+                            .to_owned(),
                         _ => form.content.clone(),
                     },
                     language: match form.content_type {
@@ -300,24 +619,42 @@ pub fn content(
                     let content_index = form.index;
                     match content_action {
                         Action::Add => {
+                            let set_field_errors = set_field_errors.clone();
                             let future = async move { add_content(&IContent { ..form }).await };
                             handle_future(future, move |response: Result<IArticle, Status>| {
                                 match response {
-                                    Ok(article) => dispatch_article.emit(article),
-                                    Err(_) => dispatch_error.emit(true),
+                                    Ok(article) => {
+                                        set_field_errors(vec![]);
+                                        dispatch_article.emit(article);
+                                    }
+                                    Err(Status::Failure(error)) if !error.fields.is_empty() => {
+                                        set_field_errors(error.fields)
+                                    }
+                                    Err(status) => dispatch_error.emit(status),
                                 };
                                 set_loading(false);
                             });
                         }
                         Action::Edit => {
+                            let set_field_errors = set_field_errors.clone();
                             let future = async move { update_content(&IContent { ..form }).await };
                             handle_future(future, move |response: Result<IArticle, Status>| {
                                 match response {
-                                    Ok(article) => dispatch_article.emit(article),
-                                    Err(_) => dispatch_error.emit(true),
+                                    Ok(article) => {
+                                        set_field_errors(vec![]);
+                                        dispatch_article.emit(article);
+                                        on_edit.emit((content_index, false));
+                                    }
+                                    // Keep the form open so that the messages are displayed next to the fields.
+                                    Err(Status::Failure(error)) if !error.fields.is_empty() => {
+                                        set_field_errors(error.fields)
+                                    }
+                                    Err(status) => {
+                                        dispatch_error.emit(status);
+                                        on_edit.emit((content_index, false));
+                                    }
                                 };
                                 set_loading(false);
-                                on_edit.emit((content_index, false));
                             });
                         }
                     };
@@ -360,8 +697,31 @@ pub fn content(
         })
     };
 
+    // Only the saved contents of an existing article can be dragged.
+    let movable = *article_action == Action::Edit && *action == Action::Edit && !*edited;
+    let (on_drag_start, on_drag_over, on_drop): (
+        Callback<DragEvent>,
+        Callback<DragEvent>,
+        Callback<DragEvent>,
+    ) = if movable {
+        (
+            on_drag_content(content.id),
+            on_drag_over(),
+            on_drop(
+                DropTarget::Content {
+                    chapter_id: content.chapter_id,
+                    index: content.index,
+                },
+                dispatch_article.clone(),
+                dispatch_error.clone(),
+            ),
+        )
+    } else {
+        Default::default()
+    };
+
     html! {
-        <>
+        <div draggable={movable.to_string()} ondragstart={on_drag_start} ondragover={on_drag_over} ondrop={on_drop}>
             {match form.content_type {
                 ContentType::Text => {
                     if *edited {
@@ -369,6 +729,10 @@ pub fn content(
                             <div>
                                 <Select<ContentType> selected={&form.content_type} options={&CONTENT_TYPES} onchange={on_change_content_type} />
                                 <TextArea rows={8} value={&form.content} onchange={on_change_content_content} />
+                                <p style="font-size: .8em; opacity: .8; margin-top: 4px;">{"**Bold**, *italics*, ~~strikethrough~~, `code`, [links](https://...) and inline math between dollars ($\\pi r^2$), \\$ is a dollar sign, [^key] cites a reference."}</p>
+                                <FieldErrors errors={field_errors.clone()} field="content" />
+                                <FieldErrors errors={field_errors.clone()} field="index" />
+                                <FieldErrors errors={field_errors.clone()} field="chapter_id" />
                                 <div style="display: flex; margin-top: 4px; margin-bottom: 4px; justify-content: flex-end; font-size:.8em;">
                                     {match action {
                                         Action::Edit => html! {<Button variant={ButtonVariant::Danger} onclick={&on_delete_content} label="Delete"/>},
@@ -387,7 +751,7 @@ pub fn content(
                                         <i class="fa fa-edit"/>
                                     </div>
                                 </div>
-                                <p style="white-space: break-spaces;">{&content.content}</p>
+                                {view_text(&content)}
                             </div>
                         }
                     }
@@ -398,6 +762,9 @@ pub fn content(
                             <div>
                                 <Select<ContentType> selected={&form.content_type} options={&CONTENT_TYPES} onchange={on_change_content_type} />
                                 <TextArea rows={8} value={&form.content} onchange={on_change_content_content} />
+                                <FieldErrors errors={field_errors.clone()} field="content" />
+                                <FieldErrors errors={field_errors.clone()} field="index" />
+                                <FieldErrors errors={field_errors.clone()} field="chapter_id" />
                                 <div style="display: flex; margin-top: 4px; margin-bottom: 4px; justify-content: flex-end; font-size:.8em;">
                                     {match action {
                                         Action::Edit => html! {<Button variant={ButtonVariant::Danger} onclick={&on_delete_content} label="Delete"/>},
@@ -428,12 +795,16 @@ pub fn content(
                             <div>
                                 <Select<ContentType> selected={&form.content_type} options={&CONTENT_TYPES} onchange={on_change_content_type} />
                                 <TextArea rows={8} value={&form.content} onchange={on_change_content_content} />
+                                <FieldErrors errors={field_errors.clone()} field="content" />
                                 <TextArea rows={1} value={match &form.url {
                                         Some(url) => url,
                                         None => "URL...",
                                     }}
                                     onchange={on_change_content_url}
                                 />
+                                <FieldErrors errors={field_errors.clone()} field="url" />
+                                <FieldErrors errors={field_errors.clone()} field="index" />
+                                <FieldErrors errors={field_errors.clone()} field="chapter_id" />
                                 <div style="display: flex; margin-top: 4px; margin-bottom: 4px; justify-content: flex-end; font-size:.8em;">
                                    {match action {
                                         Action::Edit => html! {<Button variant={ButtonVariant::Danger} onclick={&on_delete_content} label="Delete"/>},
@@ -479,7 +850,42 @@ pub fn content(
                                         />
                                     </div>
                                 </div>
+                                <FieldErrors errors={field_errors.clone()} field="language" />
                                 <TextArea rows={8} value={&form.content} onchange={on_change_content_content} />
+                                <FieldErrors errors={field_errors.clone()} field="content" />
+                                <TextInput
+                                    placeholder="File name or caption"
+                                    value={form.caption.clone().unwrap_or_default()}
+                                    onchange={on_change_content_caption}
+                                />
+                                <div style="display: flex; margin-top: 4px;">
+                                    <TextInput
+                                        placeholder="Start line (1)"
+                                        value={form.start_line.map(|line| line.to_string()).unwrap_or_default()}
+                                        onchange={on_change_code_start_line}
+                                    />
+                                    <TextInput
+                                        placeholder="Emphasized lines (3, 5-8)"
+                                        value={form.emphasized_lines.clone().unwrap_or_default()}
+                                        onchange={on_change_code_emphasized_lines}
+                                    />
+                                    <TextInput
+                                        placeholder="Added lines"
+                                        value={form.added_lines.clone().unwrap_or_default()}
+                                        onchange={on_change_code_added_lines}
+                                    />
+                                    <TextInput
+                                        placeholder="Removed lines"
+                                        value={form.removed_lines.clone().unwrap_or_default()}
+                                        onchange={on_change_code_removed_lines}
+                                    />
+                                </div>
+                                <FieldErrors errors={field_errors.clone()} field="start_line" />
+                                <FieldErrors errors={field_errors.clone()} field="emphasized_lines" />
+                                <FieldErrors errors={field_errors.clone()} field="added_lines" />
+                                <FieldErrors errors={field_errors.clone()} field="removed_lines" />
+                                <FieldErrors errors={field_errors.clone()} field="index" />
+                                <FieldErrors errors={field_errors.clone()} field="chapter_id" />
                                 <div style="display: flex; margin-top: 4px; margin-bottom: 4px; justify-content: flex-end; font-size:.8em;">
                                    {match action {
                                         Action::Edit => html! {<Button variant={ButtonVariant::Danger} onclick={&on_delete_content} label="Delete"/>},
@@ -503,6 +909,12 @@ pub fn content(
                                         Some(code) => code,
                                         None => &content.content,
                                     }}
+                                    content={&content.content}
+                                    language={content.language.clone()}
+                                    caption={content.caption.clone()}
+                                    start_line={content.start_line.unwrap_or(1)}
+                                    added_lines={content.added_lines.clone().unwrap_or_default()}
+                                    removed_lines={content.removed_lines.clone().unwrap_or_default()}
                                 />
                             </div>
                         }
@@ -514,6 +926,95 @@ pub fn content(
                             <div>
                                 <Select<ContentType> selected={&form.content_type} options={&CONTENT_TYPES} onchange={on_change_content_type} />
                                 <TextArea rows={1} value={&form.content} onchange={on_change_content_content} />
+                                <FieldErrors errors={field_errors.clone()} field="content" />
+                                <TextInput
+                                    placeholder="Alt text, describing the image to screen readers"
+                                    value={IImage::from_data(&form.data).alt}
+                                    onchange={on_change_image_alt}
+                                />
+                                <FieldErrors errors={field_errors.clone()} field="data.alt" />
+                                <TextInput
+                                    placeholder="Caption"
+                                    value={form.caption.clone().unwrap_or_default()}
+                                    onchange={on_change_content_caption}
+                                />
+                                <TextInput
+                                    placeholder="Credits"
+                                    value={IImage::from_data(&form.data).credits.unwrap_or_default()}
+                                    onchange={on_change_image_credits}
+                                />
+                                <FieldErrors errors={field_errors.clone()} field="index" />
+                                <FieldErrors errors={field_errors.clone()} field="chapter_id" />
+                                <div style="display: flex; margin-top: 4px; margin-bottom: 4px; justify-content: flex-end; font-size:.8em;">
+                                    {match action {
+                                        Action::Edit => html! {<Button variant={ButtonVariant::Danger} onclick={&on_delete_content} label="Delete"/>},
+                                        Action::Add => html! {}
+                                    }}
+                                    <Button onclick={on_cancel_edit} label="Cancel" />
+                                    <Button onclick={on_save_content} label="Save" />
+                                </div>
+                            </div>
+                        }
+                    } else {
+                        html! {
+                            <div style="align-items: center; position: relative; display: flex; margin-top: 8px; margin-bottom: 8px;">
+                                <div style="display: flex; justify-content: center; align-items: center;">
+                                    <div onclick={on_edit_content} style="position: absolute; right: -64px; cursor: pointer;">
+                                        <i class="fa fa-edit"/>
+                                    </div>
+                                </div>
+                                {view_image(&content)}
+                            </div>
+                        }
+                    }
+                },
+                ContentType::Table => {
+                    if *edited {
+                        html! {
+                            <div>
+                                <Select<ContentType> selected={&form.content_type} options={&CONTENT_TYPES} onchange={on_change_content_type} />
+                                <TableEditor table={Rc::new(ITable::from_data(&form.data))} onchange={on_change_content_table} />
+                                <FieldErrors errors={field_errors.clone()} field="data" />
+                                <FieldErrors errors={field_errors.clone()} field="data.header" />
+                                <FieldErrors errors={field_errors.clone()} field="data.alignments" />
+                                {for field_errors.iter().filter(|error| error.field.starts_with("data.rows")).map(|error| html! {
+                                    <p style="color: rgb(178, 34, 34); font-size: .8em; margin-top: 4px;">{&error.message}</p>
+                                })}
+                                <FieldErrors errors={field_errors.clone()} field="index" />
+                                <FieldErrors errors={field_errors.clone()} field="chapter_id" />
+                                <div style="display: flex; margin-top: 4px; margin-bottom: 4px; justify-content: flex-end; font-size:.8em;">
+                                    {match action {
+                                        Action::Edit => html! {<Button variant={ButtonVariant::Danger} onclick={&on_delete_content} label="Delete"/>},
+                                        Action::Add => html! {}
+                                    }}
+                                    <Button onclick={on_cancel_edit} label="Cancel" />
+                                    <Button onclick={on_save_content} label="Save" />
+                                </div>
+                            </div>
+                        }
+                    } else {
+                        html! {
+                            <div style="align-items: center; position: relative; display: flex; margin-top: 8px; margin-bottom: 8px;">
+                                <div style="display: flex; justify-content: center; align-items: center;">
+                                    <div onclick={on_edit_content} style="position: absolute; right: -64px; cursor: pointer;">
+                                        <i class="fa fa-edit"/>
+                                    </div>
+                                </div>
+                                <Table table={Rc::new(ITable::from_data(&content.data))} />
+                            </div>
+                        }
+                    }
+                },
+                ContentType::Math => {
+                    if *edited {
+                        html! {
+                            <div>
+                                <Select<ContentType> selected={&form.content_type} options={&CONTENT_TYPES} onchange={on_change_content_type} />
+                                <TextArea rows={4} value={&form.content} onchange={on_change_content_content} />
+                                <p style="font-size: .8em; opacity: .8; margin-top: 4px;">{"LaTeX, rendered to MathML when saved."}</p>
+                                <FieldErrors errors={field_errors.clone()} field="content" />
+                                <FieldErrors errors={field_errors.clone()} field="index" />
+                                <FieldErrors errors={field_errors.clone()} field="chapter_id" />
                                 <div style="display: flex; margin-top: 4px; margin-bottom: 4px; justify-content: flex-end; font-size:.8em;">
                                     {match action {
                                         Action::Edit => html! {<Button variant={ButtonVariant::Danger} onclick={&on_delete_content} label="Delete"/>},
@@ -532,7 +1033,248 @@ pub fn content(
                                         <i class="fa fa-edit"/>
                                     </div>
                                 </div>
-                                <img src={&content.content} style="width: 75%; height: 100%; object-fit: contain;" />
+                                {view_math(&content)}
+                            </div>
+                        }
+                    }
+                }
+                ContentType::Callout => {
+                    if *edited {
+                        html! {
+                            <div>
+                                <div style="display: flex;">
+                                    <Select<ContentType> selected={&form.content_type} options={&CONTENT_TYPES} onchange={on_change_content_type} />
+                                    <div style="margin-left: 8px;">
+                                        <Select<CalloutKind>
+                                            selected={ICallout::from_data(&form.data).kind}
+                                            options={CALLOUT_KINDS}
+                                            onchange={on_change_callout_kind}
+                                        />
+                                    </div>
+                                </div>
+                                <FieldErrors errors={field_errors.clone()} field="data" />
+                                <FieldErrors errors={field_errors.clone()} field="data.kind" />
+                                <TextArea rows={4} value={&form.content} onchange={on_change_content_content} />
+                                <FieldErrors errors={field_errors.clone()} field="content" />
+                                <FieldErrors errors={field_errors.clone()} field="index" />
+                                <FieldErrors errors={field_errors.clone()} field="chapter_id" />
+                                <div style="display: flex; margin-top: 4px; margin-bottom: 4px; justify-content: flex-end; font-size:.8em;">
+                                    {match action {
+                                        Action::Edit => html! {<Button variant={ButtonVariant::Danger} onclick={&on_delete_content} label="Delete"/>},
+                                        Action::Add => html! {}
+                                    }}
+                                    <Button onclick={on_cancel_edit} label="Cancel" />
+                                    <Button onclick={on_save_content} label="Save" />
+                                </div>
+                            </div>
+                        }
+                    } else {
+                        html! {
+                            <div style="align-items: center; position: relative; display: flex; margin-top: 8px; margin-bottom: 8px;">
+                                <div style="display: flex; justify-content: center; align-items: center;">
+                                    <div onclick={on_edit_content} style="position: absolute; right: -64px; cursor: pointer;">
+                                        <i class="fa fa-edit"/>
+                                    </div>
+                                </div>
+                                {view_callout(&content)}
+                            </div>
+                        }
+                    }
+                }
+                ContentType::Quote => {
+                    if *edited {
+                        let quote = IQuote::from_data(&form.data);
+                        html! {
+                            <div>
+                                <Select<ContentType> selected={&form.content_type} options={&CONTENT_TYPES} onchange={on_change_content_type} />
+                                <TextArea rows={4} value={&form.content} onchange={on_change_content_content} />
+                                <FieldErrors errors={field_errors.clone()} field="content" />
+                                <div style="display: flex; margin-top: 4px;">
+                                    <TextInput
+                                        placeholder="Attribution"
+                                        value={quote.attribution.unwrap_or_default()}
+                                        onchange={on_change_quote_attribution}
+                                    />
+                                    <TextInput
+                                        placeholder="Source URL"
+                                        value={quote.source_url.unwrap_or_default()}
+                                        onchange={on_change_quote_source_url}
+                                    />
+                                </div>
+                                <FieldErrors errors={field_errors.clone()} field="data" />
+                                <FieldErrors errors={field_errors.clone()} field="data.source_url" />
+                                <FieldErrors errors={field_errors.clone()} field="index" />
+                                <FieldErrors errors={field_errors.clone()} field="chapter_id" />
+                                <div style="display: flex; margin-top: 4px; margin-bottom: 4px; justify-content: flex-end; font-size:.8em;">
+                                    {match action {
+                                        Action::Edit => html! {<Button variant={ButtonVariant::Danger} onclick={&on_delete_content} label="Delete"/>},
+                                        Action::Add => html! {}
+                                    }}
+                                    <Button onclick={on_cancel_edit} label="Cancel" />
+                                    <Button onclick={on_save_content} label="Save" />
+                                </div>
+                            </div>
+                        }
+                    } else {
+                        html! {
+                            <div style="align-items: center; position: relative; display: flex; margin-top: 8px; margin-bottom: 8px;">
+                                <div style="display: flex; justify-content: center; align-items: center;">
+                                    <div onclick={on_edit_content} style="position: absolute; right: -64px; cursor: pointer;">
+                                        <i class="fa fa-edit"/>
+                                    </div>
+                                </div>
+                                {view_quote(&content)}
+                            </div>
+                        }
+                    }
+                }
+                ContentType::List => {
+                    if *edited {
+                        let list = IList::from_data(&form.data);
+                        html! {
+                            <div>
+                                <Select<ContentType> selected={&form.content_type} options={&CONTENT_TYPES} onchange={on_change_content_type} />
+                                <div style="display: flex; align-items: center; margin-bottom: 8px;">
+                                    <CheckBox checked={list.ordered} onchange={on_change_list_ordered} />
+                                    <span style="margin-left: 8px;">{"Numbered"}</span>
+                                </div>
+                                <TextArea rows={8} value={list.outline()} onchange={on_change_list_outline} />
+                                <p style="font-size: .8em; opacity: .8; margin-top: 4px;">{"One item per line, indent with two spaces to nest an item."}</p>
+                                <FieldErrors errors={field_errors.clone()} field="data" />
+                                {for field_errors.iter().filter(|error| error.field.starts_with("data.items")).map(|error| html! {
+                                    <p style="color: rgb(178, 34, 34); font-size: .8em; margin-top: 4px;">{&error.message}</p>
+                                })}
+                                <FieldErrors errors={field_errors.clone()} field="index" />
+                                <FieldErrors errors={field_errors.clone()} field="chapter_id" />
+                                <div style="display: flex; margin-top: 4px; margin-bottom: 4px; justify-content: flex-end; font-size:.8em;">
+                                    {match action {
+                                        Action::Edit => html! {<Button variant={ButtonVariant::Danger} onclick={&on_delete_content} label="Delete"/>},
+                                        Action::Add => html! {}
+                                    }}
+                                    <Button onclick={on_cancel_edit} label="Cancel" />
+                                    <Button onclick={on_save_content} label="Save" />
+                                </div>
+                            </div>
+                        }
+                    } else {
+                        html! {
+                            <div style="align-items: center; position: relative; display: flex; margin-top: 8px; margin-bottom: 8px;">
+                                <div style="display: flex; justify-content: center; align-items: center;">
+                                    <div onclick={on_edit_content} style="position: absolute; right: -64px; cursor: pointer;">
+                                        <i class="fa fa-edit"/>
+                                    </div>
+                                </div>
+                                {view_list(&content)}
+                            </div>
+                        }
+                    }
+                }
+                ContentType::Video => {
+                    if *edited {
+                        let player = IPlayer::from_data(&form.data);
+                        html! {
+                            <div>
+                                <Select<ContentType> selected={&form.content_type} options={&CONTENT_TYPES} onchange={on_change_content_type} />
+                                <TextInput
+                                    placeholder="File under media/ (/media/videos/talk.mp4)"
+                                    value={form.content.clone()}
+                                    onchange={on_change_content_content}
+                                />
+                                <FieldErrors errors={field_errors.clone()} field="content" />
+                                <TextInput
+                                    placeholder="Or a YouTube or Vimeo URL"
+                                    value={form.url.clone().unwrap_or_default()}
+                                    onchange={on_change_content_url}
+                                />
+                                <FieldErrors errors={field_errors.clone()} field="url" />
+                                <TextInput
+                                    placeholder="Poster image (/media/images/talk.webp)"
+                                    value={player.poster.clone().unwrap_or_default()}
+                                    onchange={on_change_player_poster}
+                                />
+                                <FieldErrors errors={field_errors.clone()} field="data.poster" />
+                                <TextInput
+                                    placeholder="Caption"
+                                    value={form.caption.clone().unwrap_or_default()}
+                                    onchange={on_change_content_caption}
+                                />
+                                <TextArea rows={3} value={player.tracks_outline()} onchange={on_change_player_tracks} />
+                                <p style="font-size: .8em; opacity: .8; margin-top: 4px;">{"Subtitles, one WebVTT file per line: /media/videos/talk.en.vtt, en, English (add \", captions\" for captions)."}</p>
+                                <FieldErrors errors={field_errors.clone()} field="data" />
+                                <FieldErrors errors={field_errors.clone()} field="data.tracks" />
+                                {for field_errors.iter().filter(|error| error.field.starts_with("data.tracks[")).map(|error| html! {
+                                    <p style="color: rgb(178, 34, 34); font-size: .8em; margin-top: 4px;">{&error.message}</p>
+                                })}
+                                <FieldErrors errors={field_errors.clone()} field="index" />
+                                <FieldErrors errors={field_errors.clone()} field="chapter_id" />
+                                <div style="display: flex; margin-top: 4px; margin-bottom: 4px; justify-content: flex-end; font-size:.8em;">
+                                    {match action {
+                                        Action::Edit => html! {<Button variant={ButtonVariant::Danger} onclick={&on_delete_content} label="Delete"/>},
+                                        Action::Add => html! {}
+                                    }}
+                                    <Button onclick={on_cancel_edit} label="Cancel" />
+                                    <Button onclick={on_save_content} label="Save" />
+                                </div>
+                            </div>
+                        }
+                    } else {
+                        html! {
+                            <div style="align-items: center; position: relative; display: flex; margin-top: 8px; margin-bottom: 8px;">
+                                <div style="display: flex; justify-content: center; align-items: center;">
+                                    <div onclick={on_edit_content} style="position: absolute; right: -64px; cursor: pointer;">
+                                        <i class="fa fa-edit"/>
+                                    </div>
+                                </div>
+                                {view_video(&content)}
+                            </div>
+                        }
+                    }
+                }
+                ContentType::Audio => {
+                    if *edited {
+                        let player = IPlayer::from_data(&form.data);
+                        html! {
+                            <div>
+                                <Select<ContentType> selected={&form.content_type} options={&CONTENT_TYPES} onchange={on_change_content_type} />
+                                <TextInput
+                                    placeholder="File under media/ (/media/audios/episode.ogg)"
+                                    value={form.content.clone()}
+                                    onchange={on_change_content_content}
+                                />
+                                <FieldErrors errors={field_errors.clone()} field="content" />
+                                <TextInput
+                                    placeholder="Caption"
+                                    value={form.caption.clone().unwrap_or_default()}
+                                    onchange={on_change_content_caption}
+                                />
+                                <TextArea rows={3} value={player.tracks_outline()} onchange={on_change_player_tracks} />
+                                <p style="font-size: .8em; opacity: .8; margin-top: 4px;">{"Subtitles, one WebVTT file per line: /media/videos/talk.en.vtt, en, English (add \", captions\" for captions)."}</p>
+                                <FieldErrors errors={field_errors.clone()} field="data" />
+                                <FieldErrors errors={field_errors.clone()} field="data.tracks" />
+                                {for field_errors.iter().filter(|error| error.field.starts_with("data.tracks[")).map(|error| html! {
+                                    <p style="color: rgb(178, 34, 34); font-size: .8em; margin-top: 4px;">{&error.message}</p>
+                                })}
+                                <FieldErrors errors={field_errors.clone()} field="index" />
+                                <FieldErrors errors={field_errors.clone()} field="chapter_id" />
+                                <div style="display: flex; margin-top: 4px; margin-bottom: 4px; justify-content: flex-end; font-size:.8em;">
+                                    {match action {
+                                        Action::Edit => html! {<Button variant={ButtonVariant::Danger} onclick={&on_delete_content} label="Delete"/>},
+                                        Action::Add => html! {}
+                                    }}
+                                    <Button onclick={on_cancel_edit} label="Cancel" />
+                                    <Button onclick={on_save_content} label="Save" />
+                                </div>
+                            </div>
+                        }
+                    } else {
+                        html! {
+                            <div style="align-items: center; position: relative; display: flex; margin-top: 8px; margin-bottom: 8px;">
+                                <div style="display: flex; justify-content: center; align-items: center;">
+                                    <div onclick={on_edit_content} style="position: absolute; right: -64px; cursor: pointer;">
+                                        <i class="fa fa-edit"/>
+                                    </div>
+                                </div>
+                                {view_audio(&content)}
                             </div>
                         }
                     }
@@ -547,6 +1289,6 @@ pub fn content(
             } else {
                 html! {}
             }}
-        </>
+        </div>
     }
 }
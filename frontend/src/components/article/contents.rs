@@ -2,7 +2,7 @@ use {
     super::content::Content,
     crate::entities::{
         action::Action,
-        interfaces::{IArticle, IContent},
+        interfaces::{IArticle, IContent, Status},
     },
     std::rc::Rc,
     yew::{html, Callback, Properties},
@@ -29,7 +29,7 @@ pub struct ContentsProps {
     #[prop_or_default]
     pub dispatch_article: Callback<IArticle>,
     #[prop_or_default]
-    pub dispatch_error: Callback<bool>,
+    pub dispatch_error: Callback<Status>,
 }
 
 #[cfg(not(feature = "editable"))]
@@ -0,0 +1,267 @@
+use {
+    crate::{
+        components::{
+            button::{Button, ButtonVariant},
+            loader::Loader,
+        },
+        entities::{
+            interfaces::{IArticle, Status},
+            revision::{IChange, IFieldChange, IRevision, IRevisionDiff},
+        },
+        service::{
+            articles::{get_revision_diff, get_revisions, restore_revision},
+            future::handle_future,
+        },
+        utils::date::format_datetime,
+    },
+    serde_json::Value,
+    std::rc::Rc,
+    yew::{html, Callback, Html, MouseEvent, Properties},
+    yew_functional::{function_component, use_effect_with_deps, use_state},
+};
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct HistoryProps {
+    pub article: Rc<IArticle>,
+    pub dispatch_article: Callback<IArticle>,
+    pub dispatch_error: Callback<Status>,
+}
+
+fn view_value(value: &Value) -> String {
+    match value {
+        Value::Null => "—".to_owned(),
+        Value::String(text) => text.clone(),
+        value => value.to_string(),
+    }
+}
+
+fn view_change(change: &IChange) -> Html {
+    html! {
+        <span class={format!("change change-{}", change.label())}>{change.label()}</span>
+    }
+}
+
+fn view_fields(fields: &[IFieldChange]) -> Html {
+    if fields.is_empty() {
+        return html! {};
+    }
+
+    html! {
+        <table class="diff-fields">
+            <tbody>
+                {for fields.iter().map(|field| html! {
+                    <tr>
+                        <th>{&field.field}</th>
+                        <td><del>{view_value(&field.before)}</del></td>
+                        <td><ins>{view_value(&field.after)}</ins></td>
+                    </tr>
+                })}
+            </tbody>
+        </table>
+    }
+}
+
+fn view_diff(diff: &IRevisionDiff) -> Html {
+    if diff.is_empty() {
+        return html! { <p class="revision-diff">{"No changes."}</p> };
+    }
+
+    html! {
+        <div class="revision-diff">
+            {view_fields(&diff.fields)}
+            {for diff.chapters.iter().map(|chapter| html! {
+                <div class="diff-chapter">
+                    <h3>
+                        {match &chapter.change {
+                            Some(change) => view_change(change),
+                            None => html! {},
+                        }}
+                        {&chapter.title}
+                    </h3>
+                    {view_fields(&chapter.fields)}
+                    {for chapter.contents.iter().map(|content| html! {
+                        <div class="diff-content">
+                            <p>{view_change(&content.change)}{&content.content_type}</p>
+                            {view_fields(&content.fields)}
+                        </div>
+                    })}
+                </div>
+            })}
+            {for diff.references.iter().map(|reference| html! {
+                <div class="diff-chapter">
+                    <h3>{view_change(&reference.change)}{format!("[^{}]", reference.key)}</h3>
+                    {view_fields(&reference.fields)}
+                </div>
+            })}
+        </div>
+    }
+}
+
+// Revisions of the article, newest first. The changes of a revision are the diff with the previous one, and any
+// revision but the newest (the current state) can be restored.
+#[function_component(History)]
+pub fn history(
+    HistoryProps {
+        article,
+        dispatch_article,
+        dispatch_error,
+    }: &HistoryProps,
+) -> Html {
+    let (is_open, set_open) = use_state(|| false);
+    let (is_loading, set_loading) = use_state(|| false);
+    let (revisions, set_revisions) = use_state(Vec::<IRevision>::new);
+    // Revision whose changes are displayed, with its diff once loaded.
+    let (selected, set_selected) = use_state(|| None::<i32>);
+    let (diff, set_diff) = use_state(|| None::<IRevisionDiff>);
+
+    {
+        let (article_id, set_loading, set_revisions, dispatch_error) = (
+            article.id,
+            set_loading.clone(),
+            set_revisions.clone(),
+            dispatch_error.clone(),
+        );
+        // Every save of the article is a new revision.
+        use_effect_with_deps(
+            move |(is_open, _)| {
+                if *is_open {
+                    set_loading(true);
+                    let (set_loading, set_revisions, dispatch_error) = (
+                        set_loading.clone(),
+                        set_revisions.clone(),
+                        dispatch_error.clone(),
+                    );
+                    let future = async move { get_revisions(&article_id).await };
+                    handle_future(future, move |data: Result<Vec<IRevision>, Status>| {
+                        match data {
+                            Ok(revisions) => set_revisions(revisions),
+                            Err(status) => dispatch_error.emit(status),
+                        };
+                        set_loading(false);
+                    });
+                }
+                || {}
+            },
+            (*is_open, article.clone()),
+        );
+    };
+
+    let on_toggle: Callback<MouseEvent> = {
+        let (is_open, set_open) = (*is_open, set_open.clone());
+        Callback::from(move |_| set_open(!is_open))
+    };
+
+    let on_show_changes = |revision_id: i32, previous_id: i32| -> Callback<MouseEvent> {
+        let (article_id, set_loading, set_selected, set_diff, dispatch_error) = (
+            article.id,
+            set_loading.clone(),
+            set_selected.clone(),
+            set_diff.clone(),
+            dispatch_error.clone(),
+        );
+        Callback::from(move |_| {
+            set_loading(true);
+            set_selected(Some(revision_id));
+            set_diff(None);
+            let (set_loading, set_diff, dispatch_error) = (
+                set_loading.clone(),
+                set_diff.clone(),
+                dispatch_error.clone(),
+            );
+            let future =
+                async move { get_revision_diff(&article_id, &previous_id, &revision_id).await };
+            handle_future(future, move |data: Result<IRevisionDiff, Status>| {
+                match data {
+                    Ok(diff) => set_diff(Some(diff)),
+                    Err(status) => dispatch_error.emit(status),
+                };
+                set_loading(false);
+            });
+        })
+    };
+
+    let on_restore = |revision_id: i32| -> Callback<MouseEvent> {
+        let (article_id, set_loading, set_selected, dispatch_article, dispatch_error) = (
+            article.id,
+            set_loading.clone(),
+            set_selected.clone(),
+            dispatch_article.clone(),
+            dispatch_error.clone(),
+        );
+        Callback::from(move |_| {
+            set_loading(true);
+            let (set_loading, set_selected, dispatch_article, dispatch_error) = (
+                set_loading.clone(),
+                set_selected.clone(),
+                dispatch_article.clone(),
+                dispatch_error.clone(),
+            );
+            let future = async move { restore_revision(&article_id, &revision_id).await };
+            handle_future(future, move |data: Result<IArticle, Status>| {
+                match data {
+                    Ok(article) => {
+                        set_selected(None);
+                        dispatch_article.emit(article);
+                    }
+                    Err(status) => dispatch_error.emit(status),
+                };
+                set_loading(false);
+            });
+        })
+    };
+
+    html! {
+        <section class="history">
+            <div style="display: flex; align-items: center; justify-content: space-between;">
+                <h2 class="article-chapter">{"History"}</h2>
+                <div style="font-size: .8em;">
+                    <Button onclick={on_toggle} label={if *is_open { "Hide" } else { "Show" }.to_owned()} />
+                </div>
+            </div>
+            {if *is_open {
+                html! {
+                    <ol class="revisions">
+                        {for revisions.iter().enumerate().map(|(position, revision)| html! {
+                            <li class={if *selected == Some(revision.id) { "revision selected" } else { "revision" }}>
+                                <div style="display: flex; align-items: center; font-size: .8em;">
+                                    <span style="flex: 1;">
+                                        {format_datetime(&revision.created).unwrap_or_else(|_| revision.created.clone())}
+                                        <span class="revision-author">{&revision.author}</span>
+                                    </span>
+                                    {match revisions.get(position + 1) {
+                                        Some(previous) => html! {
+                                            <Button onclick={on_show_changes(revision.id, previous.id)} label="Changes" />
+                                        },
+                                        None => html! {},
+                                    }}
+                                    {if position > 0 {
+                                        html! {
+                                            <Button variant={ButtonVariant::Warning} onclick={on_restore(revision.id)} label="Restore" />
+                                        }
+                                    } else {
+                                        html! {}
+                                    }}
+                                </div>
+                                {match (&*diff, *selected == Some(revision.id)) {
+                                    (Some(diff), true) => view_diff(diff),
+                                    _ => html! {},
+                                }}
+                            </li>
+                        })}
+                    </ol>
+                }
+            } else {
+                html! {}
+            }}
+            {if *is_loading {
+                html! {
+                    <div style="align-items: center; justify-content: center; display: flex;">
+                        <Loader />
+                    </div>
+                }
+            } else {
+                html! {}
+            }}
+        </section>
+    }
+}
@@ -7,3 +7,13 @@ pub mod chapter_list;
 pub mod chapters;
 pub mod content;
 pub mod contents;
+#[cfg(feature = "editable")]
+pub mod history;
+#[cfg(feature = "editable")]
+pub mod preview_link;
+pub mod references;
+#[cfg(not(feature = "editable"))]
+pub mod related;
+#[cfg(feature = "editable")]
+pub mod schedule;
+pub mod series;
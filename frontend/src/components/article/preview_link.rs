@@ -0,0 +1,77 @@
+use {
+    crate::{
+        components::{button::Button, loader::Loader},
+        entities::interfaces::{IPreviewLink, IPreviewLinkRequest, Status},
+        service::{articles::create_preview_link, future::handle_future},
+        utils::date::format_datetime,
+    },
+    yew::{html, Callback, MouseEvent, Properties},
+    yew_functional::{function_component, use_state},
+};
+
+// Validity of the links minted from the editor.
+const PREVIEW_HOURS: i64 = 72;
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct PreviewLinkProps {
+    pub article_id: i32,
+    pub dispatch_error: Callback<Status>,
+}
+
+// Mint a link to the read-only draft on the public site, to be shared with reviewers.
+#[function_component(PreviewLink)]
+pub fn preview_link(
+    PreviewLinkProps {
+        article_id,
+        dispatch_error,
+    }: &PreviewLinkProps,
+) -> Html {
+    let (is_loading, set_loading) = use_state(|| false);
+    let (link, set_link) = use_state(|| None::<IPreviewLink>);
+
+    let on_create: Callback<MouseEvent> = {
+        let (article_id, dispatch_error) = (*article_id, dispatch_error.clone());
+        Callback::from(move |_| {
+            set_loading(true);
+            let (set_loading, set_link, dispatch_error) = (
+                set_loading.clone(),
+                set_link.clone(),
+                dispatch_error.clone(),
+            );
+            let payload = IPreviewLinkRequest {
+                hours: PREVIEW_HOURS,
+            };
+            let future = async move { create_preview_link(&article_id, &payload).await };
+            handle_future(future, move |data: Result<IPreviewLink, Status>| {
+                match data {
+                    Ok(link) => set_link(Some(link)),
+                    Err(status) => dispatch_error.emit(status),
+                };
+                set_loading(false);
+            });
+        })
+    };
+
+    html! {
+        <div class="preview-link">
+            {match &*link {
+                Some(link) => html! {
+                    <>
+                        <input type="text" readonly=true value={link.url.clone()} />
+                        <span class="preview-expires">
+                            {format!("Expires on {}", format_datetime(&link.expires).unwrap_or_else(|_| link.expires.clone()))}
+                        </span>
+                    </>
+                },
+                None => html! {},
+            }}
+            <div style="font-size: .8em;">
+                {if *is_loading {
+                    html! { <Loader /> }
+                } else {
+                    html! { <Button onclick={on_create} label="Preview link" /> }
+                }}
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,343 @@
+use {
+    crate::entities::{
+        action::Action,
+        interfaces::{IArticle, IReference, Status},
+    },
+    std::rc::Rc,
+    yew::{html, Callback, Html, Properties},
+    yew_functional::function_component,
+};
+
+#[cfg(feature = "editable")]
+use {
+    crate::{
+        components::{
+            button::{Button, ButtonVariant},
+            field_errors::FieldErrors,
+            loader::Loader,
+            text_input::TextInput,
+        },
+        entities::interfaces::IFieldError,
+        service::{
+            articles::{add_reference, delete_reference, get_article, update_reference},
+            future::handle_future,
+        },
+    },
+    yew::{ChangeData, MouseEvent},
+    yew_functional::use_state,
+};
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct ReferencesProps {
+    pub references: Rc<Vec<IReference>>,
+    #[prop_or(Action::Edit)]
+    pub article_action: Action,
+    #[prop_or_default]
+    pub article_id: i32,
+    #[prop_or_default]
+    pub dispatch_article: Callback<IArticle>,
+    #[prop_or_default]
+    pub dispatch_error: Callback<Status>,
+}
+
+// The title (linked to its url) and a back-link to every citation of the reference.
+fn view_reference(reference: &IReference) -> Html {
+    html! {
+        <>
+            {match &reference.url {
+                Some(url) => html! {
+                    <a href={url.clone()} target="_blank" rel="noopener noreferrer">{&reference.title}</a>
+                },
+                None => html! { <>{&reference.title}</> },
+            }}
+            {for (1..=reference.citations).map(|citation| html! {
+                <a class="back-link" href={format!("#citation-{}-{}", reference.key, citation)}>{"↩"}</a>
+            })}
+        </>
+    }
+}
+
+#[cfg(not(feature = "editable"))]
+#[function_component(References)]
+pub fn references(ReferencesProps { references, .. }: &ReferencesProps) -> Html {
+    if references.is_empty() {
+        return html! {};
+    }
+
+    html! {
+        <section class="references">
+            <h2 class="article-chapter">{"References"}</h2>
+            <ol>
+                {for references.iter().map(|reference| html! {
+                    <li id={format!("reference-{}", reference.key)}>{view_reference(reference)}</li>
+                })}
+            </ol>
+        </section>
+    }
+}
+
+#[cfg(feature = "editable")]
+#[function_component(References)]
+pub fn references(
+    ReferencesProps {
+        references,
+        article_action,
+        article_id,
+        dispatch_article,
+        dispatch_error,
+    }: &ReferencesProps,
+) -> Html {
+    // Id of the reference being edited, 0 for a new one.
+    let (edited, set_edited) = use_state(|| None::<i32>);
+
+    // References are added once the article exists, they need its id.
+    if *article_action == Action::Add {
+        return html! {};
+    }
+
+    let on_add_reference: Callback<MouseEvent> = {
+        let set_edited = set_edited.clone();
+        Callback::from(move |_| set_edited(Some(0)))
+    };
+    let on_close: Callback<()> = {
+        let set_edited = set_edited.clone();
+        Callback::from(move |_| set_edited(None))
+    };
+    let view_form = |reference: IReference, action: Action| {
+        html! {
+            <ReferenceForm
+                reference={Rc::new(reference)}
+                action={action}
+                on_close={on_close.clone()}
+                dispatch_article={dispatch_article}
+                dispatch_error={dispatch_error}
+            />
+        }
+    };
+
+    html! {
+        <section class="references">
+            <h2 class="article-chapter">{"References"}</h2>
+            <ol>
+                {for references.iter().map(|reference| {
+                    if *edited == Some(reference.id) {
+                        html! { <li>{view_form(reference.clone(), Action::Edit)}</li> }
+                    } else {
+                        let on_edit_reference: Callback<MouseEvent> = {
+                            let (id, set_edited) = (reference.id, set_edited.clone());
+                            Callback::from(move |_| set_edited(Some(id)))
+                        };
+                        html! {
+                            <li id={format!("reference-{}", reference.key)}>
+                                {view_reference(reference)}
+                                <i onclick={on_edit_reference} class="fa fa-edit" style="margin-left: 8px; cursor: pointer;"></i>
+                            </li>
+                        }
+                    }
+                })}
+            </ol>
+            {if *edited == Some(0) {
+                view_form(
+                    IReference {
+                        article_id: *article_id,
+                        index: references.len() as i32,
+                        ..IReference::default()
+                    },
+                    Action::Add,
+                )
+            } else {
+                html! {
+                    <div style="display: flex; justify-content: flex-end; font-size: .8em;">
+                        <Button onclick={on_add_reference} label="Add a reference" />
+                    </div>
+                }
+            }}
+        </section>
+    }
+}
+
+#[cfg(feature = "editable")]
+#[derive(Properties, Clone, PartialEq)]
+pub struct ReferenceFormProps {
+    pub reference: Rc<IReference>,
+    pub action: Action,
+    pub on_close: Callback<()>,
+    pub dispatch_article: Callback<IArticle>,
+    pub dispatch_error: Callback<Status>,
+}
+
+#[cfg(feature = "editable")]
+#[function_component(ReferenceForm)]
+pub fn reference_form(
+    ReferenceFormProps {
+        reference,
+        action,
+        on_close,
+        dispatch_article,
+        dispatch_error,
+    }: &ReferenceFormProps,
+) -> Html {
+    let (is_loading, set_loading) = use_state(|| false);
+    let (field_errors, set_field_errors) = use_state(Vec::<IFieldError>::new);
+    let (key, set_key) = {
+        let key = reference.key.clone();
+        use_state(move || key)
+    };
+    let (title, set_title) = {
+        let title = reference.title.clone();
+        use_state(move || title)
+    };
+    let (url, set_url) = {
+        let url = reference.url.clone().unwrap_or_default();
+        use_state(move || url)
+    };
+
+    let on_change_key: Callback<ChangeData> = Callback::from(move |event: ChangeData| {
+        if let ChangeData::Value(updated_key) = event {
+            set_key(updated_key);
+        }
+    });
+    let on_change_title: Callback<ChangeData> = Callback::from(move |event: ChangeData| {
+        if let ChangeData::Value(updated_title) = event {
+            set_title(updated_title);
+        }
+    });
+    let on_change_url: Callback<ChangeData> = Callback::from(move |event: ChangeData| {
+        if let ChangeData::Value(updated_url) = event {
+            set_url(updated_url);
+        }
+    });
+
+    let on_cancel: Callback<MouseEvent> = {
+        let on_close = on_close.clone();
+        Callback::from(move |_| on_close.emit(()))
+    };
+
+    let on_delete_reference: Callback<MouseEvent> = {
+        let (article_id, reference_id, set_loading, on_close, dispatch_article, dispatch_error) = (
+            reference.article_id,
+            reference.id,
+            set_loading.clone(),
+            on_close.clone(),
+            dispatch_article.clone(),
+            dispatch_error.clone(),
+        );
+        Callback::from(move |_| {
+            set_loading(true);
+            let (set_loading, on_close, dispatch_article, dispatch_error) = (
+                set_loading.clone(),
+                on_close.clone(),
+                dispatch_article.clone(),
+                dispatch_error.clone(),
+            );
+            let future = async move { delete_reference(&reference_id).await };
+            handle_future(
+                future,
+                move |response: Result<Status, Status>| match response {
+                    Ok(_) => {
+                        let (set_loading, on_close, dispatch_article, dispatch_error) = (
+                            set_loading.clone(),
+                            on_close.clone(),
+                            dispatch_article.clone(),
+                            dispatch_error.clone(),
+                        );
+                        let future = async move { get_article(&article_id).await };
+                        handle_future(future, move |data: Result<IArticle, Status>| {
+                            match data {
+                                Ok(article) => dispatch_article.emit(article),
+                                Err(status) => dispatch_error.emit(status),
+                            };
+                            set_loading(false);
+                            on_close.emit(());
+                        });
+                    }
+                    Err(status) => {
+                        dispatch_error.emit(status);
+                        set_loading(false);
+                    }
+                },
+            );
+        })
+    };
+
+    let on_save_reference: Callback<MouseEvent> = {
+        let (reference, action, on_close, dispatch_article, dispatch_error) = (
+            IReference {
+                key: key.trim().to_owned(),
+                title: title.trim().to_owned(),
+                url: Some(url.trim().to_owned()).filter(|url| !url.is_empty()),
+                ..(**reference).clone()
+            },
+            action.clone(),
+            on_close.clone(),
+            dispatch_article.clone(),
+            dispatch_error.clone(),
+        );
+        Callback::from(move |_| {
+            set_loading(true);
+            let (reference, action, set_loading, set_field_errors, on_close) = (
+                reference.clone(),
+                action.clone(),
+                set_loading.clone(),
+                set_field_errors.clone(),
+                on_close.clone(),
+            );
+            let (dispatch_article, dispatch_error) =
+                (dispatch_article.clone(), dispatch_error.clone());
+            let future = async move {
+                match action {
+                    Action::Add => add_reference(&reference).await,
+                    Action::Edit => update_reference(&reference).await,
+                }
+            };
+            handle_future(future, move |data: Result<IArticle, Status>| {
+                match data {
+                    Ok(article) => {
+                        dispatch_article.emit(article);
+                        on_close.emit(());
+                    }
+                    // Keep the form open so that the messages are displayed next to the fields.
+                    Err(Status::Failure(error)) if !error.fields.is_empty() => {
+                        set_field_errors(error.fields)
+                    }
+                    Err(status) => {
+                        dispatch_error.emit(status);
+                        on_close.emit(());
+                    }
+                };
+                set_loading(false);
+            });
+        })
+    };
+
+    html! {
+        <div style="margin-top: 8px; margin-bottom: 8px;">
+            <TextInput value={(*key).clone()} onchange={on_change_key} placeholder="Key, cited as [^key]" />
+            <FieldErrors errors={field_errors.clone()} field="key" />
+            <TextInput value={(*title).clone()} onchange={on_change_title} placeholder="Title" />
+            <FieldErrors errors={field_errors.clone()} field="title" />
+            <TextInput value={(*url).clone()} onchange={on_change_url} placeholder="https://... (optional)" />
+            <FieldErrors errors={field_errors.clone()} field="url" />
+            <FieldErrors errors={field_errors.clone()} field="index" />
+            <div style="display: flex; margin-top: 4px; margin-bottom: 4px; justify-content: flex-end; font-size: .8em;">
+                {match action {
+                    Action::Edit => html! {
+                        <Button variant={ButtonVariant::Danger} onclick={on_delete_reference} label="Delete" />
+                    },
+                    Action::Add => html! {}
+                }}
+                <Button onclick={on_cancel} label="Cancel" />
+                <Button onclick={on_save_reference} label="Save" />
+            </div>
+            {if *is_loading {
+                html! {
+                    <div style="align-items: center; justify-content: center; display: flex;">
+                        <Loader />
+                    </div>
+                }
+            } else {
+                html! {}
+            }}
+        </div>
+    }
+}
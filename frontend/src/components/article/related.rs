@@ -0,0 +1,108 @@
+use {
+    crate::{
+        components::{project::Project, tag_label::TagLabel},
+        entities::interfaces::{IArticleSummary, IRelatedItem, Status},
+        routes::AppRoute,
+        service::{articles::get_related, future::handle_future},
+        utils::date::format_date,
+    },
+    yew::{html, Callback, Html, Properties},
+    yew_functional::{function_component, use_effect_with_deps, use_state},
+    yew_router::components::RouterAnchor,
+};
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct RelatedProps {
+    pub article_id: i32,
+    #[prop_or_default]
+    pub dispatch_error: Callback<Status>,
+}
+
+fn related_article(article: &IArticleSummary) -> Html {
+    html! {
+        <RouterAnchor<AppRoute> route=AppRoute::Article{id: article.id}>
+            <div style="margin-bottom: 8px;">
+                <h1 class="heading">{&article.title}</h1>
+            </div>
+            <div style="margin-top: 8px; margin-bottom: 8px;">
+                {for article.tags.iter().map(|tag| { html! { <TagLabel tag={&tag.label} /> } })}
+            </div>
+            <div style="display: flex; margin-top: 12px; margin-bottom: 8px;">
+                <p>{format_date(&article.pub_date).unwrap_or_else(|_|"An error occured.".to_string())}</p>
+                {match &article.updated {
+                    Some(update_date) => html! {
+                        <div style="display: flex; margin-left: 16px; font-style: italic;">
+                            <p>{"Updated:"}</p>
+                            <p style="margin-left: 8px;">
+                                {format_date(update_date).unwrap_or_else(|_|"An error occured.".to_string())}
+                            </p>
+                        </div>
+                    },
+                    None => html! {},
+                }}
+            </div>
+            <div style="margin-top: 8px; margin-bottom: 8px;">
+                <img
+                    src={&article.image}
+                    alt={&article.image_alt}
+                    style="object-fit: cover; height: 16em; width: 100%;"
+                />
+            </div>
+            <div style="margin-top: 8px; margin-bottom: 8px;">
+                <p>{&article.headline}</p>
+            </div>
+        </RouterAnchor<AppRoute>>
+    }
+}
+
+// Where to go next after reading the article, hidden when nothing is related.
+#[function_component(Related)]
+pub fn related(
+    RelatedProps {
+        article_id,
+        dispatch_error,
+    }: &RelatedProps,
+) -> Html {
+    let (items, set_items) = use_state(Vec::<IRelatedItem>::new);
+
+    {
+        let dispatch_error = dispatch_error.clone();
+        use_effect_with_deps(
+            move |article_id| {
+                let article_id = *article_id;
+                let future = async move { get_related(&article_id).await };
+                handle_future(future, move |data: Result<Vec<IRelatedItem>, Status>| {
+                    match data {
+                        Ok(items) => set_items(items),
+                        // Drafts opened through a preview link have no related items.
+                        Err(Status::Failure(error)) if error.code == "not_found" => {}
+                        Err(status) => dispatch_error.emit(status),
+                    };
+                });
+                || {}
+            },
+            *article_id,
+        );
+    }
+
+    if items.is_empty() {
+        return html! {};
+    }
+
+    html! {
+        <section class="related">
+            <h2 class="article-chapter">{"Related"}</h2>
+            {for items.iter().map(|item| html! {
+                <div style="margin-bottom: 12px;">
+                    {match item {
+                        IRelatedItem::Article(article) => related_article(article),
+                        IRelatedItem::Project(project) => html! { <Project project={project} /> },
+                    }}
+                    <div style="margin-top: 12px;">
+                        <hr style="border: 0; border-top: 1px solid rgb(41, 41, 41);"/>
+                    </div>
+                </div>
+            })}
+        </section>
+    }
+}
@@ -0,0 +1,207 @@
+use {
+    crate::{
+        components::{button::Button, field_errors::FieldErrors, loader::Loader},
+        entities::interfaces::{
+            IArticle, IFieldError, IScheduleArticle, IScheduledArticle, Status,
+        },
+        routes::AppRoute,
+        service::{
+            articles::{get_scheduled_articles, schedule_article},
+            future::handle_future,
+        },
+        utils::date::format_datetime,
+    },
+    std::rc::Rc,
+    yew::{html, Callback, ChangeData, MouseEvent, Properties},
+    yew_functional::{function_component, use_effect_with_deps, use_state},
+    yew_router::components::RouterAnchor,
+};
+
+// The API sends "YYYY-MM-DDTHH:MM:SS", a datetime-local input only takes (and gives back) the minutes.
+fn to_input(datetime: &Option<String>) -> String {
+    datetime
+        .as_ref()
+        .map(|datetime| datetime.chars().take(16).collect())
+        .unwrap_or_default()
+}
+
+fn from_input(value: &str) -> Option<String> {
+    match value {
+        "" => None,
+        value => Some(format!("{}:00", value)),
+    }
+}
+
+fn view_datetime(datetime: &str) -> String {
+    format_datetime(datetime).unwrap_or_else(|_| datetime.to_owned())
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct ScheduleProps {
+    pub article: Rc<IArticle>,
+    pub dispatch_article: Callback<IArticle>,
+    pub dispatch_error: Callback<Status>,
+}
+
+// Publication and unpublication times of the article, applied by the scheduler of the API. An empty time cancels
+// the transition.
+#[function_component(Schedule)]
+pub fn schedule(
+    ScheduleProps {
+        article,
+        dispatch_article,
+        dispatch_error,
+    }: &ScheduleProps,
+) -> Html {
+    let (is_loading, set_loading) = use_state(|| false);
+    let (field_errors, set_field_errors) = use_state(Vec::<IFieldError>::new);
+    let (publish_at, set_publish_at) = use_state(|| to_input(&article.publish_at));
+    let (unpublish_at, set_unpublish_at) = use_state(|| to_input(&article.unpublish_at));
+
+    {
+        let (set_publish_at, set_unpublish_at) = (set_publish_at.clone(), set_unpublish_at.clone());
+        use_effect_with_deps(
+            move |article| {
+                set_publish_at(to_input(&article.publish_at));
+                set_unpublish_at(to_input(&article.unpublish_at));
+                || {}
+            },
+            article.clone(),
+        );
+    };
+
+    let on_change_publish_at: Callback<ChangeData> = Callback::from(move |event: ChangeData| {
+        if let ChangeData::Value(value) = event {
+            set_publish_at(value);
+        }
+    });
+    let on_change_unpublish_at: Callback<ChangeData> = Callback::from(move |event: ChangeData| {
+        if let ChangeData::Value(value) = event {
+            set_unpublish_at(value);
+        }
+    });
+
+    let on_save: Callback<MouseEvent> = {
+        let (article_id, set_loading, set_field_errors, dispatch_article, dispatch_error) = (
+            article.id,
+            set_loading.clone(),
+            set_field_errors.clone(),
+            dispatch_article.clone(),
+            dispatch_error.clone(),
+        );
+        let payload = Rc::new(IScheduleArticle {
+            publish_at: from_input(&publish_at),
+            unpublish_at: from_input(&unpublish_at),
+        });
+        Callback::from(move |_| {
+            set_loading(true);
+            let (payload, set_loading, set_field_errors, dispatch_article, dispatch_error) = (
+                payload.clone(),
+                set_loading.clone(),
+                set_field_errors.clone(),
+                dispatch_article.clone(),
+                dispatch_error.clone(),
+            );
+            let future = async move { schedule_article(&article_id, &payload).await };
+            handle_future(future, move |data: Result<IArticle, Status>| {
+                match data {
+                    Ok(article) => {
+                        set_field_errors(vec![]);
+                        dispatch_article.emit(article);
+                    }
+                    Err(Status::Failure(error)) if !error.fields.is_empty() => {
+                        set_field_errors(error.fields)
+                    }
+                    Err(status) => dispatch_error.emit(status),
+                };
+                set_loading(false);
+            });
+        })
+    };
+
+    html! {
+        <div class="schedule">
+            <label>
+                {"Publish on"}
+                <input type="datetime-local" value={(*publish_at).clone()} onchange={on_change_publish_at} />
+            </label>
+            <label>
+                {"Unpublish on"}
+                <input type="datetime-local" value={(*unpublish_at).clone()} onchange={on_change_unpublish_at} />
+            </label>
+            <div style="font-size: .8em;">
+                {if *is_loading {
+                    html! { <Loader /> }
+                } else {
+                    html! { <Button onclick={on_save} label="Schedule" /> }
+                }}
+            </div>
+            <FieldErrors errors={field_errors.clone()} field="unpublish_at" />
+        </div>
+    }
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct ScheduledArticlesProps {
+    #[prop_or_default]
+    pub dispatch_error: Callback<Status>,
+}
+
+// Articles waiting for the scheduler, soonest first.
+#[function_component(ScheduledArticles)]
+pub fn scheduled_articles(
+    ScheduledArticlesProps { dispatch_error }: &ScheduledArticlesProps,
+) -> Html {
+    let (articles, set_articles) = use_state(Vec::<IScheduledArticle>::new);
+
+    {
+        let dispatch_error = dispatch_error.clone();
+        use_effect_with_deps(
+            move |_| {
+                let future = async move { get_scheduled_articles().await };
+                handle_future(
+                    future,
+                    move |data: Result<Vec<IScheduledArticle>, Status>| {
+                        match data {
+                            Ok(articles) => set_articles(articles),
+                            Err(status) => dispatch_error.emit(status),
+                        };
+                    },
+                );
+                || {}
+            },
+            (),
+        );
+    };
+
+    if articles.is_empty() {
+        return html! {};
+    }
+
+    html! {
+        <section class="scheduled-articles">
+            <h2 class="article-chapter">{"Scheduled"}</h2>
+            <ul>
+                {for articles.iter().map(|article| html! {
+                    <li>
+                        <RouterAnchor<AppRoute> route={AppRoute::Article { id: article.id }}>
+                            {&article.title}
+                        </RouterAnchor<AppRoute>>
+                        {match &article.publish_at {
+                            Some(publish_at) => html! {
+                                <span class="scheduled-time">{format!("Publishes on {}", view_datetime(publish_at))}</span>
+                            },
+                            None => html! {},
+                        }}
+                        {match &article.unpublish_at {
+                            Some(unpublish_at) => html! {
+                                <span class="scheduled-time">{format!("Unpublishes on {}", view_datetime(unpublish_at))}</span>
+                            },
+                            None => html! {},
+                        }}
+                    </li>
+                })}
+            </ul>
+        </section>
+    }
+}
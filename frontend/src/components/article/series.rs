@@ -0,0 +1,44 @@
+use {
+    crate::{
+        entities::interfaces::{IArticleSeries, ISeriesPart},
+        routes::AppRoute,
+    },
+    yew::{html, Html, Properties},
+    yew_functional::function_component,
+    yew_router::components::RouterAnchor,
+};
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct SeriesBoxProps {
+    pub series: IArticleSeries,
+}
+
+fn part_link(part: &Option<ISeriesPart>, label: &str) -> Html {
+    match part {
+        Some(part) => html! {
+            <RouterAnchor<AppRoute> route={AppRoute::Article { id: part.id }}>
+                <span class="series-link">{format!("{} {}", label, part.title)}</span>
+            </RouterAnchor<AppRoute>>
+        },
+        None => html! { <span /> },
+    }
+}
+
+// Position of the article in its series, with links to the series and the neighbouring parts.
+#[function_component(SeriesBox)]
+pub fn series_box(SeriesBoxProps { series }: &SeriesBoxProps) -> Html {
+    html! {
+        <div class="series-box">
+            <p>
+                {format!("Part {} of {} in ", series.part, series.parts)}
+                <RouterAnchor<AppRoute> route={AppRoute::Series { id: series.id }}>
+                    <span class="series-link">{&series.title}</span>
+                </RouterAnchor<AppRoute>>
+            </p>
+            <div class="series-navigation">
+                {part_link(&series.previous, "←")}
+                {part_link(&series.next, "→")}
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,21 @@
+use {
+    crate::entities::callout::CalloutKind,
+    yew::{html, Properties},
+    yew_functional::function_component,
+};
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct CalloutProps {
+    pub kind: CalloutKind,
+    pub text: String,
+}
+
+#[function_component(Callout)]
+pub fn callout(CalloutProps { kind, text }: &CalloutProps) -> Html {
+    html! {
+        <aside class={kind.class()} role="note">
+            <i class={kind.icon()} aria-label={kind.to_string()} />
+            <p style="white-space: break-spaces;">{text}</p>
+        </aside>
+    }
+}
@@ -1,19 +1,45 @@
 use {
-    yew::{html, Callback, Html, MouseEvent, Properties},
-    yew_functional::function_component,
+    crate::{
+        entities::language::Language,
+        service::future::handle_future,
+        utils::{
+            clipboard::copy_to_clipboard,
+            html::raw_html,
+            line_ranges::{in_line_ranges, parse_line_ranges},
+        },
+    },
+    wasm_bindgen::{closure::Closure, JsCast},
+    yew::{html, utils::window, Callback, Html, MouseEvent, Properties},
+    yew_functional::{function_component, use_state},
 };
 
-fn highlight_code_in_html(highlighted_code: &str) -> Html {
-    let div = yew::utils::document().create_element("div").unwrap();
-    div.set_inner_html(highlighted_code);
+// How long the result of a copy is shown on the button.
+const COPY_FEEDBACK_MS: i32 = 2000;
 
-    Html::VRef(div.into())
+#[derive(Clone, Copy, PartialEq)]
+enum CopyState {
+    Idle,
+    Copied,
+    Failed,
 }
 
 #[derive(Properties, Clone, PartialEq)]
 pub struct CodeProps {
     #[prop_or_default]
     pub highlighted_code: String,
+    // Raw code, copied to the clipboard and used to count the lines of the gutter.
+    #[prop_or_default]
+    pub content: String,
+    #[prop_or_default]
+    pub language: Option<Language>,
+    #[prop_or_default]
+    pub caption: Option<String>,
+    #[prop_or(1)]
+    pub start_line: i32,
+    #[prop_or_default]
+    pub added_lines: String,
+    #[prop_or_default]
+    pub removed_lines: String,
     #[prop_or_default]
     pub onclick: Callback<MouseEvent>,
 }
@@ -22,12 +48,93 @@ pub struct CodeProps {
 pub fn code(
     CodeProps {
         highlighted_code,
+        content,
+        language,
+        caption,
+        start_line,
+        added_lines,
+        removed_lines,
         onclick,
     }: &CodeProps,
 ) -> Html {
+    let (copy_state, set_copy_state) = use_state(|| CopyState::Idle);
+
+    let on_copy: Callback<MouseEvent> = {
+        let (content, set_copy_state) = (content.clone(), set_copy_state.clone());
+        Callback::from(move |event: MouseEvent| {
+            // Copying is not a click on the code block itself (which opens the editor).
+            event.stop_propagation();
+            let (content, set_copy_state) = (content.clone(), set_copy_state.clone());
+            let future = async move { copy_to_clipboard(&content).await };
+            handle_future(future, move |result| {
+                set_copy_state(match result {
+                    Ok(_) => CopyState::Copied,
+                    Err(_) => CopyState::Failed,
+                });
+                let reset = {
+                    let set_copy_state = set_copy_state.clone();
+                    Closure::once_into_js(move || set_copy_state(CopyState::Idle))
+                };
+                let _ = window().set_timeout_with_callback_and_timeout_and_arguments_0(
+                    reset.unchecked_ref(),
+                    COPY_FEEDBACK_MS,
+                );
+            });
+        })
+    };
+
+    let (added_lines, removed_lines) = (
+        parse_line_ranges(added_lines),
+        parse_line_ranges(removed_lines),
+    );
+
     html! {
-        <div style="flex: 1; overflow-x: hidden;" onclick=onclick>
-            {highlight_code_in_html(highlighted_code)}
-        </div>
+        <figure class="code-block" onclick=onclick>
+            <figcaption class="code-toolbar">
+                <span class="code-caption">
+                    {caption.as_deref().unwrap_or_default()}
+                </span>
+                {match language {
+                    Some(language) => html! {
+                        <span class="code-language">{language.label()}</span>
+                    },
+                    None => html! {}
+                }}
+                <button
+                    class={match *copy_state {
+                        CopyState::Idle => "code-copy",
+                        CopyState::Copied => "code-copy code-copy-success",
+                        CopyState::Failed => "code-copy code-copy-failure",
+                    }}
+                    title="Copy the code"
+                    onclick=on_copy
+                >
+                    {match *copy_state {
+                        CopyState::Idle => html! { <><i class="fa fa-clipboard"/>{" Copy"}</> },
+                        CopyState::Copied => html! { <><i class="fa fa-check"/>{" Copied"}</> },
+                        CopyState::Failed => html! { <><i class="fa fa-times"/>{" Failed"}</> },
+                    }}
+                </button>
+            </figcaption>
+            <div class="code-body">
+                <pre class="code-gutter" aria-hidden="true">
+                    {for (*start_line..).take(content.lines().count()).map(|line| {
+                        let (class, marker) = if in_line_ranges(&added_lines, line) {
+                            ("code-gutter-line code-gutter-added", "+")
+                        } else if in_line_ranges(&removed_lines, line) {
+                            ("code-gutter-line code-gutter-removed", "-")
+                        } else {
+                            ("code-gutter-line", " ")
+                        };
+                        html! {
+                            <span class={class}>{format!("{} {}\n", line, marker)}</span>
+                        }
+                    })}
+                </pre>
+                <div class="code-lines">
+                    {raw_html(highlighted_code)}
+                </div>
+            </div>
+        </figure>
     }
 }
@@ -0,0 +1,24 @@
+use {
+    crate::entities::interfaces::IFieldError,
+    std::rc::Rc,
+    yew::{html, Properties},
+    yew_functional::function_component,
+};
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct FieldErrorsProps {
+    pub errors: Rc<Vec<IFieldError>>,
+    pub field: &'static str,
+}
+
+// Validation messages returned by the API for a single form field.
+#[function_component(FieldErrors)]
+pub fn field_errors(FieldErrorsProps { errors, field }: &FieldErrorsProps) -> Html {
+    html! {
+        <>
+            {for errors.iter().filter(|error| error.field == *field).map(|error| html! {
+                <p style="color: rgb(178, 34, 34); font-size: .8em; margin-top: 4px;">{&error.message}</p>
+            })}
+        </>
+    }
+}
@@ -1,5 +1,8 @@
 use {
-    crate::{components::navbar::Navbar, entities::interfaces::SearchResults},
+    crate::{
+        components::navbar::Navbar,
+        entities::interfaces::{SearchResults, Status},
+    },
     yew::{html, Callback, Properties},
     yew_functional::function_component,
 };
@@ -7,7 +10,7 @@ use {
 #[derive(Properties, Clone, PartialEq)]
 pub struct HeaderProps {
     pub dispatch_search_results: Callback<(String, SearchResults)>,
-    pub dispatch_error: Callback<bool>,
+    pub dispatch_error: Callback<Status>,
 }
 
 #[function_component(Header)]
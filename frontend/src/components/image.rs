@@ -0,0 +1,52 @@
+use {
+    crate::entities::image::IImage,
+    std::rc::Rc,
+    yew::{html, Properties},
+    yew_functional::function_component,
+};
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct ImageProps {
+    pub src: String,
+    pub image: Rc<IImage>,
+    #[prop_or_default]
+    pub caption: Option<String>,
+}
+
+#[function_component(Image)]
+pub fn image(
+    ImageProps {
+        src,
+        image,
+        caption,
+    }: &ImageProps,
+) -> Html {
+    // The space of the image is kept while it loads.
+    let style = match (image.width, image.height) {
+        (Some(width), Some(height)) => format!("aspect-ratio: {} / {};", width, height),
+        _ => String::new(),
+    };
+    let caption = caption.as_deref().filter(|caption| !caption.is_empty());
+    let credits = image
+        .credits
+        .as_deref()
+        .filter(|credits| !credits.is_empty());
+
+    html! {
+        <figure class="image">
+            <img src={src} alt={&image.alt} style={style} loading="lazy" />
+            {if caption.is_some() || credits.is_some() {
+                html! {
+                    <figcaption>
+                        {caption.unwrap_or_default()}
+                        {credits.map_or_else(|| html! {}, |credits| html! {
+                            <span class="image-credits">{credits}</span>
+                        })}
+                    </figcaption>
+                }
+            } else {
+                html! {}
+            }}
+        </figure>
+    }
+}
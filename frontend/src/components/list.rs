@@ -0,0 +1,41 @@
+use {
+    crate::entities::list::{IList, IListItem},
+    std::rc::Rc,
+    yew::{html, Html, Properties},
+    yew_functional::function_component,
+};
+
+fn view_items(items: &[IListItem], ordered: bool) -> Html {
+    let items = html! {
+        <>{for items.iter().map(|item| html! {
+            <li>
+                {&item.text}
+                {if item.items.is_empty() {
+                    html! {}
+                } else {
+                    view_items(&item.items, ordered)
+                }}
+            </li>
+        })}</>
+    };
+    if ordered {
+        html! { <ol>{items}</ol> }
+    } else {
+        html! { <ul>{items}</ul> }
+    }
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct ListProps {
+    pub list: Rc<IList>,
+}
+
+// Nested items keep the numbering style of the list.
+#[function_component(List)]
+pub fn list(ListProps { list }: &ListProps) -> Html {
+    html! {
+        <div class="content-list">
+            {view_items(&list.items, list.ordered)}
+        </div>
+    }
+}
@@ -1,23 +1,33 @@
 pub mod article;
 pub mod button;
+pub mod callout;
 pub mod checkbox;
 pub mod code;
+pub mod field_errors;
 pub mod footer;
 pub mod header;
+pub mod image;
+pub mod list;
 pub mod loader;
 pub mod modal;
 pub mod navbar;
 pub mod navlink;
 pub mod navlinks;
 pub mod page_not_found;
+pub mod player;
 pub mod project;
 pub mod projects;
+pub mod quote;
 pub mod results;
 pub mod resume_project;
 pub mod search;
 pub mod select;
+pub mod series;
 pub mod slideshow;
 pub mod switch;
+pub mod table;
+#[cfg(feature = "editable")]
+pub mod table_editor;
 pub mod tag;
 pub mod tag_label;
 pub mod tags;
@@ -1,7 +1,7 @@
 use {
     crate::{
         components::{modal::Modal, navlinks::NavLinks, search::Search},
-        entities::interfaces::SearchResults,
+        entities::interfaces::{SearchResults, Status},
     },
     yew::{html, Callback, Properties},
     yew_functional::{function_component, use_state},
@@ -10,7 +10,7 @@ use {
 #[derive(Properties, Clone, PartialEq)]
 pub struct NavBarProps {
     pub dispatch_search_results: Callback<(String, SearchResults)>,
-    pub dispatch_error: Callback<bool>,
+    pub dispatch_error: Callback<Status>,
 }
 
 #[function_component(Navbar)]
@@ -1,5 +1,8 @@
 use {
-    crate::{components::navlink::NavLink, routes::AppRoute, store::store::BlogStore, API_URL},
+    crate::{
+        components::navlink::NavLink, entities::interfaces::Status, routes::AppRoute,
+        store::store::BlogStore, API_URL,
+    },
     std::{collections::HashMap, rc::Rc},
     yew::{html, Callback},
     yew_functional::{function_component, use_context, use_state},
@@ -24,6 +27,11 @@ const NAV_LINKS: &[TNavLink] = &[
         label_on_hover: "$ cd ~/tags",
         route: Some(AppRoute::Tags),
     },
+    TNavLink {
+        label: "/series",
+        label_on_hover: "$ cd ~/series",
+        route: Some(AppRoute::SeriesList),
+    },
     TNavLink {
         label: "/projects",
         label_on_hover: "$ cd ~/projects",
@@ -46,7 +54,19 @@ const RESUME_SLUG: &str = "/media/resume/resume_Guillaume_B_2024.pdf";
 #[function_component(NavLinks)]
 pub fn navlinks() -> Html {
     let context = use_context::<Rc<BlogStore>>().expect("No context found!");
-    let is_error = context.is_error;
+    // API errors are summed up next to the logo, with their field details in the tooltip.
+    let error = context.error.as_ref().map(|status| match status {
+        Status::Failure(error) => (
+            error.message.clone(),
+            error
+                .fields
+                .iter()
+                .map(|field| [field.field.as_str(), ": ", field.message.as_str()].concat())
+                .collect::<Vec<String>>()
+                .join("\n"),
+        ),
+        _ => ("Error".to_owned(), String::new()),
+    });
 
     let (hovered_nav_links, set_hovered_nav_links) = {
         use_state(move || {
@@ -85,17 +105,16 @@ pub fn navlinks() -> Html {
                 <RouterAnchor<AppRoute> route={AppRoute::Home}>
                     <NavLink label="guimauve" />
                 </RouterAnchor<AppRoute>>
-                {if is_error {
-                    html! {
-                        <div style="display: flex; flex: 0; margin-left: 12px; align-items: center; justify-content: center;" >
+                {match error {
+                    Some((message, details)) => html! {
+                        <div title={details} style="display: flex; flex: 0; margin-left: 12px; align-items: center; justify-content: center;" >
                             <i style="color: rgb(178, 34, 34);" class="fa fa-exclamation-triangle"></i>
                             <div style="margin-left: 8px; align-items: baseline;">
-                                <p style="font-size: .7rem;">{"Error"}</p>
+                                <p style="font-size: .7rem; white-space: nowrap;">{message}</p>
                             </div>
                         </div>
-                    }
-                } else {
-                    html! {}
+                    },
+                    None => html! {}
                 }}
             </div>
             <div style="display: flex; flex: 1; justify-content: center;">
@@ -0,0 +1,121 @@
+use {
+    crate::{
+        entities::player::{EmbedProvider, IPlayer},
+        API_URL,
+    },
+    std::rc::Rc,
+    yew::{html, Callback, Html, MouseEvent, Properties},
+    yew_functional::{function_component, use_state},
+};
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct PlayerProps {
+    // Self-hosted file, with the API URL.
+    #[prop_or_default]
+    pub src: String,
+    // External video.
+    #[prop_or_default]
+    pub url: Option<String>,
+    pub player: Rc<IPlayer>,
+    #[prop_or_default]
+    pub caption: Option<String>,
+}
+
+fn view_tracks(player: &IPlayer) -> Html {
+    html! {
+        <>
+            {for player.tracks.iter().map(|track| html! {
+                <track
+                    kind={track.kind.as_str()}
+                    src={format!("{}{}", API_URL, track.src)}
+                    srclang={track.srclang.clone()}
+                    label={track.label.clone()}
+                />
+            })}
+        </>
+    }
+}
+
+fn view_caption(caption: &Option<String>) -> Html {
+    match caption.as_deref() {
+        Some(caption) if !caption.is_empty() => html! { <figcaption>{caption}</figcaption> },
+        _ => html! {},
+    }
+}
+
+// External players are only loaded (and reach their provider) once the reader clicks on them.
+#[function_component(Video)]
+pub fn video(
+    PlayerProps {
+        src,
+        url,
+        player,
+        caption,
+    }: &PlayerProps,
+) -> Html {
+    let (loaded, set_loaded) = use_state(|| false);
+    let on_load: Callback<MouseEvent> = Callback::from(move |_| set_loaded(true));
+
+    let poster = player
+        .poster
+        .as_ref()
+        .map(|poster| format!("{}{}", API_URL, poster))
+        .unwrap_or_default();
+
+    html! {
+        <figure class="player">
+            {match url.as_deref().and_then(EmbedProvider::parse) {
+                Some((provider, id)) if *loaded => html! {
+                    <div class="player-embed">
+                        <iframe
+                            src={provider.embed_url(&id)}
+                            title={caption.clone().unwrap_or_else(|| provider.name().to_owned())}
+                            allow="autoplay; fullscreen; picture-in-picture"
+                            allowfullscreen="allowfullscreen"
+                        />
+                    </div>
+                },
+                Some((provider, _)) => html! {
+                    <div
+                        class="player-embed player-consent"
+                        style={if poster.is_empty() {
+                            String::new()
+                        } else {
+                            format!("background-image: url('{}');", poster)
+                        }}
+                    >
+                        <button onclick={on_load}>
+                            <i class="fa fa-play" />{format!(" Play on {}", provider.name())}
+                        </button>
+                        <p>{format!("The video is loaded from {}, which may set cookies.", provider.name())}</p>
+                    </div>
+                },
+                None => html! {
+                    <video controls="controls" preload="metadata" src={src.clone()} poster={poster}>
+                        {view_tracks(player)}
+                    </video>
+                },
+            }}
+            {view_caption(caption)}
+        </figure>
+    }
+}
+
+#[function_component(Audio)]
+pub fn audio(
+    PlayerProps {
+        src,
+        player,
+        caption,
+        ..
+    }: &PlayerProps,
+) -> Html {
+    html! {
+        <figure class="player player-audio">
+            <audio controls="controls" preload="metadata" src={src.clone()}>
+                {view_tracks(player)}
+            </audio>
+            {view_caption(caption)}
+        </figure>
+    }
+}
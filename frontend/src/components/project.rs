@@ -39,7 +39,7 @@ pub fn project(
         <div style="display: flex; flex: 1; font-size: 0.8em; flex-direction: column;">
             <div style="display: flex; flex: 1">
                 <div style="margin-right: 12px">
-                    <img style="width: 8em;" src={&project.image} />
+                    <img style="width: 8em;" src={&project.image} alt={&project.title} />
                 </div>
                 <div style="display: flex; flex: 1; flex-direction: column;">
                     <div style="margin-bottom: 4px;">
@@ -93,7 +93,7 @@ pub fn project(
                                                                        }
                                                                     >
                                                                         <div style="margin-left: 8px; position: relative; br: 2px; font-size: 0; width: 50px;">
-                                                                            <img src={image} style="width: 100%;"/>
+                                                                            <img src={&image.image} alt={&image.alt} style="width: 100%;"/>
                                                                             <div class="project-gallery-img-container-middle"></div>
                                                                         </div>
                                                                    </div>
@@ -114,7 +114,7 @@ pub fn project(
                                                             }}
                                                             body={html! {
                                                                 <Slideshow selected_image_index={*selected_image_index}
-                                                                           selected_image_url={gallery.get(*selected_image_index).expect("Invalid index!")}
+                                                                           selected_image={gallery.get(*selected_image_index).expect("Invalid index!").clone()}
                                                                            slideshow_length={gallery.len()}
                                                                            /* NOTE - Identified as a redudant closure by cargo clippy. But cloning the function
                                                                             * and passing it to the callback doesn't work */
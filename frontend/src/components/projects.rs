@@ -17,7 +17,7 @@ use {
 #[derive(Properties, Clone, PartialEq)]
 pub struct ProjectsProps {
     pub dispatch_projects: Callback<HashMap<i32, IProject>>,
-    pub dispatch_error: Callback<bool>,
+    pub dispatch_error: Callback<Status>,
 }
 
 #[function_component(Projects)]
@@ -62,7 +62,7 @@ pub fn projects(
                 move |data: Result<HashMap<i32, IProject>, Status>| {
                     match data {
                         Ok(projects) => dispatch.emit(projects),
-                        Err(_) => dispatch_error.emit(true),
+                        Err(status) => dispatch_error.emit(status),
                     };
                     set_loading(false);
                 },
@@ -0,0 +1,34 @@
+use {
+    crate::entities::quote::IQuote,
+    std::rc::Rc,
+    yew::{html, Properties},
+    yew_functional::function_component,
+};
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct QuoteProps {
+    pub quote: Rc<IQuote>,
+    pub text: String,
+}
+
+#[function_component(Quote)]
+pub fn quote(QuoteProps { quote, text }: &QuoteProps) -> Html {
+    let source_url = quote.source_url.clone().unwrap_or_default();
+    html! {
+        <figure class="quote">
+            <blockquote cite={source_url.clone()}>
+                <p style="white-space: break-spaces;">{text}</p>
+            </blockquote>
+            {match (quote.attribution.as_deref(), quote.source_url.as_deref()) {
+                (Some(attribution), Some(url)) => html! {
+                    <figcaption>{"— "}<a target="_blank" href={url.to_owned()}>{attribution}</a></figcaption>
+                },
+                (Some(attribution), None) => html! { <figcaption>{"— "}{attribution}</figcaption> },
+                (None, Some(url)) => html! {
+                    <figcaption>{"— "}<a target="_blank" href={url.to_owned()}>{url}</a></figcaption>
+                },
+                (None, None) => html! {},
+            }}
+        </figure>
+    }
+}
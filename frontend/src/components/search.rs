@@ -17,7 +17,7 @@ use {
 #[derive(Properties, Clone, PartialEq)]
 pub struct SearchProps {
     pub dispatch_search_results: Callback<(String, SearchResults)>,
-    pub dispatch_error: Callback<bool>,
+    pub dispatch_error: Callback<Status>,
     pub on_click_result: Callback<MouseEvent>,
 }
 
@@ -98,7 +98,7 @@ pub fn search(
                 handle_future(future, move |data: Result<SearchResults, Status>| {
                     match data {
                         Ok(results) => dispatch_search_results.emit(((*query).clone(), results)),
-                        Err(_) => dispatch_error.emit(true),
+                        Err(status) => dispatch_error.emit(status),
                     };
                     set_loading(false);
                 });
@@ -0,0 +1,468 @@
+use {
+    crate::{
+        components::loader::Loader,
+        entities::interfaces::{ISeries, ISeriesPart, ISeriesSummary, Status},
+        routes::AppRoute,
+        service::{
+            future::handle_future,
+            series::{get_series, get_series_list},
+        },
+        utils::date::format_date,
+    },
+    yew::{html, Callback, Html, Properties},
+    yew_functional::{function_component, use_effect_with_deps, use_state},
+    yew_router::components::RouterAnchor,
+};
+
+#[cfg(feature = "editable")]
+use {
+    crate::{
+        components::{
+            button::{Button, ButtonVariant},
+            field_errors::FieldErrors,
+            text_area::TextArea,
+            text_input::TextInput,
+        },
+        entities::interfaces::{IArticle, IFieldError, IInputSeries, ISeriesParts},
+        service::{
+            articles::get_article_list,
+            series::{add_series, delete_series, set_series_parts, update_series},
+        },
+    },
+    std::{collections::HashMap, rc::Rc},
+    yew::{ChangeData, MouseEvent},
+    yew_router::agent::{RouteAgentDispatcher, RouteRequest},
+};
+
+fn view_loader(is_loading: bool) -> Html {
+    if is_loading {
+        html! {
+            <div style="align-items: center; justify-content: center; display: flex; margin-top: 24px; margin-bottom: 24px;">
+                <Loader />
+            </div>
+        }
+    } else {
+        html! {}
+    }
+}
+
+fn view_part(index: usize, part: &ISeriesPart) -> Html {
+    html! {
+        <RouterAnchor<AppRoute> route={AppRoute::Article { id: part.id }}>
+            <div class="series-part">
+                <h2>
+                    {format!("{}. {}", index + 1, part.title)}
+                    {if part.published { html! {} } else { html! { <span class="series-draft">{" (draft)"}</span> } }}
+                </h2>
+                <p>{&part.headline}</p>
+                <p class="series-date">{format_date(&part.pub_date).unwrap_or_else(|_|"An error occured.".to_string())}</p>
+            </div>
+        </RouterAnchor<AppRoute>>
+    }
+}
+
+// The parts in their new order after moving the part at `index` to `to`, or removing it.
+#[cfg(feature = "editable")]
+fn moved(ids: &[i32], index: usize, to: Option<usize>) -> Vec<i32> {
+    let mut ids = ids.to_vec();
+    let id = ids.remove(index);
+    if let Some(to) = to {
+        ids.insert(to, id);
+    }
+    ids
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct SeriesProps {
+    pub id: i32,
+    #[prop_or_default]
+    pub dispatch_error: Callback<Status>,
+}
+
+// Parts of a series in reading order.
+#[function_component(Series)]
+pub fn series(SeriesProps { id, dispatch_error }: &SeriesProps) -> Html {
+    let (is_loading, set_loading) = use_state(|| false);
+    let (series, set_series) = use_state(|| None::<ISeries>);
+
+    {
+        let (set_series, dispatch_error) = (set_series.clone(), dispatch_error.clone());
+        use_effect_with_deps(
+            move |id| {
+                set_loading(true);
+                let id = *id;
+                let future = async move { get_series(&id).await };
+                handle_future(future, move |data: Result<ISeries, Status>| {
+                    match data {
+                        Ok(series) => set_series(Some(series)),
+                        Err(status) => dispatch_error.emit(status),
+                    };
+                    set_loading(false);
+                });
+                || {}
+            },
+            *id,
+        );
+    }
+
+    #[cfg(feature = "editable")]
+    let editor = match &*series {
+        Some(series) => html! {
+            <SeriesEditor
+                series={Rc::new(series.clone())}
+                dispatch_series={Callback::from(move |series: ISeries| set_series(Some(series)))}
+                dispatch_error={dispatch_error}
+            />
+        },
+        None => html! {},
+    };
+    #[cfg(not(feature = "editable"))]
+    let editor = html! {};
+
+    html! {
+        <div style="display: flex; justify-content: center; flex: 1;">
+            <div style="flex: 1; max-width: 1024px;">
+                {match &*series {
+                    Some(series) => html! {
+                        <>
+                            <div style="align-items: center; display: flex; margin-bottom: 24px;">
+                                <h1 class="heading">{&series.title}</h1>
+                            </div>
+                            <p class="series-description">{&series.description}</p>
+                            {for series.parts.iter().enumerate().map(|(index, part)| view_part(index, part))}
+                        </>
+                    },
+                    None => html! {},
+                }}
+                {editor}
+                {view_loader(*is_loading)}
+            </div>
+        </div>
+    }
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct SeriesListProps {
+    #[prop_or_default]
+    pub dispatch_error: Callback<Status>,
+}
+
+#[function_component(SeriesList)]
+pub fn series_list(SeriesListProps { dispatch_error }: &SeriesListProps) -> Html {
+    let (is_loading, set_loading) = use_state(|| false);
+    let (series_list, set_series_list) = use_state(Vec::<ISeriesSummary>::new);
+
+    {
+        let dispatch_error = dispatch_error.clone();
+        use_effect_with_deps(
+            move |_| {
+                set_loading(true);
+                let future = async move { get_series_list().await };
+                handle_future(future, move |data: Result<Vec<ISeriesSummary>, Status>| {
+                    match data {
+                        Ok(series_list) => set_series_list(series_list),
+                        Err(status) => dispatch_error.emit(status),
+                    };
+                    set_loading(false);
+                });
+                || {}
+            },
+            (),
+        );
+    }
+
+    #[cfg(feature = "editable")]
+    let new_series = html! { <NewSeries dispatch_error={dispatch_error} /> };
+    #[cfg(not(feature = "editable"))]
+    let new_series = html! {};
+
+    html! {
+        <div style="display: flex; justify-content: center; flex: 1;">
+            <div style="flex: 1; max-width: 1024px;">
+                <div style="align-items: center; display: flex; margin-bottom: 24px;">
+                    <h1 class="heading">{"/series"}</h1>
+                </div>
+                {for series_list.iter().map(|series| html! {
+                    <RouterAnchor<AppRoute> route={AppRoute::Series { id: series.id }}>
+                        <div class="series-part">
+                            <h2>{&series.title}</h2>
+                            <p>{&series.description}</p>
+                        </div>
+                    </RouterAnchor<AppRoute>>
+                })}
+                {new_series}
+                {view_loader(*is_loading)}
+            </div>
+        </div>
+    }
+}
+
+#[cfg(feature = "editable")]
+#[derive(Properties, Clone, PartialEq)]
+pub struct NewSeriesProps {
+    #[prop_or_default]
+    pub dispatch_error: Callback<Status>,
+}
+
+// Create an empty series, then open it to add its parts.
+#[cfg(feature = "editable")]
+#[function_component(NewSeries)]
+pub fn new_series(NewSeriesProps { dispatch_error }: &NewSeriesProps) -> Html {
+    let (field_errors, set_field_errors) = use_state(Vec::<IFieldError>::new);
+    let (title, set_title) = use_state(String::new);
+    let (description, set_description) = use_state(String::new);
+
+    let on_change_title: Callback<ChangeData> = Callback::from(move |event: ChangeData| {
+        if let ChangeData::Value(value) = event {
+            set_title(value);
+        }
+    });
+    let on_change_description: Callback<ChangeData> = Callback::from(move |event: ChangeData| {
+        if let ChangeData::Value(value) = event {
+            set_description(value);
+        }
+    });
+
+    let on_create: Callback<MouseEvent> = {
+        let payload = Rc::new(IInputSeries {
+            title: (*title).clone(),
+            description: (*description).clone(),
+        });
+        let dispatch_error = dispatch_error.clone();
+        Callback::from(move |_| {
+            let (payload, set_field_errors, dispatch_error) = (
+                payload.clone(),
+                set_field_errors.clone(),
+                dispatch_error.clone(),
+            );
+            let future = async move { add_series(&payload).await };
+            handle_future(future, move |data: Result<ISeries, Status>| {
+                match data {
+                    Ok(series) => RouteAgentDispatcher::<()>::new().send(
+                        RouteRequest::ChangeRoute((AppRoute::Series { id: series.id }).into()),
+                    ),
+                    Err(Status::Failure(error)) if !error.fields.is_empty() => {
+                        set_field_errors(error.fields)
+                    }
+                    Err(status) => dispatch_error.emit(status),
+                };
+            });
+        })
+    };
+
+    html! {
+        <div class="series-form">
+            <h2>{"New series"}</h2>
+            <TextInput value={(*title).clone()} onchange={on_change_title} placeholder="Title" />
+            <FieldErrors errors={field_errors.clone()} field="title" />
+            <TextArea value={(*description).clone()} onchange={on_change_description} rows={3} />
+            <Button onclick={on_create} label="Create" />
+        </div>
+    }
+}
+
+#[cfg(feature = "editable")]
+#[derive(Properties, Clone, PartialEq)]
+pub struct SeriesEditorProps {
+    pub series: Rc<ISeries>,
+    pub dispatch_series: Callback<ISeries>,
+    pub dispatch_error: Callback<Status>,
+}
+
+// Title, description and parts of the series. Every change of the parts is saved at once.
+#[cfg(feature = "editable")]
+#[function_component(SeriesEditor)]
+pub fn series_editor(
+    SeriesEditorProps {
+        series,
+        dispatch_series,
+        dispatch_error,
+    }: &SeriesEditorProps,
+) -> Html {
+    let (is_loading, set_loading) = use_state(|| false);
+    let (field_errors, set_field_errors) = use_state(Vec::<IFieldError>::new);
+    let (title, set_title) = use_state(|| series.title.clone());
+    let (description, set_description) = use_state(|| series.description.clone());
+    let (articles, set_articles) = use_state(HashMap::<i32, IArticle>::new);
+    let (selected, set_selected) = use_state(|| None::<i32>);
+
+    {
+        let dispatch_error = dispatch_error.clone();
+        use_effect_with_deps(
+            move |_| {
+                let future = async move { get_article_list().await };
+                handle_future(
+                    future,
+                    move |data: Result<HashMap<i32, IArticle>, Status>| {
+                        match data {
+                            Ok(articles) => set_articles(articles),
+                            Err(status) => dispatch_error.emit(status),
+                        };
+                    },
+                );
+                || {}
+            },
+            (),
+        );
+    }
+
+    let on_change_title: Callback<ChangeData> = Callback::from(move |event: ChangeData| {
+        if let ChangeData::Value(value) = event {
+            set_title(value);
+        }
+    });
+    let on_change_description: Callback<ChangeData> = Callback::from(move |event: ChangeData| {
+        if let ChangeData::Value(value) = event {
+            set_description(value);
+        }
+    });
+    let on_select: Callback<ChangeData> = {
+        let set_selected = set_selected.clone();
+        Callback::from(move |event: ChangeData| {
+            if let ChangeData::Select(select) = event {
+                set_selected(select.value().parse().ok());
+            }
+        })
+    };
+
+    let on_save: Callback<MouseEvent> = {
+        let (series_id, set_loading, set_field_errors, dispatch_series, dispatch_error) = (
+            series.id,
+            set_loading.clone(),
+            set_field_errors.clone(),
+            dispatch_series.clone(),
+            dispatch_error.clone(),
+        );
+        let payload = Rc::new(IInputSeries {
+            title: (*title).clone(),
+            description: (*description).clone(),
+        });
+        Callback::from(move |_| {
+            set_loading(true);
+            let (payload, set_loading, set_field_errors, dispatch_series, dispatch_error) = (
+                payload.clone(),
+                set_loading.clone(),
+                set_field_errors.clone(),
+                dispatch_series.clone(),
+                dispatch_error.clone(),
+            );
+            let future = async move { update_series(&series_id, &payload).await };
+            handle_future(future, move |data: Result<ISeries, Status>| {
+                match data {
+                    Ok(series) => {
+                        set_field_errors(vec![]);
+                        dispatch_series.emit(series);
+                    }
+                    Err(Status::Failure(error)) if !error.fields.is_empty() => {
+                        set_field_errors(error.fields)
+                    }
+                    Err(status) => dispatch_error.emit(status),
+                };
+                set_loading(false);
+            });
+        })
+    };
+
+    let on_delete: Callback<MouseEvent> = {
+        let (series_id, dispatch_error) = (series.id, dispatch_error.clone());
+        Callback::from(move |_| {
+            let dispatch_error = dispatch_error.clone();
+            let future = async move { delete_series(&series_id).await };
+            handle_future(future, move |data: Result<(), Status>| {
+                match data {
+                    Ok(()) => RouteAgentDispatcher::<()>::new()
+                        .send(RouteRequest::ChangeRoute(AppRoute::SeriesList.into())),
+                    Err(status) => dispatch_error.emit(status),
+                };
+            });
+        })
+    };
+
+    let save_parts: Rc<dyn Fn(Vec<i32>)> = {
+        let (series_id, set_loading, dispatch_series, dispatch_error) = (
+            series.id,
+            set_loading.clone(),
+            dispatch_series.clone(),
+            dispatch_error.clone(),
+        );
+        Rc::new(move |articles: Vec<i32>| {
+            set_loading(true);
+            let (set_loading, dispatch_series, dispatch_error) = (
+                set_loading.clone(),
+                dispatch_series.clone(),
+                dispatch_error.clone(),
+            );
+            let payload = ISeriesParts { articles };
+            let future = async move { set_series_parts(&series_id, &payload).await };
+            handle_future(future, move |data: Result<ISeries, Status>| {
+                match data {
+                    Ok(series) => dispatch_series.emit(series),
+                    Err(status) => dispatch_error.emit(status),
+                };
+                set_loading(false);
+            });
+        })
+    };
+
+    let ids: Vec<i32> = series.parts.iter().map(|part| part.id).collect();
+    let on_part = |index: usize, to: Option<usize>| -> Callback<MouseEvent> {
+        let (parts, save_parts) = (moved(&ids, index, to), save_parts.clone());
+        Callback::from(move |_| save_parts(parts.clone()))
+    };
+    let on_add: Callback<MouseEvent> = {
+        let (ids, selected, set_selected, save_parts) = (
+            ids.clone(),
+            selected.clone(),
+            set_selected.clone(),
+            save_parts.clone(),
+        );
+        Callback::from(move |_| {
+            if let Some(id) = *selected {
+                save_parts(ids.iter().copied().chain(Some(id)).collect());
+                set_selected(None);
+            }
+        })
+    };
+
+    let mut candidates: Vec<&IArticle> = articles
+        .values()
+        .filter(|article| !ids.contains(&article.id))
+        .collect();
+    candidates.sort_by(|a, b| a.title.cmp(&b.title));
+
+    html! {
+        <div class="series-form">
+            <h2>{"Edit series"}</h2>
+            <TextInput value={(*title).clone()} onchange={on_change_title} placeholder="Title" />
+            <FieldErrors errors={field_errors.clone()} field="title" />
+            <TextArea value={(*description).clone()} onchange={on_change_description} rows={3} />
+            <div style="display: flex; font-size: .8em;">
+                <Button onclick={on_save} label="Save" />
+                <Button onclick={on_delete} label="Delete series" variant={ButtonVariant::Danger} />
+            </div>
+            <h2>{"Parts"}</h2>
+            <ol>
+                {for series.parts.iter().enumerate().map(|(index, part)| html! {
+                    <li class="series-part-editor">
+                        <span>{&part.title}</span>
+                        <Button onclick={on_part(index, index.checked_sub(1))} label="↑" disabled={index == 0} />
+                        <Button onclick={on_part(index, Some(index + 1))} label="↓" disabled={index + 1 == ids.len()} />
+                        <Button onclick={on_part(index, None)} label="Remove" variant={ButtonVariant::Warning} />
+                    </li>
+                })}
+            </ol>
+            <div style="display: flex; align-items: center;">
+                <select onchange={on_select}>
+                    <option selected={selected.is_none()}>{"Add an article..."}</option>
+                    {for candidates.iter().map(|article| html! {
+                        <option value={article.id.to_string()} selected={*selected == Some(article.id)}>
+                            {&article.title}
+                        </option>
+                    })}
+                </select>
+                <Button onclick={on_add} label="Add" disabled={selected.is_none()} />
+            </div>
+            {view_loader(*is_loading)}
+        </div>
+    }
+}
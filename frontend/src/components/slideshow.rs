@@ -1,12 +1,12 @@
 use {
-    crate::components::button::Button,
+    crate::{components::button::Button, entities::interfaces::IProjectImage},
     yew::{html, Callback, Properties},
     yew_functional::function_component,
 };
 
 #[derive(Properties, Clone, PartialEq)]
 pub struct SlideshowProps {
-    pub selected_image_url: String,
+    pub selected_image: IProjectImage,
     pub slideshow_length: usize,
     pub selected_image_index: usize,
     pub select_image: Callback<usize>,
@@ -16,7 +16,7 @@ pub struct SlideshowProps {
 pub fn slideshow(
     SlideshowProps {
         slideshow_length,
-        selected_image_url,
+        selected_image,
         selected_image_index,
         select_image,
     }: &SlideshowProps,
@@ -55,12 +55,32 @@ pub fn slideshow(
     container_style.push_str(justify_content);
     container_style.push_str("; align-items: center; max-height: 28rem;");
 
+    let caption = [
+        selected_image.caption.as_deref(),
+        selected_image.credits.as_deref(),
+    ]
+    .iter()
+    .flatten()
+    .filter(|text| !text.is_empty())
+    .copied()
+    .collect::<Vec<_>>()
+    .join(" — ");
+
     html! {
         <div style={container_style}>
             {if slideshow_length > 1 {
                 html! { <Button icon_name="fa fa-chevron-left" onclick={on_previous_image_clicked} /> }
             } else { html! {} }}
-            <img src={selected_image_url} style="width: 75%; object-fit: contain; align-self: stretch;"/>
+            <figure style="width: 75%; margin: 0; display: flex; flex-direction: column; align-self: stretch;">
+                <img src={&selected_image.image} alt={&selected_image.alt} style="width: 100%; flex: 1; min-height: 0; object-fit: contain;"/>
+                {if caption.is_empty() {
+                    html! {}
+                } else {
+                    html! {
+                        <figcaption style="margin-top: 8px; font-size: .8em; text-align: center; opacity: .8;">{caption}</figcaption>
+                    }
+                }}
+            </figure>
             {if slideshow_length > 1 {
                 html! { <Button icon_name="fa fa-chevron-right" onclick={on_next_image_clicked} /> }
             } else { html! {} }}
@@ -0,0 +1,42 @@
+use {
+    crate::entities::table::ITable,
+    std::rc::Rc,
+    yew::{html, Properties},
+    yew_functional::function_component,
+};
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct TableProps {
+    pub table: Rc<ITable>,
+}
+
+// The wrapper scrolls horizontally on narrow screens instead of squeezing the columns.
+#[function_component(Table)]
+pub fn table(TableProps { table }: &TableProps) -> Html {
+    html! {
+        <div class="table-wrapper">
+            <table class="content-table">
+                {match table.caption.as_deref() {
+                    Some(caption) if !caption.is_empty() => html! { <caption>{caption}</caption> },
+                    _ => html! {}
+                }}
+                <thead>
+                    <tr>
+                        {for table.header.iter().enumerate().map(|(column, cell)| html! {
+                            <th style={table.alignment(column).style()}>{cell}</th>
+                        })}
+                    </tr>
+                </thead>
+                <tbody>
+                    {for table.rows.iter().map(|row| html! {
+                        <tr>
+                            {for row.iter().enumerate().map(|(column, cell)| html! {
+                                <td style={table.alignment(column).style()}>{cell}</td>
+                            })}
+                        </tr>
+                    })}
+                </tbody>
+            </table>
+        </div>
+    }
+}
@@ -0,0 +1,168 @@
+use {
+    crate::{
+        components::{
+            button::{Button, ButtonVariant},
+            select::Select,
+            text_input::TextInput,
+        },
+        entities::table::{Alignment, ITable, ALIGNMENTS},
+    },
+    std::rc::Rc,
+    yew::{html, Callback, ChangeData, MouseEvent, Properties},
+    yew_functional::function_component,
+};
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct TableEditorProps {
+    pub table: Rc<ITable>,
+    pub onchange: Callback<ITable>,
+}
+
+// Emit a modified copy of the table, the editor itself holds no state.
+fn emit_edited(table: &ITable, onchange: &Callback<ITable>, edit: impl FnOnce(&mut ITable)) {
+    let mut table = table.clone();
+    edit(&mut table);
+    // Keep one alignment per column.
+    table.alignments.resize(table.header.len(), Alignment::None);
+    onchange.emit(table);
+}
+
+// Grid of inputs: the header row, one row per table row, and a column of alignments.
+#[function_component(TableEditor)]
+pub fn table_editor(TableEditorProps { table, onchange }: &TableEditorProps) -> Html {
+    let on_change_caption: Callback<ChangeData> = {
+        let (table, onchange) = (table.clone(), onchange.clone());
+        Callback::from(move |event: ChangeData| {
+            if let ChangeData::Value(caption) = event {
+                emit_edited(&table, &onchange, |table| {
+                    table.caption = Some(caption).filter(|caption| !caption.trim().is_empty())
+                });
+            }
+        })
+    };
+    let on_add_column: Callback<MouseEvent> = {
+        let (table, onchange) = (table.clone(), onchange.clone());
+        Callback::from(move |_| {
+            emit_edited(&table, &onchange, |table| {
+                table.header.push(String::new());
+                for row in &mut table.rows {
+                    row.push(String::new());
+                }
+            })
+        })
+    };
+    let on_add_row: Callback<MouseEvent> = {
+        let (table, onchange) = (table.clone(), onchange.clone());
+        Callback::from(move |_| {
+            emit_edited(&table, &onchange, |table| {
+                table.rows.push(vec![String::new(); table.header.len()])
+            })
+        })
+    };
+
+    let view_header_cell = |column: usize, cell: &String| {
+        let on_change_cell: Callback<ChangeData> = {
+            let (table, onchange) = (table.clone(), onchange.clone());
+            Callback::from(move |event: ChangeData| {
+                if let ChangeData::Value(value) = event {
+                    emit_edited(&table, &onchange, |table| table.header[column] = value);
+                }
+            })
+        };
+        let on_change_alignment: Callback<Alignment> = {
+            let (table, onchange) = (table.clone(), onchange.clone());
+            Callback::from(move |alignment| {
+                emit_edited(&table, &onchange, |table| {
+                    table.alignments.resize(table.header.len(), Alignment::None);
+                    table.alignments[column] = alignment;
+                })
+            })
+        };
+        let on_remove_column: Callback<MouseEvent> = {
+            let (table, onchange) = (table.clone(), onchange.clone());
+            Callback::from(move |_| {
+                emit_edited(&table, &onchange, |table| {
+                    table.header.remove(column);
+                    if column < table.alignments.len() {
+                        table.alignments.remove(column);
+                    }
+                    for row in &mut table.rows {
+                        if column < row.len() {
+                            row.remove(column);
+                        }
+                    }
+                })
+            })
+        };
+        html! {
+            <th>
+                <TextInput placeholder="Header" value={cell} onchange={on_change_cell} />
+                <div style="display: flex; align-items: center; margin-top: 4px; font-size: .8em;">
+                    <Select<Alignment> selected={table.alignment(column)} options={ALIGNMENTS} onchange={on_change_alignment} />
+                    <Button variant={ButtonVariant::Danger} icon_name="fa fa-times" onclick={on_remove_column} />
+                </div>
+            </th>
+        }
+    };
+
+    let view_row = |row_index: usize, row: &Vec<String>| {
+        let on_remove_row: Callback<MouseEvent> = {
+            let (table, onchange) = (table.clone(), onchange.clone());
+            Callback::from(move |_| {
+                emit_edited(&table, &onchange, |table| {
+                    table.rows.remove(row_index);
+                })
+            })
+        };
+        html! {
+            <tr>
+                {for (0..table.header.len()).map(|column| {
+                    let on_change_cell: Callback<ChangeData> = {
+                        let (table, onchange) = (table.clone(), onchange.clone());
+                        Callback::from(move |event: ChangeData| {
+                            if let ChangeData::Value(value) = event {
+                                emit_edited(&table, &onchange, |table| {
+                                    let row = &mut table.rows[row_index];
+                                    row.resize(table.header.len(), String::new());
+                                    row[column] = value;
+                                });
+                            }
+                        })
+                    };
+                    html! {
+                        <td>
+                            <TextInput value={row.get(column).cloned().unwrap_or_default()} onchange={on_change_cell} />
+                        </td>
+                    }
+                })}
+                <td style="font-size: .8em;">
+                    <Button variant={ButtonVariant::Danger} icon_name="fa fa-times" onclick={on_remove_row} />
+                </td>
+            </tr>
+        }
+    };
+
+    html! {
+        <div class="table-wrapper">
+            <table class="content-table table-editor">
+                <thead>
+                    <tr>
+                        {for table.header.iter().enumerate().map(|(column, cell)| view_header_cell(column, cell))}
+                    </tr>
+                </thead>
+                <tbody>
+                    {for table.rows.iter().enumerate().map(|(row_index, row)| view_row(row_index, row))}
+                </tbody>
+            </table>
+            <div style="display: flex; margin-top: 4px; font-size: .8em;">
+                <Button icon_name="fa fa-plus" label="Column" onclick={on_add_column} />
+                <Button icon_name="fa fa-plus" label="Row" onclick={on_add_row} disabled={table.header.is_empty()} />
+            </div>
+            <TextInput
+                placeholder="Caption"
+                value={table.caption.clone().unwrap_or_default()}
+                onchange={on_change_caption}
+            />
+        </div>
+    }
+}
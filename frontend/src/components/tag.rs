@@ -18,7 +18,7 @@ use {
 pub struct TagProps {
     pub tag: String,
     pub dispatch_tag_results: Callback<(String, SearchResults)>,
-    pub dispatch_error: Callback<bool>,
+    pub dispatch_error: Callback<Status>,
 }
 
 #[function_component(Tag)]
@@ -93,7 +93,7 @@ pub fn tag(
                 handle_future(future, move |data: Result<SearchResults, Status>| {
                     match data {
                         Ok(results) => dispatch_tag_results.emit((tag.clone(), results)),
-                        Err(_) => dispatch_error.emit(true),
+                        Err(status) => dispatch_error.emit(status),
                     };
                     set_loading(false);
                 });
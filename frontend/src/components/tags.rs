@@ -13,7 +13,7 @@ use {
 #[derive(Properties, Clone, PartialEq)]
 pub struct TagsProps {
     pub dispatch_tags: Callback<Vec<ITag>>,
-    pub dispatch_error: Callback<bool>,
+    pub dispatch_error: Callback<Status>,
 }
 
 #[function_component(Tags)]
@@ -37,7 +37,7 @@ pub fn tags(
             handle_future(future, move |data: Result<Vec<ITag>, Status>| {
                 match data {
                     Ok(tags) => dispatch_tags.emit(tags),
-                    Err(_) => dispatch_error.emit(true),
+                    Err(status) => dispatch_error.emit(status),
                 };
                 set_loading(false);
             });
@@ -0,0 +1,66 @@
+use {
+    serde::{Deserialize, Serialize},
+    std::fmt::{Display, Formatter, Result},
+};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CalloutKind {
+    Info,
+    Tip,
+    Warning,
+    Danger,
+}
+
+impl Default for CalloutKind {
+    fn default() -> Self {
+        Self::Info
+    }
+}
+
+impl CalloutKind {
+    pub fn class(&self) -> &'static str {
+        match self {
+            Self::Info => "callout callout-info",
+            Self::Tip => "callout callout-tip",
+            Self::Warning => "callout callout-warning",
+            Self::Danger => "callout callout-danger",
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            Self::Info => "fa fa-info-circle",
+            Self::Tip => "fa fa-lightbulb-o",
+            Self::Warning => "fa fa-exclamation-triangle",
+            Self::Danger => "fa fa-ban",
+        }
+    }
+}
+
+impl Display for CalloutKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+pub const CALLOUT_KINDS: &[CalloutKind] = &[
+    CalloutKind::Info,
+    CalloutKind::Tip,
+    CalloutKind::Warning,
+    CalloutKind::Danger,
+];
+
+// Kind of a Callout content, stored in IContent.data.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct ICallout {
+    pub kind: CalloutKind,
+}
+
+impl ICallout {
+    pub fn from_data(data: &Option<serde_json::Value>) -> Self {
+        data.as_ref()
+            .and_then(|data| serde_json::from_value(data.clone()).ok())
+            .unwrap_or_default()
+    }
+}
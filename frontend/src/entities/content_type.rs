@@ -10,6 +10,13 @@ pub enum ContentType {
     Link,
     Code,
     Image,
+    Table,
+    Math,
+    Callout,
+    Quote,
+    List,
+    Video,
+    Audio,
 }
 
 impl Default for ContentType {
@@ -30,4 +37,11 @@ pub const CONTENT_TYPES: &[ContentType] = &[
     ContentType::Code,
     ContentType::Image,
     ContentType::Link,
+    ContentType::Table,
+    ContentType::Math,
+    ContentType::Callout,
+    ContentType::Quote,
+    ContentType::List,
+    ContentType::Video,
+    ContentType::Audio,
 ];
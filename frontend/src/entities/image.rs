@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+// Description of an Image content, stored in IContent.data. Width and height are measured by the API.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct IImage {
+    #[serde(default)]
+    pub alt: String,
+    #[serde(default)]
+    pub credits: Option<String>,
+    #[serde(default)]
+    pub width: Option<i32>,
+    #[serde(default)]
+    pub height: Option<i32>,
+}
+
+impl IImage {
+    pub fn from_data(data: &Option<serde_json::Value>) -> Self {
+        data.as_ref()
+            .and_then(|data| serde_json::from_value(data.clone()).ok())
+            .unwrap_or_default()
+    }
+}
@@ -3,13 +3,39 @@ use {
     crate::{utils::date::get_current_date, API_URL},
     serde::{Deserialize, Serialize},
     std::collections::HashMap,
+    wasm_bindgen::JsValue,
 };
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Clone, PartialEq)]
 pub enum Status {
     Success,
     Error,
     Unknown,
+    // Error body returned by the API ({ code, message, fields }).
+    Failure(IApiError),
+}
+
+// Fall back to a generic error when the API could not be reached or did not answer with a JSON error body.
+impl From<JsValue> for Status {
+    fn from(error: JsValue) -> Self {
+        error
+            .into_serde::<IApiError>()
+            .map_or(Status::Error, Status::Failure)
+    }
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct IFieldError {
+    pub field: String,
+    pub message: String,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct IApiError {
+    pub code: String,
+    pub message: String,
+    #[serde(default)]
+    pub fields: Vec<IFieldError>,
 }
 
 #[derive(Deserialize)]
@@ -57,6 +83,13 @@ pub struct IContent {
     pub language: Option<Language>,
     pub highlighted_code: Option<String>,
     pub url: Option<String>,
+    pub caption: Option<String>,
+    pub start_line: Option<i32>,
+    pub emphasized_lines: Option<String>,
+    pub added_lines: Option<String>,
+    pub removed_lines: Option<String>,
+    pub data: Option<serde_json::Value>,
+    pub rendered_html: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Default, Clone, PartialEq, Eq)]
@@ -68,6 +101,19 @@ pub struct IChapter {
     pub contents: Vec<IContent>,
 }
 
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq, Eq)]
+pub struct IReference {
+    pub id: i32,
+    pub article_id: i32,
+    pub index: i32,
+    pub key: String,
+    pub title: String,
+    pub url: Option<String>,
+    // Number of [^key] citations in the article, computed by the API.
+    #[serde(default)]
+    pub citations: i32,
+}
+
 // TODO - Use it in IArticle (or not)
 #[derive(Serialize, Deserialize, Default, Clone, PartialEq, Eq)]
 pub struct IArticleHeader {
@@ -77,6 +123,8 @@ pub struct IArticleHeader {
     pub published: bool,
     pub headline: String,
     pub image: String,
+    #[serde(default)]
+    pub image_alt: String,
     pub image_credits: Option<String>,
     pub tags: Vec<ITag>,
     pub updated: Option<String>,
@@ -90,10 +138,21 @@ pub struct IArticle {
     pub published: bool,
     pub headline: String,
     pub image: String,
+    #[serde(default)]
+    pub image_alt: String,
     pub image_credits: Option<String>,
     pub updated: Option<String>,
+    // Scheduled transitions, applied by the API.
+    #[serde(default)]
+    pub publish_at: Option<String>,
+    #[serde(default)]
+    pub unpublish_at: Option<String>,
     pub tags: Vec<ITag>,
     pub chapters: Vec<IChapter>,
+    #[serde(default)]
+    pub references: Vec<IReference>,
+    #[serde(default)]
+    pub series: Option<IArticleSeries>,
 }
 
 impl Default for IArticle {
@@ -102,22 +161,150 @@ impl Default for IArticle {
             id: 0,
             title: "New article...".to_owned(),
             image: API_URL.to_owned() + "/media/images/articles/ferris.webp",
+            image_alt: "Ferris the crab, the Rust mascot".to_owned(),
             image_credits: None,
             headline: "Preview...".to_owned(),
             pub_date: get_current_date().expect("Could not get current readable date."),
             published: false,
             updated: None,
+            publish_at: None,
+            unpublish_at: None,
             chapters: vec![],
+            references: vec![],
             tags: vec![],
+            series: None,
         }
     }
 }
 
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct ISeriesPart {
+    pub id: i32,
+    pub title: String,
+    pub headline: String,
+    pub pub_date: String,
+    pub published: bool,
+}
+
+// Position of an article in its series, counted from 1.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct IArticleSeries {
+    pub id: i32,
+    pub title: String,
+    pub part: i32,
+    pub parts: i32,
+    pub previous: Option<ISeriesPart>,
+    pub next: Option<ISeriesPart>,
+}
+
+// An article without its chapters and references.
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct IArticleSummary {
+    pub id: i32,
+    pub title: String,
+    pub pub_date: String,
+    pub updated: Option<String>,
+    pub headline: String,
+    pub image: String,
+    #[serde(default)]
+    pub image_alt: String,
+    pub tags: Vec<ITag>,
+}
+
+// An article or a project related to an article, best first.
+#[derive(Deserialize, Clone, PartialEq)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum IRelatedItem {
+    Article(IArticleSummary),
+    Project(IProject),
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct ISeriesSummary {
+    pub id: i32,
+    pub title: String,
+    pub description: String,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct ISeries {
+    pub id: i32,
+    pub title: String,
+    pub description: String,
+    pub parts: Vec<ISeriesPart>,
+}
+
+#[derive(Serialize)]
+pub struct IInputSeries {
+    pub title: String,
+    pub description: String,
+}
+
+// Article ids of the parts, in order.
+#[derive(Serialize)]
+pub struct ISeriesParts {
+    pub articles: Vec<i32>,
+}
+
 #[derive(Serialize)]
 pub struct IPublishArticle {
     pub published: bool,
 }
 
+// A missing time cancels the transition.
+#[derive(Serialize)]
+pub struct IScheduleArticle {
+    pub publish_at: Option<String>,
+    pub unpublish_at: Option<String>,
+}
+
+// Validity of a preview link, in hours.
+#[derive(Serialize)]
+pub struct IPreviewLinkRequest {
+    pub hours: i64,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct IPreviewLink {
+    pub token: String,
+    pub url: String,
+    pub expires: String,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct IScheduledArticle {
+    pub id: i32,
+    pub title: String,
+    pub published: bool,
+    pub publish_at: Option<String>,
+    pub unpublish_at: Option<String>,
+}
+
+// New position of a chapter dragged in the editor.
+#[derive(Serialize)]
+pub struct IChapterMove {
+    pub article_id: i32,
+    pub index: i32,
+}
+
+// New position of a content dragged in the editor, in the same or another chapter.
+#[derive(Serialize)]
+pub struct IContentMove {
+    pub chapter_id: i32,
+    pub index: i32,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq, Eq)]
+pub struct IProjectImage {
+    pub image: String,
+    #[serde(default)]
+    pub alt: String,
+    #[serde(default)]
+    pub caption: Option<String>,
+    #[serde(default)]
+    pub credits: Option<String>,
+}
+
 #[derive(Serialize, Deserialize, Default, Clone, PartialEq, Eq)]
 pub struct IProject {
     pub id: i32,
@@ -130,6 +317,6 @@ pub struct IProject {
     pub download_link: Option<String>,
     pub git: Option<String>,
     pub tags: Vec<ITag>,
-    pub gallery: Option<Vec<String>>,
+    pub gallery: Option<Vec<IProjectImage>>,
     pub category: ProjectCategory,
 }
@@ -14,6 +14,16 @@ pub enum Language {
     Javascript,
     Typescript,
     Yaml,
+    C,
+    Cpp,
+    Haskell,
+    Go,
+    Toml,
+    Json,
+    Dockerfile,
+    Markdown,
+    Diff,
+    PlainText,
 }
 
 impl Default for Language {
@@ -22,9 +32,35 @@ impl Default for Language {
     }
 }
 
+impl Language {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Rust => "Rust",
+            Self::Bash => "Bash",
+            Self::Python => "Python",
+            Self::Sql => "SQL",
+            Self::Html => "HTML",
+            Self::Css => "CSS",
+            Self::Javascript => "JavaScript",
+            Self::Typescript => "TypeScript",
+            Self::Yaml => "YAML",
+            Self::C => "C",
+            Self::Cpp => "C++",
+            Self::Haskell => "Haskell",
+            Self::Go => "Go",
+            Self::Toml => "TOML",
+            Self::Json => "JSON",
+            Self::Dockerfile => "Dockerfile",
+            Self::Markdown => "Markdown",
+            Self::Diff => "Diff",
+            Self::PlainText => "Plain text",
+        }
+    }
+}
+
 impl Display for Language {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{:?}", self)
+        write!(f, "{}", self.label())
     }
 }
 
@@ -38,4 +74,14 @@ pub const LANGUAGES: &[Language] = &[
     Language::Javascript,
     Language::Typescript,
     Language::Yaml,
+    Language::C,
+    Language::Cpp,
+    Language::Haskell,
+    Language::Go,
+    Language::Toml,
+    Language::Json,
+    Language::Dockerfile,
+    Language::Markdown,
+    Language::Diff,
+    Language::PlainText,
 ];
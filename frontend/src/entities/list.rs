@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+
+// Indentation of a nested item in the outline edited as text.
+#[cfg(feature = "editable")]
+const OUTLINE_INDENT: &str = "  ";
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct IListItem {
+    pub text: String,
+    #[serde(default)]
+    pub items: Vec<IListItem>,
+}
+
+// Items of a List content, stored in IContent.data.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct IList {
+    #[serde(default)]
+    pub ordered: bool,
+    pub items: Vec<IListItem>,
+}
+
+impl IList {
+    pub fn from_data(data: &Option<serde_json::Value>) -> Self {
+        data.as_ref()
+            .and_then(|data| serde_json::from_value(data.clone()).ok())
+            .unwrap_or_default()
+    }
+
+    // One item per line, nested items indented by two spaces more than their parent.
+    #[cfg(feature = "editable")]
+    pub fn outline(&self) -> String {
+        fn write_items(items: &[IListItem], depth: usize, outline: &mut String) {
+            for item in items {
+                outline.push_str(&OUTLINE_INDENT.repeat(depth));
+                outline.push_str(&item.text);
+                outline.push('\n');
+                write_items(&item.items, depth + 1, outline);
+            }
+        }
+        let mut outline = String::new();
+        write_items(&self.items, 0, &mut outline);
+        outline
+    }
+
+    #[cfg(feature = "editable")]
+    pub fn items_from_outline(outline: &str) -> Vec<IListItem> {
+        // Items being filled, one per depth.
+        let mut open_items: Vec<IListItem> = Vec::new();
+        let mut items = Vec::new();
+        let close_item = |open_items: &mut Vec<IListItem>, items: &mut Vec<IListItem>| {
+            if let Some(item) = open_items.pop() {
+                match open_items.last_mut() {
+                    Some(parent) => parent.items.push(item),
+                    None => items.push(item),
+                }
+            }
+        };
+        for line in outline.lines().filter(|line| !line.trim().is_empty()) {
+            let text = line.trim_start();
+            let depth = (line.len() - text.len()) / OUTLINE_INDENT.len();
+            // An item deeper than its parent's child is attached to the parent.
+            while open_items.len() > depth {
+                close_item(&mut open_items, &mut items);
+            }
+            open_items.push(IListItem {
+                text: text.trim_end().to_owned(),
+                items: Vec::new(),
+            });
+        }
+        while !open_items.is_empty() {
+            close_item(&mut open_items, &mut items);
+        }
+        items
+    }
+}
@@ -1,5 +1,13 @@
 pub mod action;
+pub mod callout;
 pub mod content_type;
+pub mod image;
 pub mod interfaces;
 pub mod language;
+pub mod list;
+pub mod player;
 pub mod project_category;
+pub mod quote;
+#[cfg(feature = "editable")]
+pub mod revision;
+pub mod table;
@@ -0,0 +1,141 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TrackKind {
+    Subtitles,
+    Captions,
+}
+
+impl Default for TrackKind {
+    fn default() -> Self {
+        Self::Subtitles
+    }
+}
+
+impl TrackKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Subtitles => "subtitles",
+            Self::Captions => "captions",
+        }
+    }
+}
+
+// WebVTT file of a self-hosted video or audio, its path has no API URL.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct ITextTrack {
+    pub src: String,
+    #[serde(default)]
+    pub kind: TrackKind,
+    pub srclang: String,
+    pub label: String,
+}
+
+// Poster and tracks of a Video or Audio content, stored in IContent.data.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct IPlayer {
+    #[serde(default)]
+    pub poster: Option<String>,
+    #[serde(default)]
+    pub tracks: Vec<ITextTrack>,
+}
+
+impl IPlayer {
+    pub fn from_data(data: &Option<serde_json::Value>) -> Self {
+        data.as_ref()
+            .and_then(|data| serde_json::from_value(data.clone()).ok())
+            .unwrap_or_default()
+    }
+
+    // One track per line: "/media/videos/talk.en.vtt, en, English" followed by ", captions" for captions.
+    #[cfg(feature = "editable")]
+    pub fn tracks_outline(&self) -> String {
+        self.tracks
+            .iter()
+            .map(|track| match track.kind {
+                TrackKind::Subtitles => {
+                    format!("{}, {}, {}\n", track.src, track.srclang, track.label)
+                }
+                TrackKind::Captions => format!(
+                    "{}, {}, {}, captions\n",
+                    track.src, track.srclang, track.label
+                ),
+            })
+            .collect()
+    }
+
+    #[cfg(feature = "editable")]
+    pub fn tracks_from_outline(outline: &str) -> Vec<ITextTrack> {
+        outline
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                let mut fields = line.split(',').map(str::trim);
+                ITextTrack {
+                    src: fields.next().unwrap_or_default().to_owned(),
+                    srclang: fields.next().unwrap_or_default().to_owned(),
+                    label: fields.next().unwrap_or_default().to_owned(),
+                    kind: match fields.next() {
+                        Some("captions") => TrackKind::Captions,
+                        _ => TrackKind::Subtitles,
+                    },
+                }
+            })
+            .collect()
+    }
+}
+
+// External video players, loaded only when the reader clicks on them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmbedProvider {
+    YouTube,
+    Vimeo,
+}
+
+impl EmbedProvider {
+    // The provider and the id of the video of a watch or share URL, see the API's validation.
+    pub fn parse(url: &str) -> Option<(Self, String)> {
+        let url = url
+            .trim()
+            .strip_prefix("https://")
+            .or_else(|| url.trim().strip_prefix("http://"))?;
+        let url = url.strip_prefix("www.").unwrap_or(url);
+        let (provider, id) = if let Some(query) = url.strip_prefix("youtube.com/watch?") {
+            let id = query
+                .split('&')
+                .find_map(|parameter| parameter.strip_prefix("v="))?;
+            (Self::YouTube, id)
+        } else if let Some(path) = url.strip_prefix("youtu.be/") {
+            (Self::YouTube, path)
+        } else if let Some(path) = url.strip_prefix("vimeo.com/") {
+            (Self::Vimeo, path)
+        } else {
+            return None;
+        };
+        let id: String = id
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+            .collect();
+        if id.is_empty() {
+            None
+        } else {
+            Some((provider, id))
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::YouTube => "YouTube",
+            Self::Vimeo => "Vimeo",
+        }
+    }
+
+    // Cookie-less players, started since the reader just asked for the video.
+    pub fn embed_url(&self, id: &str) -> String {
+        match self {
+            Self::YouTube => format!("https://www.youtube-nocookie.com/embed/{}?autoplay=1", id),
+            Self::Vimeo => format!("https://player.vimeo.com/video/{}?autoplay=1&dnt=1", id),
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+// Attribution of a Quote content, stored in IContent.data.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct IQuote {
+    #[serde(default)]
+    pub attribution: Option<String>,
+    #[serde(default)]
+    pub source_url: Option<String>,
+}
+
+impl IQuote {
+    pub fn from_data(data: &Option<serde_json::Value>) -> Self {
+        data.as_ref()
+            .and_then(|data| serde_json::from_value(data.clone()).ok())
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,76 @@
+use {serde::Deserialize, serde_json::Value};
+
+// A saved state of an article, listed newest first by the API.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct IRevision {
+    pub id: i32,
+    pub article_id: i32,
+    pub author: String,
+    pub created: String,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum IChange {
+    Added,
+    Removed,
+    Modified,
+}
+
+impl IChange {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Added => "added",
+            Self::Removed => "removed",
+            Self::Modified => "modified",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct IFieldChange {
+    pub field: String,
+    pub before: Value,
+    pub after: Value,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct IContentDiff {
+    pub id: i32,
+    pub content_type: String,
+    pub change: IChange,
+    pub fields: Vec<IFieldChange>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct IChapterDiff {
+    pub id: i32,
+    pub title: String,
+    // None when only the contents of the chapter changed.
+    pub change: Option<IChange>,
+    pub fields: Vec<IFieldChange>,
+    pub contents: Vec<IContentDiff>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct IReferenceDiff {
+    pub id: i32,
+    pub key: String,
+    pub change: IChange,
+    pub fields: Vec<IFieldChange>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct IRevisionDiff {
+    pub from: i32,
+    pub to: i32,
+    pub fields: Vec<IFieldChange>,
+    pub chapters: Vec<IChapterDiff>,
+    pub references: Vec<IReferenceDiff>,
+}
+
+impl IRevisionDiff {
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty() && self.chapters.is_empty() && self.references.is_empty()
+    }
+}
@@ -0,0 +1,67 @@
+use {
+    serde::{Deserialize, Serialize},
+    std::fmt::{Display, Formatter, Result},
+};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Alignment {
+    None,
+    Left,
+    Center,
+    Right,
+}
+
+impl Default for Alignment {
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+impl Alignment {
+    pub fn style(&self) -> &'static str {
+        match self {
+            Self::None => "",
+            Self::Left => "text-align: left;",
+            Self::Center => "text-align: center;",
+            Self::Right => "text-align: right;",
+        }
+    }
+}
+
+impl Display for Alignment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+pub const ALIGNMENTS: &[Alignment] = &[
+    Alignment::None,
+    Alignment::Left,
+    Alignment::Center,
+    Alignment::Right,
+];
+
+// Rows and columns of a Table content, stored in IContent.data.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct ITable {
+    pub header: Vec<String>,
+    #[serde(default)]
+    pub alignments: Vec<Alignment>,
+    #[serde(default)]
+    pub rows: Vec<Vec<String>>,
+    #[serde(default)]
+    pub caption: Option<String>,
+}
+
+impl ITable {
+    pub fn from_data(data: &Option<serde_json::Value>) -> Self {
+        data.as_ref()
+            .and_then(|data| serde_json::from_value(data.clone()).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn alignment(&self, column: usize) -> Alignment {
+        self.alignments.get(column).copied().unwrap_or_default()
+    }
+}
@@ -24,6 +24,7 @@ static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
 pub fn run_app() -> Result<(), JsValue> {
     #[cfg(debug_assertions)]
     wasm_logger::init(wasm_logger::Config::default());
+    utils::theme::follow_system_theme()?;
     yew::start_app::<app::App>();
     Ok(())
 }
@@ -16,7 +16,7 @@ pub struct ArticleProps {
     #[prop_or_default]
     pub id: i32,
     pub dispatch_article: Callback<IArticle>,
-    pub dispatch_error: Callback<bool>,
+    pub dispatch_error: Callback<Status>,
 }
 
 #[function_component(Article)]
@@ -45,7 +45,7 @@ pub fn article(
                 handle_future(future, move |data: Result<IArticle, Status>| {
                     match data {
                         Ok(article) => dispatch_article.emit(article),
-                        Err(_) => dispatch_error.emit(true),
+                        Err(status) => dispatch_error.emit(status),
                     };
                     set_loading(false); // !!
                 });
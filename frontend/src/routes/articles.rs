@@ -13,7 +13,7 @@ use {
 #[derive(Properties, Clone, PartialEq)]
 pub struct ArticlesProps {
     pub dispatch_articles: Callback<HashMap<i32, IArticle>>,
-    pub dispatch_error: Callback<bool>,
+    pub dispatch_error: Callback<Status>,
 }
 
 #[function_component(Articles)]
@@ -38,7 +38,7 @@ pub fn articles(
                 move |data: Result<HashMap<i32, IArticle>, Status>| {
                     match data {
                         Ok(articles) => dispatch_articles.emit(articles),
-                        Err(_) => dispatch_error.emit(true),
+                        Err(status) => dispatch_error.emit(status),
                     };
                     set_loading(false);
                 },
@@ -4,7 +4,10 @@ pub mod about;
 pub mod article;
 pub mod articles;
 pub mod new_article;
+#[cfg(not(feature = "editable"))]
+pub mod preview;
 pub mod projects;
+pub mod series;
 pub mod tag;
 pub mod tags;
 
@@ -13,6 +16,9 @@ pub enum AppRoute {
     #[cfg(feature = "editable")]
     #[to = "/articles/new!"]
     NewArticle,
+    #[cfg(not(feature = "editable"))]
+    #[to = "/preview/{token}"]
+    Preview { token: String },
     #[to = "/articles/{id}"]
     Article { id: i32 },
     #[to = "/articles!"]
@@ -21,6 +27,10 @@ pub enum AppRoute {
     Tag { tag: String },
     #[to = "/tags!"]
     Tags,
+    #[to = "/series/{id}"]
+    Series { id: i32 },
+    #[to = "/series!"]
+    SeriesList,
     #[to = "/projects!"]
     Projects,
     #[to = "/about!"]
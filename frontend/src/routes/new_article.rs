@@ -1,7 +1,9 @@
 use {
     crate::{
-        components::article::article::Article, entities::action::Action,
-        entities::interfaces::IArticle, store::store::BlogStore,
+        components::article::article::Article,
+        entities::action::Action,
+        entities::interfaces::{IArticle, Status},
+        store::store::BlogStore,
     },
     std::rc::Rc,
     yew::{html, Callback, Properties},
@@ -11,7 +13,7 @@ use {
 #[derive(Properties, Clone, PartialEq)]
 pub struct NewArticleProps {
     pub dispatch_new_article: Callback<IArticle>,
-    pub dispatch_error: Callback<bool>,
+    pub dispatch_error: Callback<Status>,
 }
 
 #[function_component(NewArticle)]
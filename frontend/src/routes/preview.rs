@@ -0,0 +1,81 @@
+use {
+    crate::{
+        components::{article::article::Article as ArticleComponent, loader::Loader},
+        entities::interfaces::{IArticle, Status},
+        service::{articles::get_preview, future::handle_future},
+    },
+    yew::{html, Callback, Properties},
+    yew_functional::{function_component, use_effect_with_deps, use_state},
+};
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct PreviewProps {
+    pub token: String,
+    pub dispatch_error: Callback<Status>,
+}
+
+// Read-only draft shared through a preview link. It is kept out of the store, so that it never shows up in the
+// article lists.
+#[function_component(Preview)]
+pub fn preview(
+    PreviewProps {
+        token,
+        dispatch_error,
+    }: &PreviewProps,
+) -> Html {
+    let (is_loading, set_loading) = use_state(|| false);
+    let (article, set_article) = use_state(|| None::<IArticle>);
+    let (is_expired, set_expired) = use_state(|| false);
+
+    {
+        let dispatch_error = dispatch_error.clone();
+        use_effect_with_deps(
+            move |token| {
+                set_loading(true);
+                let token = token.clone();
+                let future = async move { get_preview(&token).await };
+                handle_future(future, move |data: Result<IArticle, Status>| {
+                    match data {
+                        Ok(article) => set_article(Some(article)),
+                        // Invalid and expired tokens are answered like a missing article.
+                        Err(Status::Failure(error)) if error.code == "not_found" => {
+                            set_expired(true)
+                        }
+                        Err(status) => dispatch_error.emit(status),
+                    };
+                    set_loading(false);
+                });
+                || {}
+            },
+            token.clone(),
+        );
+    }
+
+    html! {
+        <div style="display: flex; flex: 1; flex-direction: column;">
+            {match &*article {
+                Some(article) => html! {
+                    <>
+                        <div class="preview-banner">
+                            {"Preview — this article is not published yet."}
+                        </div>
+                        <ArticleComponent article={article.clone()} />
+                    </>
+                },
+                None if *is_expired => html! {
+                    <div class="preview-banner">{"This preview link is invalid or has expired."}</div>
+                },
+                None => html! {},
+            }}
+            {if *is_loading {
+                html! {
+                    <div style="align-items: center; justify-content: center; display: flex; margin-top: 24px; margin-bottom: 24px;">
+                        <Loader />
+                    </div>
+                }
+            } else {
+                html! {}
+            }}
+        </div>
+    }
+}
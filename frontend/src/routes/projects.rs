@@ -1,5 +1,8 @@
 use {
-    crate::{components::projects::Projects as ProjectsComponent, entities::interfaces::IProject},
+    crate::{
+        components::projects::Projects as ProjectsComponent,
+        entities::interfaces::{IProject, Status},
+    },
     std::collections::HashMap,
     yew::{html, Callback, Properties},
     yew_functional::function_component,
@@ -8,7 +11,7 @@ use {
 #[derive(Properties, Clone, PartialEq)]
 pub struct ProjectsProps {
     pub dispatch_projects: Callback<HashMap<i32, IProject>>,
-    pub dispatch_error: Callback<bool>,
+    pub dispatch_error: Callback<Status>,
 }
 
 #[function_component(Projects)]
@@ -0,0 +1,33 @@
+use {
+    crate::{
+        components::series::{Series as SeriesComponent, SeriesList as SeriesListComponent},
+        entities::interfaces::Status,
+    },
+    yew::{html, Callback, Properties},
+    yew_functional::function_component,
+};
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct SeriesProps {
+    pub id: i32,
+    pub dispatch_error: Callback<Status>,
+}
+
+#[function_component(Series)]
+pub fn series(SeriesProps { id, dispatch_error }: &SeriesProps) -> Html {
+    html! {
+        <SeriesComponent id={id} dispatch_error={dispatch_error} />
+    }
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct SeriesListProps {
+    pub dispatch_error: Callback<Status>,
+}
+
+#[function_component(SeriesList)]
+pub fn series_list(SeriesListProps { dispatch_error }: &SeriesListProps) -> Html {
+    html! {
+        <SeriesListComponent dispatch_error={dispatch_error} />
+    }
+}
@@ -1,5 +1,8 @@
 use {
-    crate::{components::tag::Tag as TagComponent, entities::interfaces::SearchResults},
+    crate::{
+        components::tag::Tag as TagComponent,
+        entities::interfaces::{SearchResults, Status},
+    },
     yew::{html, Callback, Properties},
     yew_functional::function_component,
 };
@@ -8,7 +11,7 @@ use {
 pub struct TagProps {
     pub tag: String,
     pub dispatch_tag_results: Callback<(String, SearchResults)>,
-    pub dispatch_error: Callback<bool>,
+    pub dispatch_error: Callback<Status>,
 }
 
 #[function_component(Tag)]
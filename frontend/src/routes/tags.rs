@@ -1,5 +1,8 @@
 use {
-    crate::{components::tags::Tags as TagsComponent, entities::interfaces::ITag},
+    crate::{
+        components::tags::Tags as TagsComponent,
+        entities::interfaces::{ITag, Status},
+    },
     yew::{html, Callback, Properties},
     yew_functional::function_component,
 };
@@ -7,7 +10,7 @@ use {
 #[derive(Properties, Clone, PartialEq)]
 pub struct TagsProps {
     pub dispatch_tags: Callback<Vec<ITag>>,
-    pub dispatch_error: Callback<bool>,
+    pub dispatch_error: Callback<Status>,
 }
 
 #[function_component(Tags)]
@@ -12,10 +12,18 @@ use {
     std::collections::HashMap,
 };
 
+#[cfg(not(feature = "editable"))]
+use crate::entities::interfaces::IRelatedItem;
+
 #[cfg(feature = "editable")]
 use {
-    crate::entities::interfaces::{
-        IArticleHeader, IChapter, IContent, IPublishArticle, TAPIResponse,
+    crate::entities::{
+        interfaces::{
+            IArticleHeader, IChapter, IChapterMove, IContent, IContentMove, IPreviewLink,
+            IPreviewLinkRequest, IPublishArticle, IReference, IScheduleArticle, IScheduledArticle,
+            TAPIResponse,
+        },
+        revision::{IRevision, IRevisionDiff},
     },
     serde_json::json,
 };
@@ -29,7 +37,7 @@ pub async fn get_article_list() -> Result<HashMap<i32, IArticle>, Status> {
     let json = Fetch::get(url).await;
     match json {
         Ok(json) => Ok(json.into_serde().unwrap()),
-        Err(_err) => Err(Status::Error),
+        Err(err) => Err(Status::from(err)),
     }
 }
 
@@ -44,7 +52,23 @@ pub async fn get_article(id: &i32) -> Result<IArticle, Status> {
     let json = Fetch::get(url).await;
     match json {
         Ok(json) => Ok(json.into_serde().unwrap()),
-        Err(_err) => Err(Status::Error),
+        Err(err) => Err(Status::from(err)),
+    }
+}
+
+// Unpublished article shared through a preview link.
+#[cfg(not(feature = "editable"))]
+pub async fn get_preview(token: &str) -> Result<IArticle, Status> {
+    // API_URL.len() + "/preview/".len() + token.len()
+    let mut url = String::with_capacity(API_URL.len() + 9 + token.len());
+    url.push_str(API_URL);
+    url.push_str("/preview/");
+    url.push_str(token);
+
+    let json = Fetch::get(url).await;
+    match json {
+        Ok(json) => Ok(json.into_serde().unwrap()),
+        Err(err) => Err(Status::from(err)),
     }
 }
 
@@ -58,7 +82,7 @@ pub async fn add_article(payload: &IArticle) -> Result<IArticle, Status> {
     let json = Fetch::post(url, Some(json!(&payload).to_string())).await;
     match json {
         Ok(json) => Ok(json.into_serde().unwrap()),
-        Err(_err) => Err(Status::Error),
+        Err(err) => Err(Status::from(err)),
     }
 }
 
@@ -74,7 +98,7 @@ pub async fn update_article_header(payload: &IArticleHeader) -> Result<IArticle,
     let json = Fetch::patch(url, Some(json!(&payload).to_string())).await;
     match json {
         Ok(json) => Ok(json.into_serde().unwrap()),
-        Err(_err) => Err(Status::Error),
+        Err(err) => Err(Status::from(err)),
     }
 }
 
@@ -90,7 +114,37 @@ pub async fn publish_article(id: &i32, payload: &IPublishArticle) -> Result<IArt
     let json = Fetch::patch(url, Some(json!(&payload).to_string())).await;
     match json {
         Ok(json) => Ok(json.into_serde().unwrap()),
-        Err(_err) => Err(Status::Error),
+        Err(err) => Err(Status::from(err)),
+    }
+}
+
+#[cfg(feature = "editable")]
+pub async fn schedule_article(id: &i32, payload: &IScheduleArticle) -> Result<IArticle, Status> {
+    let id_str = id.to_string();
+    // API_URL.len() + "/articles/schedule/".len() + id_str.len()
+    let mut url = String::with_capacity(API_URL.len() + 19 + id_str.len());
+    url.push_str(API_URL);
+    url.push_str("/articles/schedule/");
+    url.push_str(&id_str);
+
+    let json = Fetch::patch(url, Some(json!(&payload).to_string())).await;
+    match json {
+        Ok(json) => Ok(json.into_serde().unwrap()),
+        Err(err) => Err(Status::from(err)),
+    }
+}
+
+#[cfg(feature = "editable")]
+pub async fn get_scheduled_articles() -> Result<Vec<IScheduledArticle>, Status> {
+    // API_URL.len() + "/articles/scheduled".len()
+    let mut url = String::with_capacity(API_URL.len() + 19);
+    url.push_str(API_URL);
+    url.push_str("/articles/scheduled");
+
+    let json = Fetch::get(url).await;
+    match json {
+        Ok(json) => Ok(json.into_serde().unwrap()),
+        Err(err) => Err(Status::from(err)),
     }
 }
 
@@ -106,7 +160,7 @@ pub async fn delete_article(id: &i32) -> Result<Status, Status> {
     let json = Fetch::delete(url).await;
     match json {
         Ok(json) => Ok(json.into_serde::<TAPIResponse<()>>().unwrap().status),
-        Err(_err) => Err(Status::Error),
+        Err(err) => Err(Status::from(err)),
     }
 }
 
@@ -120,7 +174,7 @@ pub async fn add_content(payload: &IContent) -> Result<IArticle, Status> {
     let json = Fetch::post(url, Some(json!(&payload).to_string())).await;
     match json {
         Ok(json) => Ok(json.into_serde().unwrap()),
-        Err(_err) => Err(Status::Error),
+        Err(err) => Err(Status::from(err)),
     }
 }
 
@@ -136,7 +190,7 @@ pub async fn update_content(payload: &IContent) -> Result<IArticle, Status> {
     let json = Fetch::patch(url, Some(json!(&payload).to_string())).await;
     match json {
         Ok(json) => Ok(json.into_serde().unwrap()),
-        Err(_err) => Err(Status::Error),
+        Err(err) => Err(Status::from(err)),
     }
 }
 
@@ -152,7 +206,24 @@ pub async fn delete_content(id: &i32) -> Result<Status, Status> {
     let json = Fetch::delete(url).await;
     match json {
         Ok(json) => Ok(json.into_serde::<TAPIResponse<()>>().unwrap().status),
-        Err(_err) => Err(Status::Error),
+        Err(err) => Err(Status::from(err)),
+    }
+}
+
+#[cfg(feature = "editable")]
+pub async fn move_content(id: &i32, payload: &IContentMove) -> Result<IArticle, Status> {
+    let id_str = id.to_string();
+    // API_URL.len() + "/contents/".len() + id_str.len() + "/move".len()
+    let mut url = String::with_capacity(API_URL.len() + 10 + id_str.len() + 5);
+    url.push_str(API_URL);
+    url.push_str("/contents/");
+    url.push_str(&id_str);
+    url.push_str("/move");
+
+    let json = Fetch::patch(url, Some(json!(&payload).to_string())).await;
+    match json {
+        Ok(json) => Ok(json.into_serde().unwrap()),
+        Err(err) => Err(Status::from(err)),
     }
 }
 
@@ -166,7 +237,7 @@ pub async fn add_chapter(payload: &IChapter) -> Result<IArticle, Status> {
     let json = Fetch::post(url, Some(json!(&payload).to_string())).await;
     match json {
         Ok(json) => Ok(json.into_serde().unwrap()),
-        Err(_err) => Err(Status::Error),
+        Err(err) => Err(Status::from(err)),
     }
 }
 
@@ -182,7 +253,7 @@ pub async fn update_chapter(payload: &IChapter) -> Result<IArticle, Status> {
     let json = Fetch::patch(url, Some(json!(&payload).to_string())).await;
     match json {
         Ok(json) => Ok(json.into_serde().unwrap()),
-        Err(_err) => Err(Status::Error),
+        Err(err) => Err(Status::from(err)),
     }
 }
 
@@ -198,6 +269,169 @@ pub async fn delete_chapter(id: &i32) -> Result<Status, Status> {
     let json = Fetch::delete(url).await;
     match json {
         Ok(json) => Ok(json.into_serde::<TAPIResponse<()>>().unwrap().status),
-        Err(_err) => Err(Status::Error),
+        Err(err) => Err(Status::from(err)),
+    }
+}
+
+#[cfg(feature = "editable")]
+pub async fn move_chapter(id: &i32, payload: &IChapterMove) -> Result<IArticle, Status> {
+    let id_str = id.to_string();
+    // API_URL.len() + "/chapters/".len() + id_str.len() + "/move".len()
+    let mut url = String::with_capacity(API_URL.len() + 10 + id_str.len() + 5);
+    url.push_str(API_URL);
+    url.push_str("/chapters/");
+    url.push_str(&id_str);
+    url.push_str("/move");
+
+    let json = Fetch::patch(url, Some(json!(&payload).to_string())).await;
+    match json {
+        Ok(json) => Ok(json.into_serde().unwrap()),
+        Err(err) => Err(Status::from(err)),
+    }
+}
+
+#[cfg(feature = "editable")]
+pub async fn add_reference(payload: &IReference) -> Result<IArticle, Status> {
+    // API_URL.len() + "/references".len()
+    let mut url = String::with_capacity(API_URL.len() + 11);
+    url.push_str(API_URL);
+    url.push_str("/references");
+
+    let json = Fetch::post(url, Some(json!(&payload).to_string())).await;
+    match json {
+        Ok(json) => Ok(json.into_serde().unwrap()),
+        Err(err) => Err(Status::from(err)),
+    }
+}
+
+#[cfg(feature = "editable")]
+pub async fn update_reference(payload: &IReference) -> Result<IArticle, Status> {
+    let id_str = payload.id.to_string();
+    // API_URL.len() + "/references/".len() + id_str.len()
+    let mut url = String::with_capacity(API_URL.len() + 12 + id_str.len());
+    url.push_str(API_URL);
+    url.push_str("/references/");
+    url.push_str(&id_str);
+
+    let json = Fetch::patch(url, Some(json!(&payload).to_string())).await;
+    match json {
+        Ok(json) => Ok(json.into_serde().unwrap()),
+        Err(err) => Err(Status::from(err)),
+    }
+}
+
+#[cfg(feature = "editable")]
+pub async fn delete_reference(id: &i32) -> Result<Status, Status> {
+    let id_str = id.to_string();
+    // API_URL.len() + "/references/".len() + id_str.len()
+    let mut url = String::with_capacity(API_URL.len() + 12 + id_str.len());
+    url.push_str(API_URL);
+    url.push_str("/references/");
+    url.push_str(&id_str);
+
+    let json = Fetch::delete(url).await;
+    match json {
+        Ok(json) => Ok(json.into_serde::<TAPIResponse<()>>().unwrap().status),
+        Err(err) => Err(Status::from(err)),
+    }
+}
+
+#[cfg(feature = "editable")]
+pub async fn get_revisions(article_id: &i32) -> Result<Vec<IRevision>, Status> {
+    let id_str = article_id.to_string();
+    // API_URL.len() + "/articles/".len() + id_str.len() + "/revisions".len()
+    let mut url = String::with_capacity(API_URL.len() + 10 + id_str.len() + 10);
+    url.push_str(API_URL);
+    url.push_str("/articles/");
+    url.push_str(&id_str);
+    url.push_str("/revisions");
+
+    let json = Fetch::get(url).await;
+    match json {
+        Ok(json) => Ok(json.into_serde().unwrap()),
+        Err(err) => Err(Status::from(err)),
+    }
+}
+
+#[cfg(feature = "editable")]
+pub async fn get_revision_diff(
+    article_id: &i32,
+    from: &i32,
+    to: &i32,
+) -> Result<IRevisionDiff, Status> {
+    let (id_str, from_str, to_str) = (article_id.to_string(), from.to_string(), to.to_string());
+    // API_URL.len() + "/articles/".len() + id_str.len() + "/revisions/diff?from=".len() + from_str.len() + "&to=".len() + to_str.len()
+    let mut url = String::with_capacity(
+        API_URL.len() + 10 + id_str.len() + 21 + from_str.len() + 4 + to_str.len(),
+    );
+    url.push_str(API_URL);
+    url.push_str("/articles/");
+    url.push_str(&id_str);
+    url.push_str("/revisions/diff?from=");
+    url.push_str(&from_str);
+    url.push_str("&to=");
+    url.push_str(&to_str);
+
+    let json = Fetch::get(url).await;
+    match json {
+        Ok(json) => Ok(json.into_serde().unwrap()),
+        Err(err) => Err(Status::from(err)),
+    }
+}
+
+#[cfg(feature = "editable")]
+pub async fn restore_revision(article_id: &i32, revision_id: &i32) -> Result<IArticle, Status> {
+    let (id_str, revision_id_str) = (article_id.to_string(), revision_id.to_string());
+    // API_URL.len() + "/articles/".len() + id_str.len() + "/revisions/".len() + revision_id_str.len() + "/restore".len()
+    let mut url =
+        String::with_capacity(API_URL.len() + 10 + id_str.len() + 11 + revision_id_str.len() + 8);
+    url.push_str(API_URL);
+    url.push_str("/articles/");
+    url.push_str(&id_str);
+    url.push_str("/revisions/");
+    url.push_str(&revision_id_str);
+    url.push_str("/restore");
+
+    let json = Fetch::post(url, None).await;
+    match json {
+        Ok(json) => Ok(json.into_serde().unwrap()),
+        Err(err) => Err(Status::from(err)),
+    }
+}
+
+#[cfg(not(feature = "editable"))]
+pub async fn get_related(id: &i32) -> Result<Vec<IRelatedItem>, Status> {
+    let id_str = id.to_string();
+    // API_URL.len() + "/articles/".len() + id_str.len() + "/related".len()
+    let mut url = String::with_capacity(API_URL.len() + 10 + id_str.len() + 8);
+    url.push_str(API_URL);
+    url.push_str("/articles/");
+    url.push_str(&id_str);
+    url.push_str("/related");
+
+    let json = Fetch::get(url).await;
+    match json {
+        Ok(json) => Ok(json.into_serde().unwrap()),
+        Err(err) => Err(Status::from(err)),
+    }
+}
+
+#[cfg(feature = "editable")]
+pub async fn create_preview_link(
+    article_id: &i32,
+    payload: &IPreviewLinkRequest,
+) -> Result<IPreviewLink, Status> {
+    let id_str = article_id.to_string();
+    // API_URL.len() + "/articles/".len() + id_str.len() + "/preview".len()
+    let mut url = String::with_capacity(API_URL.len() + 10 + id_str.len() + 8);
+    url.push_str(API_URL);
+    url.push_str("/articles/");
+    url.push_str(&id_str);
+    url.push_str("/preview");
+
+    let json = Fetch::post(url, Some(json!(&payload).to_string())).await;
+    match json {
+        Ok(json) => Ok(json.into_serde().unwrap()),
+        Err(err) => Err(Status::from(err)),
     }
 }
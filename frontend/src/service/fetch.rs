@@ -1,10 +1,19 @@
 use {
+    std::{cell::RefCell, collections::HashMap},
     wasm_bindgen::{JsCast, JsValue},
     wasm_bindgen_futures::JsFuture,
     web_sys::{/*console,*/ Request, RequestInit, RequestMode, RequestRedirect, Response},
     yew::web_sys,
 };
 
+const NOT_MODIFIED: u16 = 304;
+
+thread_local! {
+    /* Last ETag and body received for each GET url. The ETag is sent back in If-None-Match so that the API can answer
+     * with an empty 304 Not Modified when the resource did not change, in which case the cached body is returned. */
+    static VALIDATED_RESPONSES: RefCell<HashMap<String, (String, JsValue)>> = RefCell::new(HashMap::new());
+}
+
 pub enum Method {
     GET,
     POST,
@@ -39,14 +48,44 @@ pub async fn fetch(
         .headers()
         .set("Access-Control-Request-Method", &method)?;
 
+    let is_get = method == "GET";
+    if is_get {
+        let etag = VALIDATED_RESPONSES
+            .with(|responses| responses.borrow().get(&url).map(|(etag, _)| etag.clone()));
+        if let Some(etag) = etag {
+            request.headers().set("If-None-Match", &etag)?;
+        }
+    }
+
     let window = web_sys::window().unwrap();
     let resp_value = JsFuture::from(window.fetch_with_request(&request)).await?;
 
     assert!(resp_value.is_instance_of::<Response>());
     let resp: Response = resp_value.dyn_into().unwrap();
 
+    if is_get && resp.status() == NOT_MODIFIED {
+        let cached = VALIDATED_RESPONSES
+            .with(|responses| responses.borrow().get(&url).map(|(_, json)| json.clone()));
+        if let Some(json) = cached {
+            return Ok(json);
+        }
+    }
+
     let json = JsFuture::from(resp.json()?).await?;
 
+    // Error responses carry a JSON body ({ code, message, fields }), decoded into a Status by the services.
+    if !resp.ok() {
+        return Err(json);
+    }
+
+    if is_get {
+        if let Some(etag) = resp.headers().get("ETag")? {
+            VALIDATED_RESPONSES.with(|responses| {
+                responses.borrow_mut().insert(url, (etag, json.clone()));
+            });
+        }
+    }
+
     Ok(json)
 }
 
@@ -3,4 +3,5 @@ pub mod fetch;
 pub mod future;
 pub mod projects;
 pub mod search;
+pub mod series;
 pub mod tags;
@@ -21,7 +21,7 @@ pub async fn get_all_projects() -> Result<HashMap<i32, IProject>, Status> {
     let json = Fetch::get(url).await;
     match json {
         Ok(json) => Ok(json.into_serde().unwrap()),
-        Err(_err) => Err(Status::Error),
+        Err(err) => Err(Status::from(err)),
     }
 }
 
@@ -35,6 +35,6 @@ pub async fn get_resume_projects() -> Result<HashMap<i32, IProject>, Status> {
     let json = Fetch::get(url).await;
     match json {
         Ok(json) => Ok(json.into_serde().unwrap()),
-        Err(_err) => Err(Status::Error),
+        Err(err) => Err(Status::from(err)),
     }
 }
@@ -21,6 +21,6 @@ pub async fn get_results_for_query(query: &str) -> Result<SearchResults, Status>
     let json = Fetch::get(url).await;
     match json {
         Ok(json) => Ok(json.into_serde().unwrap()),
-        Err(_err) => Err(Status::Error),
+        Err(err) => Err(Status::from(err)),
     }
 }
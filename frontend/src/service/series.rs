@@ -0,0 +1,109 @@
+/* About Strings and concatenation (from https://rust-unofficial.github.io/patterns/idioms/concat-format.html)
+ *      Using format! is usually the most succinct and readable way to combine strings, BUT:
+ *      It is usually not the most efficient way to combine strings.
+ *      A series of push operations on a mutable string is usually the most efficient (especially if the string has been pre-allocated to the expected size).
+*/
+use {
+    super::fetch::Fetch,
+    crate::{
+        entities::interfaces::{ISeries, ISeriesSummary, Status},
+        API_URL,
+    },
+};
+
+#[cfg(feature = "editable")]
+use {
+    crate::entities::interfaces::{IInputSeries, ISeriesParts},
+    serde_json::json,
+};
+
+pub async fn get_series_list() -> Result<Vec<ISeriesSummary>, Status> {
+    // API_URL.len() + "/series".len()
+    let mut url = String::with_capacity(API_URL.len() + 7);
+    url.push_str(API_URL);
+    url.push_str("/series");
+
+    let json = Fetch::get(url).await;
+    match json {
+        Ok(json) => Ok(json.into_serde().unwrap()),
+        Err(err) => Err(Status::from(err)),
+    }
+}
+
+pub async fn get_series(id: &i32) -> Result<ISeries, Status> {
+    let id_str = id.to_string();
+    // API_URL.len() + "/series/".len() + id_str.len()
+    let mut url = String::with_capacity(API_URL.len() + 8 + id_str.len());
+    url.push_str(API_URL);
+    url.push_str("/series/");
+    url.push_str(&id_str);
+
+    let json = Fetch::get(url).await;
+    match json {
+        Ok(json) => Ok(json.into_serde().unwrap()),
+        Err(err) => Err(Status::from(err)),
+    }
+}
+
+#[cfg(feature = "editable")]
+pub async fn add_series(payload: &IInputSeries) -> Result<ISeries, Status> {
+    // API_URL.len() + "/series".len()
+    let mut url = String::with_capacity(API_URL.len() + 7);
+    url.push_str(API_URL);
+    url.push_str("/series");
+
+    let json = Fetch::post(url, Some(json!(&payload).to_string())).await;
+    match json {
+        Ok(json) => Ok(json.into_serde().unwrap()),
+        Err(err) => Err(Status::from(err)),
+    }
+}
+
+#[cfg(feature = "editable")]
+pub async fn update_series(id: &i32, payload: &IInputSeries) -> Result<ISeries, Status> {
+    let id_str = id.to_string();
+    // API_URL.len() + "/series/".len() + id_str.len()
+    let mut url = String::with_capacity(API_URL.len() + 8 + id_str.len());
+    url.push_str(API_URL);
+    url.push_str("/series/");
+    url.push_str(&id_str);
+
+    let json = Fetch::patch(url, Some(json!(&payload).to_string())).await;
+    match json {
+        Ok(json) => Ok(json.into_serde().unwrap()),
+        Err(err) => Err(Status::from(err)),
+    }
+}
+
+#[cfg(feature = "editable")]
+pub async fn delete_series(id: &i32) -> Result<(), Status> {
+    let id_str = id.to_string();
+    // API_URL.len() + "/series/".len() + id_str.len()
+    let mut url = String::with_capacity(API_URL.len() + 8 + id_str.len());
+    url.push_str(API_URL);
+    url.push_str("/series/");
+    url.push_str(&id_str);
+
+    let json = Fetch::delete(url).await;
+    match json {
+        Ok(_) => Ok(()),
+        Err(err) => Err(Status::from(err)),
+    }
+}
+
+#[cfg(feature = "editable")]
+pub async fn set_series_parts(id: &i32, payload: &ISeriesParts) -> Result<ISeries, Status> {
+    let id_str = id.to_string();
+    // API_URL.len() + "/series/".len() + id_str.len() + "/articles".len()
+    let mut url = String::with_capacity(API_URL.len() + 8 + id_str.len() + 9);
+    url.push_str(API_URL);
+    url.push_str("/series/");
+    url.push_str(&id_str);
+    url.push_str("/articles");
+
+    let json = Fetch::patch(url, Some(json!(&payload).to_string())).await;
+    match json {
+        Ok(json) => Ok(json.into_serde().unwrap()),
+        Err(err) => Err(Status::from(err)),
+    }
+}
@@ -20,7 +20,7 @@ pub async fn get_tag_list() -> Result<Vec<ITag>, Status> {
     let json = Fetch::get(url).await;
     match json {
         Ok(json) => Ok(json.into_serde().unwrap()),
-        Err(_err) => Err(Status::Error),
+        Err(err) => Err(Status::from(err)),
     }
 }
 
@@ -34,6 +34,6 @@ pub async fn get_results_for_tag(tag: &str) -> Result<SearchResults, Status> {
     let json = Fetch::get(url).await;
     match json {
         Ok(json) => Ok(json.into_serde().unwrap()),
-        Err(_err) => Err(Status::Error),
+        Err(err) => Err(Status::from(err)),
     }
 }
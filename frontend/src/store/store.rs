@@ -1,5 +1,5 @@
 use {
-    crate::entities::interfaces::{IArticle, IProject, ITag, ResultsIds, SearchResults},
+    crate::entities::interfaces::{IArticle, IProject, ITag, ResultsIds, SearchResults, Status},
     std::collections::HashMap,
 };
 
@@ -13,7 +13,7 @@ pub struct BlogStore {
     pub tag_results: HashMap<String, ResultsIds>,
     pub search_query: String,
     pub search_results: HashMap<String, ResultsIds>,
-    pub is_error: bool,
+    pub error: Option<Status>,
 }
 
 #[derive(Clone)]
@@ -26,7 +26,7 @@ pub enum Action {
     SetTag(ITag),
     SetTagResults((String, SearchResults)),
     SetSearchResults((String, SearchResults)),
-    SetError(bool),
+    SetError(Status),
 }
 
 fn update_results(
@@ -72,14 +72,14 @@ pub fn reducer(prev: std::rc::Rc<BlogStore>, action: Action) -> BlogStore {
     match action {
         Action::SetArticles(articles) => BlogStore {
             articles,
-            is_error: false,
+            error: None,
             ..(*prev).clone()
         },
         Action::SetArticle(article) => {
             articles.insert(article.id, article);
             BlogStore {
                 articles,
-                is_error: false,
+                error: None,
                 ..(*prev).clone()
             }
         }
@@ -89,17 +89,17 @@ pub fn reducer(prev: std::rc::Rc<BlogStore>, action: Action) -> BlogStore {
         },
         Action::SetProjects(projects) => BlogStore {
             projects,
-            is_error: false,
+            error: None,
             ..(*prev).clone()
         },
         Action::SetTags(tags) => BlogStore {
             tags,
-            is_error: false,
+            error: None,
             ..(*prev).clone()
         },
         Action::SetTag(selected_tag) => BlogStore {
             selected_tag,
-            is_error: false,
+            error: None,
             ..(*prev).clone()
         },
         Action::SetSearchResults((search_query, results)) => {
@@ -115,7 +115,7 @@ pub fn reducer(prev: std::rc::Rc<BlogStore>, action: Action) -> BlogStore {
                 projects,
                 search_results,
                 search_query,
-                is_error: false,
+                error: None,
                 ..(*prev).clone()
             }
         }
@@ -131,12 +131,12 @@ pub fn reducer(prev: std::rc::Rc<BlogStore>, action: Action) -> BlogStore {
                 articles,
                 projects,
                 tag_results,
-                is_error: false,
+                error: None,
                 ..(*prev).clone()
             }
         }
-        Action::SetError(is_error) => BlogStore {
-            is_error,
+        Action::SetError(status) => BlogStore {
+            error: Some(status),
             ..(*prev).clone()
         },
     }
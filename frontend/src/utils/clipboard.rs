@@ -0,0 +1,19 @@
+/* navigator.clipboard is only exposed by web-sys behind the unstable APIs flag, it is reached through js_sys::Reflect instead. */
+use {
+    js_sys::{Function, Promise, Reflect},
+    wasm_bindgen::{JsCast, JsValue},
+    wasm_bindgen_futures::JsFuture,
+    yew::utils::window,
+};
+
+pub async fn copy_to_clipboard(text: &str) -> Result<(), JsValue> {
+    let clipboard = Reflect::get(&window().navigator(), &JsValue::from_str("clipboard"))?;
+    let write_text: Function =
+        Reflect::get(&clipboard, &JsValue::from_str("writeText"))?.dyn_into()?;
+    let promise: Promise = write_text
+        .call1(&clipboard, &JsValue::from_str(text))?
+        .dyn_into()?;
+    JsFuture::from(promise).await?;
+
+    Ok(())
+}
@@ -0,0 +1,125 @@
+/*
+ * Drag and drop of chapters and contents in the editor of an existing article.
+ * The dragged item travels in the DataTransfer of the event ("chapter:12" or "content:34") and the drop target moves
+ * it through the API, which shifts the siblings in one transaction:
+ *     - a chapter dropped on a chapter title takes its place,
+ *     - a content dropped on a content takes its place, in the same or another chapter,
+ *     - a content dropped on a chapter title becomes the first content of the chapter.
+ */
+use {
+    crate::{
+        entities::interfaces::{IArticle, IChapterMove, IContentMove, Status},
+        service::{
+            articles::{move_chapter, move_content},
+            future::handle_future,
+        },
+    },
+    yew::{Callback, DragEvent},
+};
+
+const DRAG_FORMAT: &str = "text/plain";
+
+#[derive(Clone, Copy, PartialEq)]
+enum Dragged {
+    Chapter(i32),
+    Content(i32),
+}
+
+impl Dragged {
+    fn to_data(self) -> String {
+        match self {
+            Self::Chapter(id) => format!("chapter:{}", id),
+            Self::Content(id) => format!("content:{}", id),
+        }
+    }
+
+    fn from_data(data: &str) -> Option<Self> {
+        let (kind, id) = data.split_once(':')?;
+        let id = id.parse().ok()?;
+        match kind {
+            "chapter" => Some(Self::Chapter(id)),
+            "content" => Some(Self::Content(id)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum DropTarget {
+    Chapter {
+        article_id: i32,
+        chapter_id: i32,
+        index: i32,
+    },
+    Content {
+        chapter_id: i32,
+        index: i32,
+    },
+}
+
+fn on_drag_start(dragged: Dragged) -> Callback<DragEvent> {
+    Callback::from(move |event: DragEvent| {
+        if let Some(data_transfer) = event.data_transfer() {
+            let _ = data_transfer.set_data(DRAG_FORMAT, &dragged.to_data());
+            data_transfer.set_effect_allowed("move");
+        }
+    })
+}
+
+pub fn on_drag_chapter(id: i32) -> Callback<DragEvent> {
+    on_drag_start(Dragged::Chapter(id))
+}
+
+pub fn on_drag_content(id: i32) -> Callback<DragEvent> {
+    on_drag_start(Dragged::Content(id))
+}
+
+// Browsers only allow a drop on elements preventing the default handling of dragover.
+pub fn on_drag_over() -> Callback<DragEvent> {
+    Callback::from(|event: DragEvent| event.prevent_default())
+}
+
+pub fn on_drop(
+    target: DropTarget,
+    dispatch_article: Callback<IArticle>,
+    dispatch_error: Callback<Status>,
+) -> Callback<DragEvent> {
+    Callback::from(move |event: DragEvent| {
+        event.prevent_default();
+        let dragged = event
+            .data_transfer()
+            .and_then(|data_transfer| data_transfer.get_data(DRAG_FORMAT).ok())
+            .and_then(|data| Dragged::from_data(&data));
+        let (dispatch_article, dispatch_error) = (dispatch_article.clone(), dispatch_error.clone());
+        let handler = move |response: Result<IArticle, Status>| match response {
+            Ok(article) => dispatch_article.emit(article),
+            Err(status) => dispatch_error.emit(status),
+        };
+
+        match (dragged, target) {
+            (
+                Some(Dragged::Chapter(id)),
+                DropTarget::Chapter {
+                    article_id,
+                    chapter_id,
+                    index,
+                },
+            ) if id != chapter_id => {
+                let payload = IChapterMove { article_id, index };
+                handle_future(async move { move_chapter(&id, &payload).await }, handler);
+            }
+            (Some(Dragged::Content(id)), DropTarget::Chapter { chapter_id, .. }) => {
+                let payload = IContentMove {
+                    chapter_id,
+                    index: 0,
+                };
+                handle_future(async move { move_content(&id, &payload).await }, handler);
+            }
+            (Some(Dragged::Content(id)), DropTarget::Content { chapter_id, index }) => {
+                let payload = IContentMove { chapter_id, index };
+                handle_future(async move { move_content(&id, &payload).await }, handler);
+            }
+            _ => (),
+        }
+    })
+}
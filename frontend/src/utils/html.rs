@@ -0,0 +1,9 @@
+use yew::{utils::document, Html};
+
+// Markup rendered by the API (highlighted code, MathML), injected as is.
+pub fn raw_html(html: &str) -> Html {
+    let div = document().create_element("div").unwrap();
+    div.set_inner_html(html);
+
+    Html::VRef(div.into())
+}
@@ -0,0 +1,20 @@
+use std::ops::RangeInclusive;
+
+// Line ranges written as "3, 5-8" (the API rejects invalid ones, they are skipped here).
+pub fn parse_line_ranges(ranges: &str) -> Vec<RangeInclusive<i32>> {
+    ranges
+        .split(',')
+        .filter_map(|range| {
+            let range = range.trim();
+            let (start, end) = range.split_once('-').unwrap_or((range, range));
+            match (start.trim().parse::<i32>(), end.trim().parse::<i32>()) {
+                (Ok(start), Ok(end)) if start <= end => Some(start..=end),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+pub fn in_line_ranges(ranges: &[RangeInclusive<i32>], line: i32) -> bool {
+    ranges.iter().any(|range| range.contains(&line))
+}
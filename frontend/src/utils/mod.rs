@@ -1,3 +1,9 @@
+pub mod clipboard;
 pub mod date;
+#[cfg(feature = "editable")]
+pub mod drag;
+pub mod html;
+pub mod line_ranges;
 #[cfg(debug_assertions)]
 pub mod logger;
+pub mod theme;
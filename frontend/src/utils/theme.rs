@@ -0,0 +1,53 @@
+/* The page follows the system colour scheme. Highlighted code only carries CSS classes, its colours come from the
+ * stylesheet served by the API for the current theme, which is swapped when the scheme changes (no content is fetched again). */
+use {
+    crate::API_URL,
+    wasm_bindgen::{closure::Closure, JsCast, JsValue},
+    web_sys::{Event, MediaQueryList},
+    yew::utils::{document, window},
+};
+
+const DARK_SCHEME_QUERY: &str = "(prefers-color-scheme: dark)";
+const CODE_THEME_LINK_ID: &str = "code-theme";
+
+fn apply_theme(dark: bool) -> Result<(), JsValue> {
+    let theme = if dark { "dark" } else { "light" };
+    let document = document();
+    if let Some(root) = document.document_element() {
+        root.set_attribute("data-theme", theme)?;
+    }
+
+    let link = match document.get_element_by_id(CODE_THEME_LINK_ID) {
+        Some(link) => link,
+        None => {
+            let link = document.create_element("link")?;
+            link.set_id(CODE_THEME_LINK_ID);
+            link.set_attribute("rel", "stylesheet")?;
+            if let Some(head) = document.query_selector("head")? {
+                head.append_child(&link)?;
+            }
+            link
+        }
+    };
+    link.set_attribute("href", &[API_URL, "/code/themes/", theme].concat())
+}
+
+pub fn follow_system_theme() -> Result<(), JsValue> {
+    let media_query_list: MediaQueryList = match window().match_media(DARK_SCHEME_QUERY)? {
+        Some(media_query_list) => media_query_list,
+        None => return apply_theme(false),
+    };
+    apply_theme(media_query_list.matches())?;
+
+    let on_change = {
+        let media_query_list = media_query_list.clone();
+        Closure::wrap(Box::new(move |_: Event| {
+            let _ = apply_theme(media_query_list.matches());
+        }) as Box<dyn FnMut(Event)>)
+    };
+    media_query_list.set_onchange(Some(on_change.as_ref().unchecked_ref()));
+    // The listener lives as long as the page.
+    on_change.forget();
+
+    Ok(())
+}